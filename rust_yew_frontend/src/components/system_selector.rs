@@ -55,10 +55,10 @@ impl Component for SystemSelector {
                     <option value="6" selected={selected_system == 6}>{"Hexad (6)"}</option>
                     <option value="7" selected={selected_system == 7}>{"Heptad (7)"}</option>
                     <option value="8" selected={selected_system == 8}>{"Octad (8)"}</option>
-                    // <option value="9">{"Ennead (9)"}</option>
-                    // <option value="10">{"Decad (10)"}</option>
-                    // <option value="11">{"Undecad (11)"}</option>
-                    // <option value="12">{"Dodecad (12)"}</option>
+                    <option value="9" selected={selected_system == 9}>{"Ennead (9)"}</option>
+                    <option value="10" selected={selected_system == 10}>{"Decad (10)"}</option>
+                    <option value="11" selected={selected_system == 11}>{"Undecad (11)"}</option>
+                    <option value="12" selected={selected_system == 12}>{"Dodecad (12)"}</option>
                 </select>
             </div>
         }
@@ -1,9 +1,10 @@
-use yew::{html, Component, Context, Html};
+use systematics::modules::document::SystemDocument;
+use yew::{html, AttrValue, Component, Context, Html};
 use wasm_bindgen::prelude::*;
 
 mod components; // Declare the components module
 use components::system_selector::SystemSelector; // Import the SystemSelector
-use components::system_overlay::SystemOverlay;
+use components::system_overlay::{diagram_options, SystemOverlay};
 
 pub struct App {
     selected_system_num: i32,
@@ -35,30 +36,21 @@ impl Component for App {
     fn view(&self, ctx: &Context<Self>) -> Html {
         let on_system_selected = ctx.link().callback(Msg::SystemSelected);
         
-        // Determine the image URL based on the selected system
-        let image_url = match self.selected_system_num {
-            1 => "monad.png",
-            2 => "dyad.png",
-            3 => "triad.png",
-            4 => "tetrad.png",
-            5 => "pentad.png",
-            6 => "hexad.png",
-            7 => "heptad.png",
-            8 => "octad.png",
-            _ => "default.png",
-        };
-
-        // Create system-specific CSS class
-        let system_class = format!("system-{}", self.selected_system_num);
+        // Draw the selected system's figure with the systematics renderer
+        let svg = SystemDocument::canonical(self.selected_system_num as usize, "")
+            .map(|document| document.to_svg(&diagram_options()))
+            .unwrap_or_default();
 
         html! {
             <div class="app-container">
                 <div class="system-selector-container">
                     <SystemSelector {on_system_selected} selected_system={self.selected_system_num} />
                 </div>
-                <div class={format!("main-content {}", system_class)}>
-                    <img src={image_url} alt={format!("System {}", self.selected_system_num)} />
-                    <SystemOverlay system_num={self.selected_system_num} />
+                <div class="main-content">
+                    <div class="diagram">
+                        { Html::from_html_unchecked(AttrValue::from(svg)) }
+                        <SystemOverlay system_num={self.selected_system_num} />
+                    </div>
                 </div>
             </div>
         }
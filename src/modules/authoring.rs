@@ -0,0 +1,700 @@
+use std::iter::Peekable;
+use std::path::Path;
+use std::vec;
+
+use toml::de::{DeTable, DeValue};
+use toml::Spanned;
+use yaml_rust2::parser::{Event, MarkedEventReceiver, Parser};
+use yaml_rust2::scanner::Marker;
+
+use crate::modules::connective::{Connective, Direction, Position};
+use crate::modules::document::{SystemDocument, TermDocument};
+use crate::modules::error::SystematicsError;
+use crate::modules::monad::Monad;
+use crate::modules::system::order_for_kind;
+use crate::modules::validation::ValidationPolicy;
+
+/// Keys allowed at the top of a system file
+const SECTIONS: [&str; 5] = ["kind", "name", "terms", "connectives", "metadata"];
+
+/// Keys allowed in a connective written as a table
+const CONNECTIVE_KEYS: [&str; 3] = ["label", "direction", "notes"];
+
+/// Hand-editable text formats for a system
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AuthoringFormat {
+    Toml,
+    Yaml,
+}
+
+impl AuthoringFormat {
+    /// Format for a name such as "toml", "yaml" or "yml" (case-insensitive)
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_lowercase().as_str() {
+            "toml" => Some(AuthoringFormat::Toml),
+            "yaml" | "yml" => Some(AuthoringFormat::Yaml),
+            _ => None,
+        }
+    }
+
+    /// Format for a file with a .toml, .yaml or .yml extension
+    pub fn for_path(path: &Path) -> Option<Self> {
+        path.extension().and_then(|extension| extension.to_str()).and_then(Self::from_name)
+    }
+}
+
+/// Key naming a canonical term or connective in a system file, e.g. "Higher Potential"
+/// is `higher-potential` and "Origin–Direction" is `origin-direction`
+pub fn authoring_key(label: &str) -> String {
+    label
+        .split(|character: char| !character.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+impl SystemDocument {
+    /// Reads a hand-written system file.
+    ///
+    /// Terms and connectives are keyed by their canonical names (see `authoring_key`);
+    /// the Monad's terms are a list. Connectives left out keep their canonical names.
+    /// Names, instances and labels are cleaned with the policy, as `read_csv` does.
+    pub fn from_authoring(text: &str, format: AuthoringFormat, policy: &ValidationPolicy) -> Result<Self, SystematicsError> {
+        let root = match format {
+            AuthoringFormat::Toml => toml_root(text)?,
+            AuthoringFormat::Yaml => yaml_root(text)?,
+        };
+        let mut document = read_document(&root)?;
+        document.validate_values(policy)?;
+        Ok(document)
+    }
+
+    /// Writes the system for editing by hand, with connectives still carrying their
+    /// canonical names commented out. Nested systems and saved six laws are not written.
+    pub fn to_authoring(&self, format: AuthoringFormat) -> String {
+        match format {
+            AuthoringFormat::Toml => self.to_toml(),
+            AuthoringFormat::Yaml => self.to_yaml(),
+        }
+    }
+
+    fn to_toml(&self) -> String {
+        let mut text = format!("kind = {}\nname = {}\n", quote(&self.kind), quote(&self.name));
+        if self.order == 1 {
+            let terms: Vec<String> = self.terms.iter().map(|term| quote(&term.instance)).collect();
+            text.push_str(&format!("terms = [{}]\n", terms.join(", ")));
+        } else {
+            text.push_str("\n[terms]\n");
+            for term in &self.terms {
+                text.push_str(&format!("{} = {}\n", term_key(term), quote(&term.instance)));
+            }
+        }
+
+        if !self.connectives.is_empty() {
+            text.push_str("\n[connectives]\n");
+            for connective in &self.connectives {
+                let key = authoring_key(&connective.canonical_name);
+                if is_canonical(connective) {
+                    text.push_str(&format!("# {} = {}\n", key, quote(&connective.canonical_name)));
+                    continue;
+                }
+                let mut fields = vec![format!("label = {}", quote(connective.label()))];
+                if connective.direction != Direction::Bidirectional {
+                    fields.push(format!("direction = {}", quote(direction_name(connective.direction))));
+                }
+                if let Some(notes) = &connective.notes {
+                    fields.push(format!("notes = {}", quote(notes)));
+                }
+                if fields.len() == 1 {
+                    text.push_str(&format!("{} = {}\n", key, quote(connective.label())));
+                } else {
+                    text.push_str(&format!("{} = {{ {} }}\n", key, fields.join(", ")));
+                }
+            }
+        }
+
+        if !self.metadata.is_empty() {
+            text.push_str("\n[metadata]\n");
+            for (key, value) in &self.metadata {
+                text.push_str(&format!("{} = {}\n", quote(key), quote(value)));
+            }
+        }
+        text
+    }
+
+    fn to_yaml(&self) -> String {
+        let mut text = format!("kind: {}\nname: {}\n", quote(&self.kind), quote(&self.name));
+        if self.order == 1 && self.terms.is_empty() {
+            text.push_str("terms: []\n");
+        } else {
+            text.push_str("terms:\n");
+            for term in &self.terms {
+                match term.canonical {
+                    Some(_) => text.push_str(&format!("  {}: {}\n", term_key(term), quote(&term.instance))),
+                    None => text.push_str(&format!("  - {}\n", quote(&term.instance))),
+                }
+            }
+        }
+
+        if !self.connectives.is_empty() {
+            // An empty mapping keeps the section valid when every line below is a comment
+            let edited = self.connectives.iter().any(|connective| !is_canonical(connective));
+            text.push_str(if edited { "connectives:\n" } else { "connectives: {}\n" });
+            for connective in &self.connectives {
+                let key = authoring_key(&connective.canonical_name);
+                if is_canonical(connective) {
+                    text.push_str(&format!("  # {}: {}\n", key, quote(&connective.canonical_name)));
+                } else if connective.direction == Direction::Bidirectional && connective.notes.is_none() {
+                    text.push_str(&format!("  {}: {}\n", key, quote(connective.label())));
+                } else {
+                    text.push_str(&format!("  {}:\n    label: {}\n", key, quote(connective.label())));
+                    if connective.direction != Direction::Bidirectional {
+                        text.push_str(&format!("    direction: {}\n", direction_name(connective.direction)));
+                    }
+                    if let Some(notes) = &connective.notes {
+                        text.push_str(&format!("    notes: {}\n", quote(notes)));
+                    }
+                }
+            }
+        }
+
+        if !self.metadata.is_empty() {
+            text.push_str("metadata:\n");
+            for (key, value) in &self.metadata {
+                text.push_str(&format!("  {}: {}\n", quote(key), quote(value)));
+            }
+        }
+        text
+    }
+}
+
+/// A value read from a system file and where it starts (1-based line and column)
+#[derive(Debug)]
+struct Node {
+    line: usize,
+    column: usize,
+    value: Value,
+}
+
+#[derive(Debug)]
+enum Value {
+    Text(String),
+    List(Vec<Node>),
+    /// Key and value pairs in file order; keys are always text
+    Table(Vec<(Node, Node)>),
+    /// Anything else, described for error messages (e.g. "a number")
+    Other(&'static str),
+}
+
+impl Node {
+    fn error(&self, message: String) -> SystematicsError {
+        SystematicsError::Syntax {
+            line: self.line,
+            column: self.column,
+            message,
+        }
+    }
+
+    /// The text of a key, or of a value that should be text
+    fn key(&self) -> &str {
+        match &self.value {
+            Value::Text(text) => text,
+            _ => "",
+        }
+    }
+
+    fn text(&self, what: &str) -> Result<&str, SystematicsError> {
+        match &self.value {
+            Value::Text(text) => Ok(text),
+            other => Err(self.error(format!("{} must be text, not {}", what, describe(other)))),
+        }
+    }
+
+    fn table(&self, what: &str) -> Result<&[(Node, Node)], SystematicsError> {
+        match &self.value {
+            Value::Table(entries) => Ok(entries),
+            other => Err(self.error(format!("{} must be a table, not {}", what, describe(other)))),
+        }
+    }
+}
+
+fn describe(value: &Value) -> &'static str {
+    match value {
+        Value::Text(_) => "text",
+        Value::List(_) => "a list",
+        Value::Table(_) => "a table",
+        Value::Other(kind) => kind,
+    }
+}
+
+fn read_document(root: &Node) -> Result<SystemDocument, SystematicsError> {
+    let entries = root.table("A system file")?;
+    check_keys(entries, &SECTIONS, "key", "")?;
+    let section = |name: &str| entries.iter().find(|(key, _)| key.key() == name);
+    let required = |name: &str| section(name).ok_or_else(|| root.error(format!("missing key '{}'", name)));
+
+    let (_, kind) = required("kind")?;
+    let order = order_for_kind(kind.text("kind")?).ok_or_else(|| kind.error(format!("unknown system kind '{}'", kind.key())))?;
+    let (_, name) = required("name")?;
+    let mut document = SystemDocument::canonical(order, name.text("name")?)?;
+
+    let (key, terms) = required("terms")?;
+    read_terms(&mut document, key, terms)?;
+    if let Some((_, connectives)) = section("connectives") {
+        read_connectives(&mut document, connectives)?;
+    }
+    if let Some((_, metadata)) = section("metadata") {
+        for (key, value) in metadata.table("metadata")? {
+            document.metadata.insert(key.key().to_string(), value.text(key.key())?.to_string());
+        }
+    }
+    Ok(document)
+}
+
+fn read_terms(document: &mut SystemDocument, key: &Node, terms: &Node) -> Result<(), SystematicsError> {
+    if document.order == 1 {
+        let Value::List(items) = &terms.value else {
+            return Err(terms.error(format!("a Monad's terms must be a list, not {}", describe(&terms.value))));
+        };
+        for (index, item) in items.iter().enumerate() {
+            let Some(position) = Position::from_index(index) else {
+                return Err(item.error(format!("a Monad holds at most {} terms", Monad::MAX_TERMS)));
+            };
+            document.terms.push(TermDocument {
+                position,
+                canonical: None,
+                instance: item.text("a term")?.to_string(),
+                child: None,
+            });
+        }
+        return Ok(());
+    }
+
+    let entries = terms.table("terms")?;
+    let keys: Vec<String> = document.terms.iter().map(term_key).collect();
+    check_keys(entries, &keys, "term", &format!(" for a {}", document.kind))?;
+    for (name, value) in entries {
+        let index = keys.iter().position(|key| key == name.key()).unwrap_or_default();
+        document.terms[index].instance = value.text(name.key())?.to_string();
+    }
+
+    let missing: Vec<String> = document
+        .terms
+        .iter()
+        .zip(&keys)
+        .filter(|(_, key)| !entries.iter().any(|(name, _)| name.key() == key.as_str()))
+        .map(|(term, key)| format!("{} ({})", key, term.position))
+        .collect();
+    match missing.len() {
+        0 => Ok(()),
+        1 => Err(key.error(format!("missing term {}", missing[0]))),
+        _ => Err(key.error(format!("missing terms {}", missing.join(", ")))),
+    }
+}
+
+fn read_connectives(document: &mut SystemDocument, connectives: &Node) -> Result<(), SystematicsError> {
+    let entries = connectives.table("connectives")?;
+    let keys: Vec<String> = document.connectives.iter().map(|connective| authoring_key(&connective.canonical_name)).collect();
+    check_keys(entries, &keys, "connective", &format!(" for a {}", document.kind))?;
+
+    for (name, value) in entries {
+        let index = keys.iter().position(|key| key == name.key()).unwrap_or_default();
+        let connective = &mut document.connectives[index];
+        let label = match &value.value {
+            Value::Table(fields) => {
+                check_keys(fields, &CONNECTIVE_KEYS, "connective key", "")?;
+                let field = |key: &str| fields.iter().find(|(name, _)| name.key() == key).map(|(_, value)| value);
+                if let Some(direction) = field("direction") {
+                    connective.direction = match direction.text("direction")? {
+                        "bidirectional" => Direction::Bidirectional,
+                        "forward" => Direction::Forward,
+                        "backward" => Direction::Backward,
+                        other => {
+                            return Err(direction.error(format!(
+                                "unknown direction '{}'; expected bidirectional, forward or backward",
+                                other
+                            )));
+                        }
+                    };
+                }
+                if let Some(notes) = field("notes") {
+                    connective.notes = Some(notes.text("notes")?.to_string());
+                }
+                field("label")
+                    .ok_or_else(|| name.error(format!("missing key 'label' for connective {}", name.key())))?
+                    .text("label")?
+            }
+            _ => value.text(name.key())?,
+        };
+        // An empty label, or the canonical name itself, leaves the connective unlabelled
+        connective.user_label = (!label.is_empty() && label != connective.canonical_name).then(|| label.to_string());
+    }
+    Ok(())
+}
+
+/// Rejects keys outside `allowed` and keys given twice, pointing at the offending key
+fn check_keys<S: AsRef<str>>(entries: &[(Node, Node)], allowed: &[S], what: &str, scope: &str) -> Result<(), SystematicsError> {
+    for (index, (key, _)) in entries.iter().enumerate() {
+        if !allowed.iter().any(|name| name.as_ref() == key.key()) {
+            let expected: Vec<&str> = allowed.iter().map(AsRef::as_ref).collect();
+            let expected = match expected.is_empty() {
+                true => "there are none".to_string(),
+                false => format!("expected one of: {}", expected.join(", ")),
+            };
+            return Err(key.error(format!("unknown {} '{}'{}; {}", what, key.key(), scope, expected)));
+        }
+        if entries[..index].iter().any(|(earlier, _)| earlier.key() == key.key()) {
+            return Err(key.error(format!("'{}' is given more than once", key.key())));
+        }
+    }
+    Ok(())
+}
+
+fn term_key(term: &TermDocument) -> String {
+    authoring_key(term.canonical.as_deref().unwrap_or_default())
+}
+
+fn is_canonical(connective: &Connective) -> bool {
+    !connective.is_relabelled() && connective.direction == Direction::Bidirectional && connective.notes.is_none()
+}
+
+fn direction_name(direction: Direction) -> &'static str {
+    match direction {
+        Direction::Bidirectional => "bidirectional",
+        Direction::Forward => "forward",
+        Direction::Backward => "backward",
+    }
+}
+
+/// A double-quoted string, escaped so both TOML and YAML read it back unchanged
+fn quote(text: &str) -> String {
+    let mut quoted = String::from("\"");
+    for character in text.chars() {
+        match character {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\t' => quoted.push_str("\\t"),
+            '\r' => quoted.push_str("\\r"),
+            character if character.is_control() => quoted.push_str(&format!("\\u{:04X}", character as u32)),
+            character => quoted.push(character),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+/// 1-based line and column (in characters) of a byte offset
+fn line_column(text: &str, offset: usize) -> (usize, usize) {
+    let before = &text[..offset.min(text.len())];
+    let line_start = before.rfind('\n').map_or(0, |index| index + 1);
+    (before.matches('\n').count() + 1, before[line_start..].chars().count() + 1)
+}
+
+fn toml_root(text: &str) -> Result<Node, SystematicsError> {
+    let table = DeTable::parse(text).map_err(|error| {
+        let (line, column) = error.span().map_or((1, 1), |span| line_column(text, span.start));
+        SystematicsError::Syntax {
+            line,
+            column,
+            message: error.message().trim().to_string(),
+        }
+    })?;
+    Ok(Node {
+        line: 1,
+        column: 1,
+        value: Value::Table(toml_entries(text, table.get_ref())),
+    })
+}
+
+fn toml_entries(text: &str, table: &DeTable) -> Vec<(Node, Node)> {
+    let mut entries: Vec<(Node, Node)> = table
+        .iter()
+        .map(|(key, value)| {
+            let (line, column) = line_column(text, key.span().start);
+            let key = Node {
+                line,
+                column,
+                value: Value::Text(key.get_ref().to_string()),
+            };
+            (key, toml_node(text, value))
+        })
+        .collect();
+    // Tables iterate in key order; errors should follow the file
+    entries.sort_by_key(|(key, _)| (key.line, key.column));
+    entries
+}
+
+fn toml_node(text: &str, value: &Spanned<DeValue>) -> Node {
+    let (line, column) = line_column(text, value.span().start);
+    let value = match value.get_ref() {
+        DeValue::String(string) => Value::Text(string.to_string()),
+        DeValue::Array(items) => Value::List(items.iter().map(|item| toml_node(text, item)).collect()),
+        DeValue::Table(table) => Value::Table(toml_entries(text, table)),
+        DeValue::Integer(_) | DeValue::Float(_) => Value::Other("a number"),
+        DeValue::Boolean(_) => Value::Other("true or false"),
+        DeValue::Datetime(_) => Value::Other("a date"),
+    };
+    Node { line, column, value }
+}
+
+/// Collects parser events with their positions
+#[derive(Default)]
+struct YamlEvents(Vec<(Event, Marker)>);
+
+impl MarkedEventReceiver for YamlEvents {
+    fn on_event(&mut self, event: Event, mark: Marker) {
+        self.0.push((event, mark));
+    }
+}
+
+fn yaml_root(text: &str) -> Result<Node, SystematicsError> {
+    let mut events = YamlEvents::default();
+    Parser::new_from_str(text).load(&mut events, false).map_err(|error| SystematicsError::Syntax {
+        line: error.marker().line(),
+        column: error.marker().col() + 1,
+        message: error.info().to_string(),
+    })?;
+
+    let mut events = events
+        .0
+        .into_iter()
+        .filter(|(event, _)| !matches!(event, Event::StreamStart | Event::StreamEnd | Event::DocumentStart | Event::DocumentEnd))
+        .collect::<Vec<_>>()
+        .into_iter()
+        .peekable();
+    Ok(yaml_node(&mut events).unwrap_or(Node {
+        line: 1,
+        column: 1,
+        value: Value::Other("an empty file"),
+    }))
+}
+
+fn yaml_node(events: &mut Peekable<vec::IntoIter<(Event, Marker)>>) -> Option<Node> {
+    let (event, mark) = events.next()?;
+    let value = match event {
+        Event::Scalar(text, ..) => Value::Text(text),
+        Event::SequenceStart(..) => {
+            let mut items = Vec::new();
+            while !matches!(events.peek(), None | Some((Event::SequenceEnd, _))) {
+                items.extend(yaml_node(events));
+            }
+            events.next();
+            Value::List(items)
+        }
+        Event::MappingStart(..) => {
+            let mut entries = Vec::new();
+            while !matches!(events.peek(), None | Some((Event::MappingEnd, _))) {
+                if let (Some(key), Some(value)) = (yaml_node(events), yaml_node(events)) {
+                    entries.push((key, value));
+                }
+            }
+            events.next();
+            Value::Table(entries)
+        }
+        Event::Alias(_) => Value::Other("an alias"),
+        _ => Value::Other("nothing"),
+    };
+    Some(Node {
+        line: mark.line(),
+        column: mark.col() + 1,
+        value,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::octad::Octad;
+    use crate::modules::system::kind_name;
+    use crate::modules::template;
+    use crate::modules::tetrad::Tetrad;
+
+    const TETRAD_TOML: &str = r#"kind = "Tetrad"
+name = "Work"
+
+[terms]
+ground = "Office"
+ideal = "Mission"
+instrumental = "Tools"
+directive = "Plan"
+
+[connectives]
+motivational-imperative = "Calling"
+demonstrable-activity = { label = "Shipping", direction = "forward", notes = "Weekly" }
+"#;
+
+    const TETRAD_YAML: &str = r#"kind: Tetrad
+name: Work
+terms:
+  ground: Office
+  ideal: Mission
+  instrumental: Tools
+  directive: Plan
+connectives:
+  motivational-imperative: Calling
+  demonstrable-activity:
+    label: Shipping
+    direction: forward
+    notes: Weekly
+"#;
+
+    fn syntax_error(result: Result<SystemDocument, SystematicsError>) -> (usize, usize, String) {
+        match result {
+            Err(SystematicsError::Syntax { line, column, message }) => (line, column, message),
+            other => panic!("expected a syntax error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_keys() {
+        assert_eq!(authoring_key("Higher Potential"), "higher-potential");
+        assert_eq!(authoring_key("Origin–Direction"), "origin-direction");
+        assert_eq!(AuthoringFormat::for_path(Path::new("work.YML")), Some(AuthoringFormat::Yaml));
+        assert_eq!(AuthoringFormat::for_path(Path::new("work.json")), None);
+
+        // Keys must tell every term and connective of an order apart
+        for template in template::all() {
+            let mut keys: Vec<String> = template.connectives.iter().map(|connective| authoring_key(connective.name)).collect();
+            keys.sort();
+            keys.dedup();
+            assert_eq!(keys.len(), template.connectives.len(), "{}", template.kind);
+        }
+    }
+
+    #[test]
+    fn test_toml_and_yaml_read_the_same() {
+        let from_toml = SystemDocument::from_authoring(TETRAD_TOML, AuthoringFormat::Toml, &ValidationPolicy::default()).unwrap();
+        let from_yaml = SystemDocument::from_authoring(TETRAD_YAML, AuthoringFormat::Yaml, &ValidationPolicy::default()).unwrap();
+        assert_eq!(from_toml, from_yaml);
+
+        assert_eq!(from_toml.name, "Work");
+        assert_eq!(from_toml.instances(), ["Office", "Mission", "Tools", "Plan"]);
+        let shipping = from_toml.connectives.iter().find(|connective| connective.joins('C', 'D')).unwrap();
+        assert_eq!(shipping.label(), "Shipping");
+        assert_eq!(shipping.direction, Direction::Forward);
+        assert_eq!(shipping.notes.as_deref(), Some("Weekly"));
+        assert!(!from_toml.connectives[1].is_relabelled());
+    }
+
+    #[test]
+    fn test_round_trip_every_order() {
+        // The newline in each name checks escaping, so the policy lets it through
+        let policy = ValidationPolicy {
+            extra_characters: "\n".to_string(),
+            ..ValidationPolicy::default()
+        };
+        for order in 1..=12 {
+            let mut document = SystemDocument::canonical(order, &format!("{} \"quoted\"\nname", order)).unwrap();
+            if order == 1 {
+                let mut monad = Monad::new("Self");
+                monad.add_term("Mind").unwrap();
+                monad.add_term("Body").unwrap();
+                document = SystemDocument::from_system(&monad);
+            }
+            if let Some(term) = document.terms.last_mut() {
+                term.instance = "Edited: # not a comment".to_string();
+            }
+            if let Some(connective) = document.connectives.last_mut() {
+                connective.user_label = Some("Relabelled".to_string());
+                connective.direction = Direction::Backward;
+            }
+            document = document.with_metadata("workshop", "June");
+
+            for format in [AuthoringFormat::Toml, AuthoringFormat::Yaml] {
+                let text = document.to_authoring(format);
+                let read = SystemDocument::from_authoring(&text, format, &policy);
+                assert_eq!(read.as_ref().ok(), Some(&document), "{:?} {}:\n{}\n{:?}", format, kind_name(order).unwrap(), text, read);
+            }
+        }
+    }
+
+    #[test]
+    fn test_canonical_connectives_are_commented_out() {
+        let document = SystemDocument::from_system(&Octad::new("Firm", "A", "B", "C", "D", "E", "F", "G", "H"));
+        let toml = document.to_authoring(AuthoringFormat::Toml);
+        assert!(toml.contains("[terms]\nsmallest-significant-holon = \"A\"\n"), "{}", toml);
+        assert_eq!(toml.matches("\n# ").count(), 28);
+
+        let yaml = document.to_authoring(AuthoringFormat::Yaml);
+        assert!(yaml.contains("connectives: {}\n  # "), "{}", yaml);
+        assert_eq!(SystemDocument::from_authoring(&yaml, AuthoringFormat::Yaml, &ValidationPolicy::default()).unwrap(), document);
+    }
+
+    #[test]
+    fn test_unknown_keys_are_located() {
+        let toml = TETRAD_TOML.replace("ideal = ", "idea = ");
+        let (line, column, message) = syntax_error(SystemDocument::from_authoring(&toml, AuthoringFormat::Toml, &ValidationPolicy::default()));
+        assert_eq!((line, column), (6, 1));
+        assert_eq!(message, "unknown term 'idea' for a Tetrad; expected one of: ground, ideal, instrumental, directive");
+
+        let yaml = TETRAD_YAML.replace("  motivational-imperative", "  motivation");
+        let (line, column, message) = syntax_error(SystemDocument::from_authoring(&yaml, AuthoringFormat::Yaml, &ValidationPolicy::default()));
+        assert_eq!((line, column), (9, 3));
+        assert!(message.starts_with("unknown connective 'motivation' for a Tetrad; expected one of: motivational-imperative,"), "{}", message);
+
+        let yaml = TETRAD_YAML.replace("    notes:", "    note:");
+        let (line, column, message) = syntax_error(SystemDocument::from_authoring(&yaml, AuthoringFormat::Yaml, &ValidationPolicy::default()));
+        assert_eq!((line, column, message.as_str()), (13, 5, "unknown connective key 'note'; expected one of: label, direction, notes"));
+
+        let toml = format!("colour = \"red\"\n{}", TETRAD_TOML);
+        let (line, column, message) = syntax_error(SystemDocument::from_authoring(&toml, AuthoringFormat::Toml, &ValidationPolicy::default()));
+        assert_eq!((line, column), (1, 1));
+        assert!(message.starts_with("unknown key 'colour'"), "{}", message);
+    }
+
+    #[test]
+    fn test_missing_keys_are_located() {
+        let toml = TETRAD_TOML.replace("directive = \"Plan\"\n", "").replace("ideal = \"Mission\"\n", "");
+        let (line, column, message) = syntax_error(SystemDocument::from_authoring(&toml, AuthoringFormat::Toml, &ValidationPolicy::default()));
+        assert_eq!((line, column, message.as_str()), (4, 2, "missing terms ideal (B), directive (D)"));
+
+        let yaml = TETRAD_YAML.replace("    label: Shipping\n", "");
+        let (line, column, message) = syntax_error(SystemDocument::from_authoring(&yaml, AuthoringFormat::Yaml, &ValidationPolicy::default()));
+        assert_eq!((line, column, message.as_str()), (10, 3, "missing key 'label' for connective demonstrable-activity"));
+
+        let (line, column, message) = syntax_error(SystemDocument::from_authoring("kind = \"Tetrad\"\n", AuthoringFormat::Toml, &ValidationPolicy::default()));
+        assert_eq!((line, column, message.as_str()), (1, 1, "missing key 'name'"));
+    }
+
+    #[test]
+    fn test_bad_values_are_located() {
+        let toml = TETRAD_TOML.replace("kind = \"Tetrad\"", "kind = \"Tetrahedron\"");
+        let (line, column, message) = syntax_error(SystemDocument::from_authoring(&toml, AuthoringFormat::Toml, &ValidationPolicy::default()));
+        assert_eq!((line, column, message.as_str()), (1, 8, "unknown system kind 'Tetrahedron'"));
+
+        let toml = TETRAD_TOML.replace("ground = \"Office\"", "ground = 4");
+        let (line, column, message) = syntax_error(SystemDocument::from_authoring(&toml, AuthoringFormat::Toml, &ValidationPolicy::default()));
+        assert_eq!((line, column, message.as_str()), (5, 10, "ground must be text, not a number"));
+
+        let yaml = TETRAD_YAML.replace("direction: forward", "direction: sideways");
+        let (line, column, message) = syntax_error(SystemDocument::from_authoring(&yaml, AuthoringFormat::Yaml, &ValidationPolicy::default()));
+        assert_eq!((line, column), (12, 16));
+        assert!(message.starts_with("unknown direction 'sideways'"));
+
+        let yaml = TETRAD_YAML.replace("  ideal: Mission\n", "  ground: Mission\n");
+        let (line, _, message) = syntax_error(SystemDocument::from_authoring(&yaml, AuthoringFormat::Yaml, &ValidationPolicy::default()));
+        assert_eq!((line, message.as_str()), (5, "'ground' is given more than once"));
+    }
+
+    #[test]
+    fn test_monad_terms_are_limited() {
+        let terms: Vec<String> = (0..27).map(|index| format!("\"T{}\"", index)).collect();
+        let toml = format!("kind = \"Monad\"\nname = \"Many\"\nterms = [{}]\n", terms.join(", "));
+        let (line, column, message) = syntax_error(SystemDocument::from_authoring(&toml, AuthoringFormat::Toml, &ValidationPolicy::default()));
+        assert_eq!(message, "a Monad holds at most 26 terms");
+        assert_eq!(line, 3);
+        assert!(column > 100, "{}", column);
+    }
+
+    #[test]
+    fn test_syntax_errors_are_located() {
+        let (line, _, _) = syntax_error(SystemDocument::from_authoring("kind = \"Tetrad\"\nname = \n", AuthoringFormat::Toml, &ValidationPolicy::default()));
+        assert_eq!(line, 2);
+        let (line, _, _) = syntax_error(SystemDocument::from_authoring("kind: Tetrad\nname: [Work\n", AuthoringFormat::Yaml, &ValidationPolicy::default()));
+        assert!(line >= 2);
+        let tetrad = SystemDocument::from_system(&Tetrad::new("Work", "G", "I", "In", "D"));
+        assert!(SystemDocument::from_authoring(&tetrad.to_authoring(AuthoringFormat::Yaml), AuthoringFormat::Yaml, &ValidationPolicy::default()).is_ok());
+    }
+}
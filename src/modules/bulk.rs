@@ -0,0 +1,333 @@
+use std::fmt;
+
+use csv::{ReaderBuilder, StringRecord, Writer};
+
+use crate::modules::authoring::authoring_key;
+use crate::modules::document::SystemDocument;
+use crate::modules::error::SystematicsError;
+use crate::modules::system::{kind_name, order_for_kind};
+use crate::modules::validation::ValidationPolicy;
+
+/// A spreadsheet row that could not be read as a system; rows count from 1 at the header
+#[derive(Debug)]
+pub struct RowError {
+    pub row: usize,
+    pub error: SystematicsError,
+}
+
+impl fmt::Display for RowError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Row {}: {}", self.row, self.error)
+    }
+}
+
+/// Systems read from a CSV file, each with its row, and the rows that were rejected
+#[derive(Debug, Default)]
+pub struct CsvImport {
+    pub systems: Vec<(usize, SystemDocument)>,
+    pub errors: Vec<RowError>,
+}
+
+/// What a CSV column holds
+#[derive(Debug, Clone, PartialEq)]
+enum Column {
+    Name,
+    Order,
+    /// A canonical term or connective name, as its authoring key; which one it is
+    /// depends on the order of each row
+    Label(String),
+}
+
+/// Reads many systems from CSV, one per row.
+///
+/// The header row needs `name` and `order` (a number or kind such as "Tetrad"); every
+/// other header is a canonical term from `get_canonical_terms()` or a canonical
+/// connective name of some order, matched without regard to case or punctuation.
+/// A term cell holds the instance and a connective cell the user label. Cells for
+/// another order's terms must be empty, so Tetrads and Hexads can share one sheet.
+///
+/// Names, instances and labels are checked against the policy. A bad header fails the
+/// whole import; a bad row is reported in `errors` and the remaining rows are still read.
+pub fn read_csv(text: &str, policy: &ValidationPolicy) -> Result<CsvImport, SystematicsError> {
+    let mut reader = ReaderBuilder::new().flexible(true).from_reader(text.as_bytes());
+    let headers = reader.headers().map_err(csv_error)?.clone();
+    let columns = read_headers(&headers)?;
+
+    let mut import = CsvImport::default();
+    for record in reader.records() {
+        match record {
+            Ok(record) => {
+                let row = record.position().map_or(0, |position| position.line() as usize);
+                if record.iter().all(|cell| cell.trim().is_empty()) {
+                    continue;
+                }
+                let system = read_row(&record, &headers, &columns).and_then(|mut system| {
+                    system.validate_values(policy)?;
+                    Ok(system)
+                });
+                match system {
+                    Ok(system) => import.systems.push((row, system)),
+                    Err(error) => import.errors.push(RowError { row, error }),
+                }
+            }
+            Err(error) => {
+                let row = error.position().map_or(0, |position| position.line() as usize);
+                import.errors.push(RowError { row, error: csv_error(error) });
+            }
+        }
+    }
+    Ok(import)
+}
+
+/// Writes systems as CSV, one per row: name, order, a column per canonical term of
+/// any of the systems (in order of first appearance) and a column per connective that
+/// any of them relabels. Notes, directions, metadata and nested systems are left out.
+pub fn write_csv(systems: &[SystemDocument]) -> Result<String, SystematicsError> {
+    let mut headers = vec!["name".to_string(), "order".to_string()];
+    let mut keys = Vec::new();
+    for system in systems {
+        if system.order == 1 {
+            return Err(SystematicsError::Structure(format!(
+                "A Monad has no canonical terms to give CSV columns ('{}')",
+                system.name
+            )));
+        }
+        for label in system.terms.iter().filter_map(|term| term.canonical.as_deref()) {
+            add_column(&mut headers, &mut keys, label);
+        }
+    }
+    for system in systems {
+        for connective in system.connectives.iter().filter(|connective| connective.is_relabelled()) {
+            add_column(&mut headers, &mut keys, &connective.canonical_name);
+        }
+    }
+
+    let mut writer = Writer::from_writer(Vec::new());
+    writer.write_record(&headers).map_err(csv_error)?;
+    for system in systems {
+        let mut record = vec![system.name.clone(), system.order.to_string()];
+        for key in &keys {
+            let term = system
+                .terms
+                .iter()
+                .find(|term| term.canonical.as_deref().is_some_and(|label| authoring_key(label) == *key));
+            let connective = system
+                .connectives
+                .iter()
+                .find(|connective| connective.is_relabelled() && authoring_key(&connective.canonical_name) == *key);
+            let cell = match (term, connective) {
+                (Some(term), _) => term.instance.clone(),
+                (None, Some(connective)) => connective.label().to_string(),
+                (None, None) => String::new(),
+            };
+            record.push(cell);
+        }
+        writer.write_record(&record).map_err(csv_error)?;
+    }
+    let bytes = writer.into_inner().map_err(|error| SystematicsError::Io(error.into_error()))?;
+    String::from_utf8(bytes).map_err(|error| SystematicsError::Parse(error.to_string()))
+}
+
+fn add_column(headers: &mut Vec<String>, keys: &mut Vec<String>, label: &str) {
+    let key = authoring_key(label);
+    if !keys.contains(&key) {
+        headers.push(label.to_string());
+        keys.push(key);
+    }
+}
+
+fn read_headers(headers: &StringRecord) -> Result<Vec<Column>, SystematicsError> {
+    let known = known_labels();
+    let mut columns = Vec::new();
+    for header in headers {
+        let column = match authoring_key(header).as_str() {
+            "name" => Column::Name,
+            "order" | "kind" => Column::Order,
+            key if known.iter().any(|label| label == key) => Column::Label(key.to_string()),
+            _ => {
+                return Err(SystematicsError::Parse(format!(
+                    "Unknown CSV column '{}'; expected name, order, or a canonical term or connective",
+                    header
+                )));
+            }
+        };
+        if columns.contains(&column) {
+            return Err(SystematicsError::Parse(format!("CSV column '{}' is given more than once", header)));
+        }
+        columns.push(column);
+    }
+    for (column, header) in [(Column::Name, "name"), (Column::Order, "order")] {
+        if !columns.contains(&column) {
+            return Err(SystematicsError::Parse(format!("Missing CSV column '{}'", header)));
+        }
+    }
+    Ok(columns)
+}
+
+/// Authoring keys of every canonical term and connective, orders 2 to 12
+fn known_labels() -> Vec<String> {
+    (2..=12)
+        .filter_map(|order| SystemDocument::canonical(order, "").ok())
+        .flat_map(|system| {
+            let terms = system.terms.into_iter().filter_map(|term| term.canonical);
+            let connectives = system.connectives.into_iter().map(|connective| connective.canonical_name);
+            terms.chain(connectives).collect::<Vec<_>>()
+        })
+        .map(|label| authoring_key(&label))
+        .collect()
+}
+
+fn read_row(record: &StringRecord, headers: &StringRecord, columns: &[Column]) -> Result<SystemDocument, SystematicsError> {
+    let cell = |column: &Column| {
+        let index = columns.iter().position(|candidate| candidate == column)?;
+        record.get(index).map(str::trim).filter(|cell| !cell.is_empty())
+    };
+    let order = cell(&Column::Order).ok_or_else(|| SystematicsError::Structure("missing order".to_string()))?;
+    let order = match order.parse::<usize>() {
+        Ok(order) => kind_name(order).map(|_| order).ok_or(SystematicsError::UnknownOrder(order))?,
+        Err(_) => order_for_kind(order).ok_or_else(|| SystematicsError::UnknownKind(order.to_string()))?,
+    };
+    if order == 1 {
+        return Err(SystematicsError::Structure(
+            "A Monad has no canonical terms to fill from CSV columns".to_string(),
+        ));
+    }
+
+    let mut system = SystemDocument::canonical(order, cell(&Column::Name).unwrap_or_default())?;
+    let mut given = vec![false; system.terms.len()];
+    for (index, column) in columns.iter().enumerate() {
+        let (Column::Label(key), Some(value)) = (column, record.get(index).map(str::trim).filter(|cell| !cell.is_empty())) else {
+            continue;
+        };
+        let names = |label: &str| authoring_key(label) == *key;
+        if let Some(position) = system.terms.iter().position(|term| term.canonical.as_deref().is_some_and(names)) {
+            system.terms[position].instance = value.to_string();
+            given[position] = true;
+        } else if let Some(connective) = system.connectives.iter_mut().find(|connective| names(&connective.canonical_name)) {
+            if value != connective.canonical_name {
+                connective.user_label = Some(value.to_string());
+            }
+        } else {
+            return Err(SystematicsError::Structure(format!(
+                "column '{}' is not a term or connective of a {}",
+                &headers[index], system.kind
+            )));
+        }
+    }
+
+    let missing: Vec<String> = system
+        .terms
+        .iter()
+        .zip(&given)
+        .filter(|(_, given)| !**given)
+        .map(|(term, _)| format!("{} ({})", term.canonical.as_deref().unwrap_or_default(), term.position))
+        .collect();
+    if !missing.is_empty() {
+        return Err(SystematicsError::Structure(format!("missing terms {}", missing.join(", "))));
+    }
+    Ok(system)
+}
+
+fn csv_error(error: csv::Error) -> SystematicsError {
+    if error.is_io_error() {
+        match error.into_kind() {
+            csv::ErrorKind::Io(error) => SystematicsError::Io(error),
+            kind => SystematicsError::Parse(format!("{:?}", kind)),
+        }
+    } else {
+        SystematicsError::Parse(error.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::hexad::Hexad;
+    use crate::modules::tetrad::Tetrad;
+
+    const SHEET: &str = "\
+name,order,Ground,Ideal,Instrumental,Directive,Motivational Imperative,Resources,Values,Options,Criteria,Facts,Priorities
+Work,4,Office,Mission,Tools,Plan,Calling,,,,,,
+Team,Hexad,,,,,,People,Trust,Hire,Budget,Churn,Growth
+";
+
+    #[test]
+    fn test_read_mixed_orders() {
+        let import = read_csv(SHEET, &ValidationPolicy::default()).unwrap();
+
+        assert!(import.errors.is_empty(), "{:?}", import.errors);
+        let (row, work) = &import.systems[0];
+        assert_eq!(*row, 2);
+        assert_eq!(work.kind, "Tetrad");
+        assert_eq!(work.instances(), vec!["Office", "Mission", "Tools", "Plan"]);
+        assert_eq!(work.connectives[0].label(), "Calling");
+        assert!(!work.connectives[1].is_relabelled());
+        assert_eq!(import.systems[1].1.kind, "Hexad");
+    }
+
+    #[test]
+    fn test_bad_rows_are_reported_and_skipped() {
+        let sheet = "\
+name,order,ground,ideal,instrumental,directive
+Good,4,A,B,C,D
+Short,4,A,,C,
+Lost,13,A,B,C,D
+Wrong,Triad,A,B,C,D
+Bell,4,A,B\u{7}C,C,D
+";
+        let import = read_csv(sheet, &ValidationPolicy::default()).unwrap();
+
+        assert_eq!(import.systems.len(), 1);
+        assert_eq!(import.systems[0].1.name, "Good");
+        let errors: Vec<String> = import.errors.iter().map(RowError::to_string).collect();
+        assert_eq!(
+            errors,
+            vec![
+                "Row 3: missing terms Ideal (B), Directive (D)",
+                "Row 4: No system has 13 terms (1 to 12)",
+                "Row 5: column 'ground' is not a term or connective of a Triad",
+                "Row 6: ideal contains invalid character \\u{7}",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_bad_headers_fail_the_import() {
+        let error = read_csv("name,order,Gound\nWork,4,Office\n", &ValidationPolicy::default()).unwrap_err();
+        assert!(error.to_string().contains("Unknown CSV column 'Gound'"), "{}", error);
+
+        let error = read_csv("name,Ground,Ideal\n", &ValidationPolicy::default()).unwrap_err();
+        assert_eq!(error.to_string(), "Missing CSV column 'order'");
+
+        let error = read_csv("name,order,Ground,ground\n", &ValidationPolicy::default()).unwrap_err();
+        assert_eq!(error.to_string(), "CSV column 'ground' is given more than once");
+    }
+
+    #[test]
+    fn test_write_and_read_back() {
+        let mut work = SystemDocument::from_system(&Tetrad::new("Work, Q3", "Office", "Mission", "Tools", "Plan"));
+        work.connectives[5].user_label = Some("Delivery".to_string());
+        let team = SystemDocument::from_system(&Hexad::new("Team", "A", "B", "C", "D", "E", "F"));
+
+        let text = write_csv(&[work.clone(), team.clone()]).unwrap();
+        let lines: Vec<&str> = text.lines().collect();
+        assert!(lines[0].starts_with("name,order,Ground,Ideal,Instrumental,Directive,"), "{}", lines[0]);
+        assert!(lines[0].ends_with(",Demonstrable activity"), "{}", lines[0]);
+        assert!(lines[1].starts_with("\"Work, Q3\",4,Office,Mission,Tools,Plan,"), "{}", lines[1]);
+        assert!(lines[1].ends_with(",Delivery"), "{}", lines[1]);
+
+        let import = read_csv(&text, &ValidationPolicy::default()).unwrap();
+        assert!(import.errors.is_empty(), "{:?}", import.errors);
+        assert_eq!(import.systems[0].1, work);
+        assert_eq!(import.systems[1].1, team);
+    }
+
+    #[test]
+    fn test_monads_have_no_columns() {
+        let monad = SystemDocument::canonical(1, "Whole").unwrap();
+        assert!(write_csv(&[monad]).is_err());
+
+        let import = read_csv("name,order\nWhole,1\n", &ValidationPolicy::default()).unwrap();
+        assert_eq!(import.errors.len(), 1);
+    }
+}
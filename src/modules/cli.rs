@@ -0,0 +1,399 @@
+mod diagrams;
+mod edits;
+mod exchange;
+mod nesting;
+mod revisions;
+mod systems;
+mod triads;
+
+use std::io::Write;
+use std::path::PathBuf;
+
+use crate::modules::connective::Position;
+use crate::modules::decad::Decad;
+use crate::modules::dodecad::Dodecad;
+use crate::modules::dyad::Dyad;
+use crate::modules::ennead::Ennead;
+use crate::modules::error::SystematicsError;
+use crate::modules::heptad::Heptad;
+use crate::modules::hexad::Hexad;
+use crate::modules::monad::Monad;
+use crate::modules::octad::Octad;
+use crate::modules::pentad::Pentad;
+use crate::modules::prompter::{IoPrompter, Prompter};
+use crate::modules::store::Store;
+use crate::modules::system::System;
+use crate::modules::tetrad::Tetrad;
+use crate::modules::triad::Triad;
+use crate::modules::undecad::Undecad;
+use crate::modules::validation::ValidationPolicy;
+
+/// Exit status for a command that ran successfully
+pub const EXIT_OK: i32 = 0;
+/// Exit status for a command that failed while running (e.g. unknown id)
+pub const EXIT_FAILURE: i32 = 1;
+/// Exit status for a command line that could not be understood
+pub const EXIT_USAGE: i32 = 2;
+
+pub const USAGE: &str = "\
+Usage: systematics [COMMAND] [OPTIONS]
+
+Without a command, runs the interactive walkthrough.
+
+Commands:
+  new <kind|order> [--name NAME] [--<term> VALUE]... [--connective A-B=LABEL]...
+  show <id> [--path C/B] [--at REVISION]
+  history <id>
+  diff <id> <other-id> [--color]
+  diff <id> --at REVISION [--color]
+  undo <id>
+  redo <id>
+  tree <id>
+  nest <id> <position> <child-id|none>
+  dot <id> [--circle] [--hide-defaults] [--nested]
+  svg <id> [--connectives] [--no-labels] [--size PIXELS]
+  export <id> [--format markdown|html|toml|yaml]
+  export --format csv [<id>...] [--order N] [--name TEXT]
+  import <file>
+  list [--order N] [--name TEXT]
+  edit <id> [--name NAME] [--<term> VALUE]... [--connective A-B=LABEL]...
+  edit <id> [C|B<>E]
+  delete <id>
+  triads <id> [--with LETTERS] [--permutations] [--offset N] [--limit N]
+  subsystem <id> --at LETTERS [--save]
+  embed <id> <into-id> --at LETTERS [--force]
+  permute <A> <B> <C>
+  permute <id> [--save]
+
+Terms are set with the canonical term in kebab case (--active, --higher-potential)
+or with the position letter (--a, --b). Monad terms are added with repeated --term.
+An empty connective label (--connective A-C=) restores the canonical name.
+`new` with no values and `permute` with no terms fall back to the interactive flow.
+`edit` with no values opens an edit session showing the current values as defaults;
+`edit 1 C` or `edit 1 B<>E` starts at that term or connective. Changes are saved together.
+`permute <id>` gives the six laws of a stored Triad; --save keeps them with it.
+`triads` lists every three-term combination; --with ACE keeps those holding all
+of A, C and E, and --permutations adds each one's six laws.
+`nest 1 C 2` makes system 2 elaborate term C of system 1; `show 1 --path C` drills
+down to it and `tree 1` shows everything nested below system 1.
+`dot` writes Graphviz source (e.g. `systematics dot 1 | dot -Tsvg`); --circle lays the
+terms on a circle, --hide-defaults drops canonical connective names and --nested adds
+nested systems as clusters.
+`svg` draws the system's Bennett figure (triangle, square, hexagram, enneagram, ...);
+--connectives adds every connective as a dashed line.
+`export` writes a report with the terms, connectives, six laws (for a Triad) and diagram,
+or with --format toml|yaml a file to edit by hand and read back with `import work.toml`.
+`export --format csv` writes many systems (all but Monads, unless ids are given), one
+per row with a column per canonical term; `import sheet.csv` saves every valid row and
+reports the rows it skipped.
+Every change is kept in the system's history with its time and author; `undo` and
+`redo` step through it and `show 1 --at 2` shows revision 2.
+`diff 1 2` compares two systems of the same order: name, instances by position and
+connective labels; `diff 1 --at 2` compares revision 2 of system 1 with its current state.
+`subsystem 1 --at AD` is the Dyad of positions A and D; `embed 2 1 --at ACEG` places
+system 2 at those positions of system 1, refusing to overwrite values unless --force.
+
+Options:
+  --json         Print machine-readable JSON
+  --store DIR    Store directory (default: $SYSTEMATICS_HOME or ~/.systematics)
+  --author NAME  Who changes are recorded under (default: $SYSTEMATICS_AUTHOR or $USER)
+  --color        Color changed values in terminal output (diff)
+  -h, --help     Show this help";
+
+/// Flags that never take a value
+const SWITCHES: &[&str] = &["json", "help", "save", "permutations", "force", "circle", "hide-defaults", "nested", "connectives", "no-labels", "color"];
+
+/// How a command failed, which decides the exit status and whether the usage text is shown
+#[derive(Debug)]
+enum Failure {
+    /// A command or option this tool doesn't know
+    Unknown(String),
+    Usage(String),
+    Error(SystematicsError),
+}
+
+/// Bad values and unknown kinds come from the command line, so they are usage errors
+impl From<SystematicsError> for Failure {
+    fn from(error: SystematicsError) -> Self {
+        match error {
+            SystematicsError::Validation { .. } | SystematicsError::UnknownKind(_) | SystematicsError::UnknownOrder(_) => {
+                Failure::Usage(error.to_string())
+            }
+            error => Failure::Error(error),
+        }
+    }
+}
+
+impl From<serde_json::Error> for Failure {
+    fn from(error: serde_json::Error) -> Self {
+        Failure::Error(error.into())
+    }
+}
+
+impl From<std::io::Error> for Failure {
+    fn from(error: std::io::Error) -> Self {
+        Failure::Error(error.into())
+    }
+}
+
+type CommandResult = Result<(), Failure>;
+
+/// Command-line arguments split into positionals, `--key value` options and switches
+#[derive(Debug, Default, PartialEq)]
+struct Arguments {
+    positional: Vec<String>,
+    options: Vec<(String, String)>,
+    switches: Vec<String>,
+}
+
+impl Arguments {
+    fn parse(args: &[String]) -> Result<Self, Failure> {
+        let mut parsed = Arguments::default();
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            let key = match arg.as_str() {
+                "-h" => "help",
+                arg => match arg.strip_prefix("--") {
+                    Some(key) => key,
+                    None => {
+                        parsed.positional.push(arg.to_string());
+                        continue;
+                    }
+                },
+            };
+
+            if let Some((key, value)) = key.split_once('=') {
+                parsed.options.push((key.to_lowercase(), value.to_string()));
+            } else if SWITCHES.contains(&key) {
+                parsed.switches.push(key.to_string());
+            } else {
+                let value = args.next().ok_or_else(|| Failure::Usage(format!("--{} needs a value", key)))?;
+                parsed.options.push((key.to_lowercase(), value.to_string()));
+            }
+        }
+        Ok(parsed)
+    }
+
+    fn switch(&self, name: &str) -> bool {
+        self.switches.iter().any(|switch| switch == name)
+    }
+
+    /// Last value given for an option
+    fn option(&self, name: &str) -> Option<&str> {
+        self.options.iter().rev().find(|(key, _)| key == name).map(|(_, value)| value.as_str())
+    }
+
+    /// Options other than the ones every command understands
+    fn edits(&self) -> impl Iterator<Item = &(String, String)> {
+        self.options.iter().filter(|(key, _)| !matches!(key.as_str(), "store" | "author"))
+    }
+
+    /// Optional numeric option, e.g. --order 5
+    fn number(&self, key: &str) -> Result<Option<usize>, Failure> {
+        self.option(key)
+            .map(|value| value.parse::<usize>().map_err(|_| Failure::Usage(format!("Invalid {} '{}'", key, value))))
+            .transpose()
+    }
+
+    fn id(&self) -> Result<u64, Failure> {
+        self.id_at(1)
+    }
+
+    /// System id given as the positional argument at an index
+    fn id_at(&self, index: usize) -> Result<u64, Failure> {
+        let id = self.positional.get(index).ok_or_else(|| Failure::Usage("Missing system id".to_string()))?;
+        id.parse().map_err(|_| Failure::Usage(format!("Invalid system id '{}'", id)))
+    }
+
+    /// Positions from --at, e.g. "ACEG" or "A,C,E,G"
+    fn positions(&self) -> Result<Vec<Position>, Failure> {
+        self.letters("at")?.ok_or_else(|| Failure::Usage("Missing --at, e.g. --at AD".to_string()))
+    }
+
+    /// Position letters of an option, separated by nothing, commas or spaces
+    fn letters(&self, key: &str) -> Result<Option<Vec<Position>>, Failure> {
+        let Some(letters) = self.option(key) else {
+            return Ok(None);
+        };
+        letters
+            .chars()
+            .filter(|letter| *letter != ',' && !letter.is_whitespace())
+            .map(|letter| match letter.is_ascii_alphabetic() {
+                true => Ok(Position::new(letter)),
+                false => Err(Failure::Usage(format!("Invalid --{} '{}', expected position letters such as ACE", key, letters))),
+            })
+            .collect::<Result<Vec<Position>, Failure>>()
+            .map(Some)
+    }
+}
+
+/// Runs a command line (without the program name) and returns the exit status
+pub fn run(args: &[String], out: &mut dyn Write, err: &mut dyn Write) -> i32 {
+    let result = Arguments::parse(args).and_then(|arguments| dispatch(&arguments, out));
+    match result {
+        Ok(()) => EXIT_OK,
+        Err(Failure::Unknown(message)) => {
+            let _ = writeln!(err, "Error: {}\n\n{}", message, USAGE);
+            EXIT_USAGE
+        }
+        Err(Failure::Usage(message)) => {
+            let _ = writeln!(err, "Error: {}\nRun 'systematics --help' for usage.", message);
+            EXIT_USAGE
+        }
+        Err(Failure::Error(error)) => {
+            let _ = writeln!(err, "Error: {}", error);
+            EXIT_FAILURE
+        }
+    }
+}
+
+fn dispatch(arguments: &Arguments, out: &mut dyn Write) -> CommandResult {
+    if arguments.switch("help") {
+        writeln!(out, "{}", USAGE)?;
+        return Ok(());
+    }
+
+    let command = arguments.positional.first().map(String::as_str).unwrap_or_default();
+    match command {
+        "new" => systems::new(arguments, out),
+        "show" => systems::show(arguments, out),
+        "history" => revisions::history(arguments, out),
+        "diff" => revisions::diff(arguments, out),
+        "undo" => revisions::undo(arguments, out),
+        "redo" => revisions::redo(arguments, out),
+        "tree" => nesting::tree(arguments, out),
+        "nest" => nesting::nest(arguments, out),
+        "dot" => diagrams::dot(arguments, out),
+        "svg" => diagrams::svg(arguments, out),
+        "export" => exchange::export(arguments, out),
+        "import" => exchange::import(arguments, out),
+        "list" => systems::list(arguments, out),
+        "edit" => systems::edit(arguments, out),
+        "delete" => systems::delete(arguments, out),
+        "triads" => triads::triads(arguments, out),
+        "subsystem" => nesting::subsystem(arguments, out),
+        "embed" => nesting::embed(arguments, out),
+        "permute" => triads::permute(arguments, out),
+        "" => Err(Failure::Unknown("Missing command".to_string())),
+        other => Err(Failure::Unknown(format!("Unknown command '{}'", other))),
+    }
+}
+
+fn open_store(arguments: &Arguments) -> Result<Store, Failure> {
+    let root = arguments.option("store").map(PathBuf::from).unwrap_or_else(Store::default_root);
+    let store = Store::open(&root)?;
+    Ok(match arguments.option("author") {
+        Some(author) => store.with_author(author),
+        None => store,
+    })
+}
+
+/// Runs the interactive creation flow on the terminal for a system with the given number of terms
+pub fn create_interactive(order: usize) -> Result<Box<dyn System>, SystematicsError> {
+    let policy = ValidationPolicy::for_workspace(&Store::default_root())?;
+    create_with(order, &mut IoPrompter::stdio().with_policy(policy))
+}
+
+/// Runs the interactive creation flow through any prompter
+pub fn create_with(order: usize, prompter: &mut dyn Prompter) -> Result<Box<dyn System>, SystematicsError> {
+    let system: Box<dyn System> = match order {
+        1 => Box::new(Monad::create_with(prompter)?),
+        2 => Box::new(Dyad::create_with(prompter)?),
+        3 => Box::new(Triad::create_with(prompter)?),
+        4 => Box::new(Tetrad::create_with(prompter)?),
+        5 => Box::new(Pentad::create_with(prompter)?),
+        6 => Box::new(Hexad::create_with(prompter)?),
+        7 => Box::new(Heptad::create_with(prompter)?),
+        8 => Box::new(Octad::create_with(prompter)?),
+        9 => Box::new(Ennead::create_with(prompter)?),
+        10 => Box::new(Decad::create_with(prompter)?),
+        11 => Box::new(Undecad::create_with(prompter)?),
+        12 => Box::new(Dodecad::create_with(prompter)?),
+        order => return Err(SystematicsError::UnknownOrder(order)),
+    };
+    Ok(system)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::prompter::ScriptedPrompter;
+    use std::fs;
+
+    /// The temporary directory holding a test's store
+    pub(super) fn store_root(store: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("systematics-cli-{}-{}", store, std::process::id()))
+    }
+
+    /// Runs a command line against a store in its own temporary directory
+    pub(super) fn run_in(store: &str, args: &[&str]) -> (i32, String, String) {
+        let root = store_root(store);
+        let mut args: Vec<String> = args.iter().map(|arg| arg.to_string()).collect();
+        args.extend(["--store".to_string(), root.display().to_string()]);
+
+        let (mut out, mut err) = (Vec::new(), Vec::new());
+        let status = run(&args, &mut out, &mut err);
+        (status, String::from_utf8(out).unwrap(), String::from_utf8(err).unwrap())
+    }
+
+    pub(super) fn cleanup(store: &str) {
+        let _ = fs::remove_dir_all(store_root(store));
+    }
+
+    #[test]
+    fn test_parse_arguments() {
+        let args: Vec<String> = ["new", "triad", "--name", "Will", "--json", "--active=Push"]
+            .iter()
+            .map(|arg| arg.to_string())
+            .collect();
+        let parsed = Arguments::parse(&args).unwrap();
+
+        assert_eq!(parsed.positional, ["new", "triad"]);
+        assert_eq!(parsed.option("name"), Some("Will"));
+        assert_eq!(parsed.option("active"), Some("Push"));
+        assert!(parsed.switch("json"));
+        assert!(matches!(Arguments::parse(&["--name".to_string()]), Err(Failure::Usage(_))));
+    }
+
+    #[test]
+    fn test_usage_errors() {
+        // Unknown commands and options are answered with the usage text
+        let unknown: &[&[&str]] = &[&[], &["frobnicate"], &["new", "triad", "--sideways", "X"]];
+        for args in unknown {
+            let (status, _, err) = run_in("usage", args);
+            assert_eq!(status, EXIT_USAGE, "{:?}", args);
+            assert!(err.contains("Usage: systematics"), "{:?}", args);
+        }
+
+        // Other mistakes get only their own message
+        let cases: &[&[&str]] = &[
+            &["new", "enneagram", "--name", "X"],
+            &["new", "tetrad", "--connective", "A-A=X"],
+            &["show", "one"],
+            &["edit", "1", "ABC"],
+            &["permute", "A", "B"],
+        ];
+        for args in cases {
+            let (status, _, err) = run_in("usage", args);
+            assert_eq!(status, EXIT_USAGE, "{:?}", args);
+            assert!(!err.contains("Usage: systematics"), "{:?}", args);
+            assert!(err.starts_with("Error: ") && err.ends_with("\nRun 'systematics --help' for usage.\n"), "{}", err);
+        }
+        cleanup("usage");
+    }
+
+    #[test]
+    fn test_create_with_every_order() {
+        for order in 1..=12 {
+            // Blank answers take every default; Monad and Dyad need a few real ones
+            let answers: &[&str] = match order {
+                1 => &["Self", "Body", ""],
+                2 => &["Pair", "Being", "Doing"],
+                _ => &[],
+            };
+            let system = create_with(order, &mut ScriptedPrompter::new(answers)).unwrap();
+            assert_eq!(system.order(), order);
+        }
+        assert!(create_with(13, &mut ScriptedPrompter::new(&[])).is_err());
+    }
+}
@@ -0,0 +1,63 @@
+use std::io::Write;
+
+use crate::modules::cli::{open_store, Arguments, CommandResult, Failure};
+use crate::modules::diagram::SvgOptions;
+use crate::modules::graph::DotOptions;
+
+pub(super) fn dot(arguments: &Arguments, out: &mut dyn Write) -> CommandResult {
+    let options = DotOptions {
+        circle: arguments.switch("circle"),
+        hide_default_labels: arguments.switch("hide-defaults"),
+        nested: arguments.switch("nested"),
+    };
+    let tree = open_store(arguments)?.tree(arguments.id()?)?;
+    write!(out, "{}", tree.to_dot(&options))?;
+    Ok(())
+}
+
+pub(super) fn svg(arguments: &Arguments, out: &mut dyn Write) -> CommandResult {
+    let mut options = SvgOptions {
+        labels: !arguments.switch("no-labels"),
+        connectives: arguments.switch("connectives"),
+        ..SvgOptions::default()
+    };
+    if let Some(size) = arguments.number("size")? {
+        options.size = u32::try_from(size)
+            .ok()
+            .filter(|&size| size > 0)
+            .ok_or_else(|| Failure::Usage(format!("Invalid size '{}', expected 1 to {} pixels", size, u32::MAX)))?;
+    }
+    let stored = open_store(arguments)?.get(arguments.id()?)?;
+    write!(out, "{}", stored.system.to_svg(&options))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::modules::cli::tests::{cleanup, run_in};
+    use crate::modules::cli::{EXIT_OK, EXIT_USAGE};
+
+    #[test]
+    fn test_svg() {
+        cleanup("svg");
+        run_in("svg", &["new", "ennead", "--name", "Project", "--a", "Kick-off", "--connective", "A-D=Plan"]);
+
+        let (status, out, _) = run_in("svg", &["svg", "1"]);
+        assert_eq!(status, EXIT_OK);
+        assert!(out.starts_with("<svg xmlns="), "{}", out);
+        assert!(out.contains("<tspan font-weight=\"bold\">Initiation</tspan>"), "{}", out);
+        assert!(!out.contains(">Plan</text>"));
+
+        let (_, out, _) = run_in("svg", &["svg", "1", "--connectives", "--no-labels", "--size", "300"]);
+        assert!(out.contains(">Plan</text>"), "{}", out);
+        assert!(!out.contains("Kick-off"));
+        let (status, _, _) = run_in("svg", &["svg", "1", "--size", "big"]);
+        assert_eq!(status, EXIT_USAGE);
+        let (status, _, err) = run_in("svg", &["svg", "1", "--size", "0"]);
+        assert_eq!(status, EXIT_USAGE);
+        assert!(err.contains("Invalid size '0'"), "{}", err);
+        let (status, _, _) = run_in("svg", &["svg", "1", "--size", "4294967296"]);
+        assert_eq!(status, EXIT_USAGE);
+        cleanup("svg");
+    }
+}
@@ -0,0 +1,135 @@
+use crate::modules::cli::{Arguments, Failure};
+use crate::modules::connective::Position;
+use crate::modules::document::{SystemDocument, TermDocument};
+use crate::modules::error::SystematicsError;
+use crate::modules::monad::Monad;
+use crate::modules::validation::ValidationPolicy;
+
+/// Applies --name, term and --connective options to a document, checking values against the policy
+pub(super) fn apply_edits(document: &mut SystemDocument, arguments: &Arguments, policy: &ValidationPolicy) -> Result<(), Failure> {
+    for (key, value) in arguments.edits() {
+        match key.as_str() {
+            "name" => document.name = checked(key, value, policy)?,
+            "term" if document.order == 1 => {
+                let position = Position::from_index(document.terms.len()).ok_or_else(|| {
+                    SystematicsError::Structure(format!("A Monad holds at most {} terms", Monad::MAX_TERMS))
+                })?;
+                document.terms.push(TermDocument {
+                    position,
+                    canonical: None,
+                    instance: checked(key, value, policy)?,
+                    child: None,
+                });
+            }
+            "connective" => set_connective(document, value, policy)?,
+            key => {
+                let term = document
+                    .terms
+                    .iter_mut()
+                    .find(|term| names_term(term, key))
+                    .ok_or_else(|| Failure::Unknown(format!("{} has no term --{}", document.kind, key)))?;
+                term.instance = checked(key, value, policy)?;
+            }
+        }
+    }
+
+    // Saved six laws follow the Triad's new instances
+    if !document.permutations.is_empty() {
+        *document = document.clone().with_six_laws()?;
+    }
+
+    // Rebuilding checks the edited document is still a valid system
+    document.to_system()?;
+    Ok(())
+}
+
+/// Sets a connective from "A-C=Label" (an empty label restores the canonical name)
+fn set_connective(document: &mut SystemDocument, value: &str, policy: &ValidationPolicy) -> Result<(), Failure> {
+    let invalid = || Failure::Usage(format!("Invalid connective '{}', expected e.g. A-C=Label", value));
+    let (pair, label) = value.split_once('=').ok_or_else(invalid)?;
+    let (from, to) = pair.trim().split_once('-').ok_or_else(invalid)?;
+    let single = |letter: &str| {
+        let mut chars = letter.trim().chars();
+        match (chars.next(), chars.next()) {
+            (Some(letter), None) => Ok(letter),
+            _ => Err(invalid()),
+        }
+    };
+    let (from, to) = (single(from)?, single(to)?);
+
+    let connective = document
+        .connectives
+        .iter_mut()
+        .find(|connective| connective.joins(from, to))
+        .ok_or_else(|| Failure::Usage(format!("{} has no connective {}<>{}", document.kind, from, to)))?;
+    connective.user_label = match policy.clean(label) {
+        label if label.is_empty() || label == connective.canonical_name => None,
+        _ => Some(checked("connective", label, policy)?),
+    };
+    Ok(())
+}
+
+/// Cleans an option value with the policy
+fn checked(key: &str, value: &str, policy: &ValidationPolicy) -> Result<String, SystematicsError> {
+    policy.validate(value).map_err(|reason| SystematicsError::validation(&format!("--{}", key), reason))
+}
+
+/// Check if an option names a term, by position letter or kebab-case canonical label
+fn names_term(term: &TermDocument, key: &str) -> bool {
+    let by_letter = key.len() == 1 && key.eq_ignore_ascii_case(&term.position.to_string());
+    let by_label = term.canonical.as_deref().is_some_and(|label| kebab_case(label) == key);
+    by_letter || by_label
+}
+
+/// "Higher Potential" -> "higher-potential"
+fn kebab_case(label: &str) -> String {
+    label.split_whitespace().map(str::to_lowercase).collect::<Vec<_>>().join("-")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::cli::tests::{cleanup, run_in};
+    use crate::modules::cli::{EXIT_FAILURE, EXIT_OK, EXIT_USAGE};
+    use crate::modules::store::Store;
+
+    #[test]
+    fn test_values_follow_workspace_policy() {
+        cleanup("policy");
+        let (status, out, _) = run_in("policy", &["new", "triad", "--name", "  Mind/Body  ", "--active", "A & B"]);
+        assert_eq!(status, EXIT_OK);
+        assert_eq!(out, "Created Triad 1: Mind/Body\n");
+
+        let root = std::env::temp_dir().join(format!("systematics-cli-policy-{}", std::process::id()));
+        Store::open(&root).unwrap().set_policy(&ValidationPolicy::strict()).unwrap();
+        let (status, _, err) = run_in("policy", &["edit", "1", "--name", "Mind/Body"]);
+        assert_eq!(status, EXIT_USAGE);
+        assert!(err.starts_with("Error: --name contains invalid character '/'"), "{}", err);
+        let (status, _, err) = run_in("policy", &["new", "tetrad", "--connective", "A-B=x<y"]);
+        assert_eq!(status, EXIT_USAGE);
+        assert!(err.starts_with("Error: --connective contains invalid character '<'"), "{}", err);
+        cleanup("policy");
+    }
+
+    #[test]
+    fn test_monad_terms() {
+        cleanup("monad");
+        run_in("monad", &["new", "monad", "--name", "Self", "--term", "Body", "--term", "Mind"]);
+
+        let (_, out, _) = run_in("monad", &["show", "1"]);
+        assert!(out.contains("- Body\n- Mind\n"), "{}", out);
+
+        let mut args = vec!["new", "monad", "--name", "Crowd"];
+        args.extend(std::iter::repeat_n(["--term", "Someone"], 27).flatten());
+        let (status, _, err) = run_in("monad", &args);
+        assert_eq!(status, EXIT_FAILURE);
+        assert_eq!(err, "Error: A Monad holds at most 26 terms\n");
+        cleanup("monad");
+    }
+
+    #[test]
+    fn test_kebab_case() {
+        assert_eq!(kebab_case("Higher Potential"), "higher-potential");
+        assert_eq!(kebab_case("Smallest Significant Holon"), "smallest-significant-holon");
+    }
+}
@@ -0,0 +1,197 @@
+use std::io::Write;
+use std::path::Path;
+
+use crate::modules::authoring::AuthoringFormat;
+use crate::modules::bulk;
+use crate::modules::cli::{open_store, Arguments, CommandResult, Failure};
+use crate::modules::document::SystemDocument;
+use crate::modules::error::SystematicsError;
+use crate::modules::report::ReportFormat;
+use crate::modules::store::{Query, Store};
+
+pub(super) fn export(arguments: &Arguments, out: &mut dyn Write) -> CommandResult {
+    let name = arguments.option("format").unwrap_or("markdown");
+    if name.eq_ignore_ascii_case("csv") {
+        return export_csv(arguments, out);
+    }
+    let (report, authoring) = (ReportFormat::from_name(name), AuthoringFormat::from_name(name));
+    if report.is_none() && authoring.is_none() {
+        return Err(Failure::Usage(format!("Unknown export format '{}'", name)));
+    }
+    let stored = open_store(arguments)?.get(arguments.id()?)?;
+    match (report, authoring) {
+        (Some(format), _) => write!(out, "{}", stored.system.report(format))?,
+        (_, Some(format)) => write!(out, "{}", stored.system.to_authoring(format))?,
+        (None, None) => {}
+    }
+    Ok(())
+}
+
+/// Many systems as CSV: the given ids, or every system matching --order and --name
+fn export_csv(arguments: &Arguments, out: &mut dyn Write) -> CommandResult {
+    let store = open_store(arguments)?;
+    let systems: Vec<SystemDocument> = if arguments.positional.len() > 1 {
+        (1..arguments.positional.len())
+            .map(|index| Ok(store.get(arguments.id_at(index)?)?.system))
+            .collect::<Result<_, Failure>>()?
+    } else {
+        let query = Query {
+            order: arguments.number("order")?,
+            name: arguments.option("name").map(str::to_string),
+        };
+        // Monads have no canonical columns, so only named ids can ask for one
+        store.query(&query)?.into_iter().map(|stored| stored.system).filter(|system| system.order != 1).collect()
+    };
+    write!(out, "{}", bulk::write_csv(&systems)?)?;
+    Ok(())
+}
+
+pub(super) fn import(arguments: &Arguments, out: &mut dyn Write) -> CommandResult {
+    let path = arguments
+        .positional
+        .get(1)
+        .map(Path::new)
+        .ok_or_else(|| Failure::Usage("Missing file, e.g. 'import work.toml'".to_string()))?;
+    let store = open_store(arguments)?;
+    if path.extension().is_some_and(|extension| extension.eq_ignore_ascii_case("csv")) {
+        return import_csv(arguments, path, &store, out);
+    }
+    let document = SystemDocument::load(path, &store.policy()?).map_err(|error| match error {
+        // Name the file so the line and column can be found
+        SystematicsError::Syntax { .. } => SystematicsError::Parse(format!("{}: {}", path.display(), error)),
+        error => error,
+    })?;
+
+    let stored = store.create_document(document)?;
+    if arguments.switch("json") {
+        writeln!(out, "{}", serde_json::to_string_pretty(&stored)?)?;
+    } else {
+        writeln!(out, "Created {} {}: {}", stored.system.kind, stored.id, stored.system.name)?;
+    }
+    Ok(())
+}
+
+/// Saves every valid row of a CSV file, then fails listing the rows that were skipped
+fn import_csv(arguments: &Arguments, path: &Path, store: &Store, out: &mut dyn Write) -> CommandResult {
+    let text = std::fs::read_to_string(path)?;
+    let import = bulk::read_csv(&text, &store.policy()?).map_err(|error| SystematicsError::Parse(format!("{}: {}", path.display(), error)))?;
+
+    let mut created = Vec::new();
+    for (row, system) in import.systems {
+        let stored = store.create_document(system)?;
+        if !arguments.switch("json") {
+            writeln!(out, "Created {} {}: {} (row {})", stored.system.kind, stored.id, stored.system.name, row)?;
+        }
+        created.push(stored);
+    }
+    if arguments.switch("json") {
+        writeln!(out, "{}", serde_json::to_string_pretty(&created)?)?;
+    }
+
+    if import.errors.is_empty() {
+        return Ok(());
+    }
+    let mut message = format!("{}: {} row(s) not imported", path.display(), import.errors.len());
+    for error in &import.errors {
+        message.push_str(&format!("\n  {}", error));
+    }
+    Err(Failure::Error(SystematicsError::Parse(message)))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use crate::modules::cli::tests::{cleanup, run_in, store_root};
+    use crate::modules::cli::{EXIT_FAILURE, EXIT_OK, EXIT_USAGE};
+
+    #[test]
+    fn test_export_reports() {
+        cleanup("reports");
+        run_in("reports", &["new", "ennead", "--name", "Project", "--a", "Kick-off"]);
+
+        let (status, out, _) = run_in("reports", &["export", "--format", "html", "1"]);
+        assert_eq!(status, EXIT_OK);
+        assert!(out.contains("<tr><td>A</td><td>Initiation</td><td>Kick-off</td></tr>"), "{}", out);
+        let (_, out, _) = run_in("reports", &["export", "1"]);
+        assert!(out.starts_with("# Project\n"), "{}", out);
+        let (status, _, err) = run_in("reports", &["export", "1", "--format", "pdf"]);
+        assert_eq!(status, EXIT_USAGE);
+        assert!(err.contains("Unknown export format 'pdf'"), "{}", err);
+        cleanup("reports");
+    }
+
+    #[test]
+    fn test_export_and_import_authoring_files() {
+        cleanup("authoring");
+        run_in("authoring", &["new", "tetrad", "--name", "Work", "--a", "Office", "--connective", "A-B=Calling"]);
+
+        let (status, toml, _) = run_in("authoring", &["export", "1", "--format", "toml"]);
+        assert_eq!(status, EXIT_OK);
+        assert!(toml.contains("[terms]\nground = \"Office\"\n"), "{}", toml);
+        assert!(toml.contains("motivational-imperative = \"Calling\"\n"), "{}", toml);
+
+        let root = store_root("authoring");
+        let file = root.join("work.toml");
+        std::fs::write(&file, toml.replace("\"Office\"", "\"Desk\"")).unwrap();
+        let (status, out, _) = run_in("authoring", &["import", file.to_str().unwrap()]);
+        assert_eq!(status, EXIT_OK);
+        assert_eq!(out, "Created Tetrad 2: Work\n");
+        let (_, out, _) = run_in("authoring", &["show", "2"]);
+        assert!(out.contains("A (Ground): Desk\n"), "{}", out);
+
+        let (_, yaml, _) = run_in("authoring", &["export", "2", "--format", "yaml"]);
+        let file = root.join("work.yaml");
+        std::fs::write(&file, yaml.replace("  ideal:", "  idea:")).unwrap();
+        let (status, _, err) = run_in("authoring", &["import", file.to_str().unwrap()]);
+        assert_eq!(status, EXIT_FAILURE);
+        assert!(err.contains("work.yaml: Line 5, column 3: unknown term 'idea' for a Tetrad"), "{}", err);
+
+        std::fs::write(&file, yaml.replace("\"Desk\"", "\"De\\tsk\"")).unwrap();
+        let (status, _, err) = run_in("authoring", &["import", file.to_str().unwrap()]);
+        assert_eq!(status, EXIT_USAGE);
+        assert!(err.contains("ground contains invalid character"), "{}", err);
+        cleanup("authoring");
+    }
+
+    #[test]
+    fn test_csv_import_and_export() {
+        cleanup("csv");
+        let root = store_root("csv");
+        fs::create_dir_all(&root).unwrap();
+        let file = root.join("sheet.csv");
+        fs::write(
+            &file,
+            "name,order,Ground,Ideal,Instrumental,Directive,Motivational imperative\n\
+             Work,Tetrad,Office,Mission,Tools,Plan,Calling\n\
+             Half,4,Office,,Tools,\n\
+             Home,4,Kitchen,Rest,Pans,Meals,\n",
+        )
+        .unwrap();
+
+        let (status, out, err) = run_in("csv", &["import", file.to_str().unwrap()]);
+        assert_eq!(status, EXIT_FAILURE);
+        assert_eq!(out, "Created Tetrad 1: Work (row 2)\nCreated Tetrad 2: Home (row 4)\n");
+        assert!(err.contains("sheet.csv: 1 row(s) not imported\n  Row 3: missing terms Ideal (B), Directive (D)\n"), "{}", err);
+
+        run_in("csv", &["new", "monad", "--name", "Whole", "--term", "One"]);
+        let (status, out, _) = run_in("csv", &["export", "--format", "csv"]);
+        assert_eq!(status, EXIT_OK);
+        assert_eq!(
+            out,
+            "name,order,Ground,Ideal,Instrumental,Directive,Motivational imperative\n\
+             Work,4,Office,Mission,Tools,Plan,Calling\n\
+             Home,4,Kitchen,Rest,Pans,Meals,\n"
+        );
+
+        let (status, out, _) = run_in("csv", &["export", "2", "--format", "csv"]);
+        assert_eq!(status, EXIT_OK);
+        assert_eq!(out, "name,order,Ground,Ideal,Instrumental,Directive\nHome,4,Kitchen,Rest,Pans,Meals\n");
+
+        fs::write(&file, "name,order,Gound\nWork,4,Office\n").unwrap();
+        let (status, _, err) = run_in("csv", &["import", file.to_str().unwrap()]);
+        assert_eq!(status, EXIT_FAILURE);
+        assert!(err.contains("Unknown CSV column 'Gound'"), "{}", err);
+        cleanup("csv");
+    }
+}
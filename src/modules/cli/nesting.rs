@@ -0,0 +1,186 @@
+use std::io::Write;
+
+use crate::modules::cli::systems::render_document;
+use crate::modules::cli::{open_store, Arguments, CommandResult, Failure};
+use crate::modules::connective::Position;
+use crate::modules::error::SystematicsError;
+use crate::modules::hierarchy::SystemTree;
+
+pub(super) fn tree(arguments: &Arguments, out: &mut dyn Write) -> CommandResult {
+    let tree = open_store(arguments)?.tree(arguments.id()?)?;
+    if arguments.switch("json") {
+        writeln!(out, "{}", serde_json::to_string_pretty(&tree)?)?;
+        return Ok(());
+    }
+
+    writeln!(out, "{} {}: {}", tree.system.kind, tree.id, tree.system.name)?;
+    write_branches(out, &tree, 1)?;
+    Ok(())
+}
+
+/// Writes each nested system under the term it elaborates, indented by depth
+fn write_branches(out: &mut dyn Write, tree: &SystemTree, depth: usize) -> CommandResult {
+    for nested in &tree.children {
+        let term = &tree.system.terms[nested.position.index()];
+        let child = &nested.tree;
+        writeln!(
+            out,
+            "{}{} {} → {} {}: {}",
+            "  ".repeat(depth),
+            term.position,
+            term.instance,
+            child.system.kind,
+            child.id,
+            child.system.name
+        )?;
+        write_branches(out, child, depth + 1)?;
+    }
+    Ok(())
+}
+
+pub(super) fn nest(arguments: &Arguments, out: &mut dyn Write) -> CommandResult {
+    let id = arguments.id()?;
+    let position = match arguments.positional.get(2).map(String::as_str) {
+        Some(letter) if letter.chars().count() == 1 => Position::new(letter.chars().next().unwrap_or_default()),
+        Some(other) => return Err(Failure::Usage(format!("Invalid position '{}'", other))),
+        None => return Err(Failure::Usage("Missing position, e.g. 'nest 1 C 2'".to_string())),
+    };
+    let child = match arguments.positional.get(3).map(String::as_str) {
+        Some("none") => None,
+        Some(_) => Some(arguments.id_at(3)?),
+        None => return Err(Failure::Usage("Missing child id (or 'none')".to_string())),
+    };
+    let stored = open_store(arguments)?.nest(id, position, child)?;
+
+    if arguments.switch("json") {
+        writeln!(out, "{}", serde_json::to_string_pretty(&stored)?)?;
+    } else if let Some(child) = child {
+        writeln!(out, "Nested system {} at {} of {} {}", child, position, stored.system.kind, id)?;
+    } else {
+        writeln!(out, "Removed the system nested at {} of {} {}", position, stored.system.kind, id)?;
+    }
+    Ok(())
+}
+
+pub(super) fn subsystem(arguments: &Arguments, out: &mut dyn Write) -> CommandResult {
+    let id = arguments.id()?;
+    let store = open_store(arguments)?;
+    let subsystem = store.get(id)?.system.subsystem(&arguments.positions()?)?;
+
+    if arguments.switch("save") {
+        let stored = store.create_document(subsystem)?;
+        if arguments.switch("json") {
+            writeln!(out, "{}", serde_json::to_string_pretty(&stored)?)?;
+        } else {
+            writeln!(out, "Created {} {}: {}", stored.system.kind, stored.id, stored.system.name)?;
+        }
+    } else if arguments.switch("json") {
+        writeln!(out, "{}", serde_json::to_string_pretty(&subsystem)?)?;
+    } else {
+        write!(out, "{}", render_document(&subsystem, None))?;
+    }
+    Ok(())
+}
+
+pub(super) fn embed(arguments: &Arguments, out: &mut dyn Write) -> CommandResult {
+    let (id, into) = (arguments.id()?, arguments.id_at(2)?);
+    let store = open_store(arguments)?;
+    let smaller = store.get(id)?.system;
+    let larger = store.get(into)?.system;
+    let embedding = larger.embed(&smaller, &arguments.positions()?)?;
+
+    if !embedding.conflicts.is_empty() && !arguments.switch("force") {
+        if arguments.switch("json") {
+            writeln!(out, "{}", serde_json::to_string_pretty(&embedding)?)?;
+        }
+        let conflicts: Vec<String> = embedding.conflicts.iter().map(|conflict| format!("  {}", conflict)).collect();
+        return Err(Failure::Error(SystematicsError::Structure(format!(
+            "Embedding would overwrite {} value(s); use --force to replace them:\n{}",
+            conflicts.len(),
+            conflicts.join("\n")
+        ))));
+    }
+
+    let stored = store.update_if_unchanged(into, &larger, embedding.system.clone())?;
+    if arguments.switch("json") {
+        writeln!(out, "{}", serde_json::to_string_pretty(&embedding)?)?;
+    } else {
+        writeln!(out, "Embedded {} {} into {} {}", smaller.kind, id, stored.system.kind, into)?;
+        for conflict in &embedding.conflicts {
+            writeln!(out, "  replaced {}", conflict)?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::modules::cli::tests::{cleanup, run_in};
+    use crate::modules::cli::{EXIT_FAILURE, EXIT_OK, EXIT_USAGE};
+
+    #[test]
+    fn test_nest_tree_and_drill_down() {
+        cleanup("nest");
+        run_in("nest", &["new", "octad", "--name", "Firm", "--c", "Critical Functions"]);
+        run_in("nest", &["new", "tetrad", "--name", "Functions", "--a", "Sales"]);
+        run_in("nest", &["new", "triad", "--name", "Selling"]);
+
+        let (status, out, _) = run_in("nest", &["nest", "1", "c", "2"]);
+        assert_eq!(status, EXIT_OK);
+        assert_eq!(out, "Nested system 2 at C of Octad 1\n");
+        run_in("nest", &["nest", "2", "A", "3"]);
+
+        let (_, out, _) = run_in("nest", &["show", "1"]);
+        assert!(out.contains("C (Supportive Platform): Critical Functions [nested: 2]\n"), "{}", out);
+        let (_, out, _) = run_in("nest", &["show", "1", "--path", "C/A"]);
+        assert!(out.starts_with("Triad 3: Selling\n"), "{}", out);
+        let (status, _, _) = run_in("nest", &["show", "1", "--path", "B"]);
+        assert_eq!(status, EXIT_USAGE);
+
+        let (_, out, _) = run_in("nest", &["tree", "1"]);
+        assert_eq!(
+            out,
+            "Octad 1: Firm\n  C Critical Functions → Tetrad 2: Functions\n    A Sales → Triad 3: Selling\n"
+        );
+
+        let (status, _, err) = run_in("nest", &["nest", "3", "A", "1"]);
+        assert_eq!(status, EXIT_FAILURE);
+        assert_eq!(err, "Error: Nesting would form a cycle: 3 → 1 → 2 → 3\n");
+
+        let (_, out, _) = run_in("nest", &["dot", "1", "--nested", "--hide-defaults"]);
+        assert!(out.contains("  s0_C -- s1_A [style=dashed, lhead=cluster_1];\n"), "{}", out);
+        assert!(out.contains("    label=\"Triad 3: Selling\";\n"), "{}", out);
+        let (_, out, _) = run_in("nest", &["dot", "1", "--circle"]);
+        assert!(out.contains("  layout=circo;\n  node"), "{}", out);
+        assert!(!out.contains("cluster"), "{}", out);
+
+        let (_, out, _) = run_in("nest", &["nest", "1", "C", "none"]);
+        assert_eq!(out, "Removed the system nested at C of Octad 1\n");
+        cleanup("nest");
+    }
+
+    #[test]
+    fn test_subsystem_and_embed() {
+        cleanup("embed");
+        run_in("embed", &["new", "tetrad", "--name", "Work", "--a", "Office", "--d", "Plan", "--connective", "A-D=Route"]);
+
+        let (status, out, _) = run_in("embed", &["subsystem", "1", "--at", "AD"]);
+        assert_eq!(status, EXIT_OK);
+        assert_eq!(out, "Dyad: Work AD\nCore Attribute: Complementarity, polarity or force\nA (Essence): Office\nB (Existence): Plan\n");
+        let (_, out, _) = run_in("embed", &["subsystem", "1", "--at", "A,B,C,D", "--save"]);
+        assert_eq!(out, "Created Tetrad 2: Work ABCD\n");
+
+        run_in("embed", &["new", "dyad", "--name", "Pair", "--a", "Desk", "--b", "Mission"]);
+        let (status, _, err) = run_in("embed", &["embed", "3", "1", "--at", "AB"]);
+        assert_eq!(status, EXIT_FAILURE);
+        assert!(err.contains("Embedding would overwrite 1 value(s)"), "{}", err);
+        assert!(err.contains("  A: 'Office' → 'Desk'"), "{}", err);
+
+        let (status, out, _) = run_in("embed", &["embed", "3", "1", "--at", "AB", "--force"]);
+        assert_eq!(status, EXIT_OK);
+        assert_eq!(out, "Embedded Dyad 3 into Tetrad 1\n  replaced A: 'Office' → 'Desk'\n");
+        let (_, out, _) = run_in("embed", &["show", "1"]);
+        assert!(out.contains("A (Ground): Desk\nB (Ideal): Mission\n"), "{}", out);
+        cleanup("embed");
+    }
+}
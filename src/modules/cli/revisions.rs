@@ -0,0 +1,166 @@
+use std::io::Write;
+
+use crate::modules::cli::{open_store, Arguments, CommandResult, Failure};
+use crate::modules::diff;
+use crate::modules::history::format_timestamp;
+use crate::modules::store::{Store, StoredSystem};
+
+pub(super) fn history(arguments: &Arguments, out: &mut dyn Write) -> CommandResult {
+    let id = arguments.id()?;
+    let history = open_store(arguments)?.history(id)?;
+    if arguments.switch("json") {
+        writeln!(out, "{}", serde_json::to_string_pretty(&history)?)?;
+        return Ok(());
+    }
+
+    for revision in &history.revisions {
+        // The current revision is starred; those after it have been undone
+        let marker = if revision.number == history.current { "*" } else { " " };
+        let undone = if revision.number > history.current { " (undone)" } else { "" };
+        writeln!(
+            out,
+            "{} {:>3}  {}  {}{}",
+            marker,
+            revision.number,
+            format_timestamp(revision.timestamp),
+            revision.author,
+            undone
+        )?;
+        for change in &revision.changes {
+            writeln!(out, "        {}", change)?;
+        }
+    }
+    for step in &history.steps {
+        writeln!(out, "        {}  {}  {}", format_timestamp(step.timestamp), step.author, step)?;
+    }
+    Ok(())
+}
+
+pub(super) fn diff(arguments: &Arguments, out: &mut dyn Write) -> CommandResult {
+    let store = open_store(arguments)?;
+    let id = arguments.id()?;
+    let (before, after) = match arguments.number("at")? {
+        Some(number) => (store.at_revision(id, number)?.system, store.get(id)?.system),
+        None if arguments.positional.len() > 2 => (store.get(id)?.system, store.get(arguments.id_at(2)?)?.system),
+        None => return Err(Failure::Usage("Missing the system to compare with, e.g. 'diff 1 2' or 'diff 1 --at 3'".to_string())),
+    };
+    let diff = diff::diff(&before, &after)?;
+    if arguments.switch("json") {
+        writeln!(out, "{}", diff.to_json()?)?;
+    } else {
+        write!(out, "{}", diff.to_text(arguments.switch("color")))?;
+    }
+    Ok(())
+}
+
+pub(super) fn undo(arguments: &Arguments, out: &mut dyn Write) -> CommandResult {
+    let id = arguments.id()?;
+    let store = open_store(arguments)?;
+    let stored = store.undo(id)?;
+    report_revision(arguments, &store, &stored, "Undid", out)
+}
+
+pub(super) fn redo(arguments: &Arguments, out: &mut dyn Write) -> CommandResult {
+    let id = arguments.id()?;
+    let store = open_store(arguments)?;
+    let stored = store.redo(id)?;
+    report_revision(arguments, &store, &stored, "Redid", out)
+}
+
+/// "Undid: Tetrad 1: Work is at revision 2 of 3"
+fn report_revision(arguments: &Arguments, store: &Store, stored: &StoredSystem, action: &str, out: &mut dyn Write) -> CommandResult {
+    if arguments.switch("json") {
+        writeln!(out, "{}", serde_json::to_string_pretty(stored)?)?;
+        return Ok(());
+    }
+    let history = store.history(stored.id)?;
+    writeln!(
+        out,
+        "{}: {} {}: {} is at revision {} of {}",
+        action,
+        stored.system.kind,
+        stored.id,
+        stored.system.name,
+        history.current,
+        history.revisions.len()
+    )?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::modules::cli::tests::{cleanup, run_in};
+    use crate::modules::cli::{EXIT_FAILURE, EXIT_OK, EXIT_USAGE};
+
+    #[test]
+    fn test_history_undo_and_redo() {
+        cleanup("history");
+        run_in("history", &["new", "tetrad", "--name", "Work", "--a", "Office", "--author", "alice"]);
+        run_in("history", &["edit", "1", "--a", "Desk", "--connective", "A-B=Calling", "--author", "bob"]);
+        run_in("history", &["edit", "1", "--name", "Office work", "--author", "bob"]);
+
+        let (status, out, err) = run_in("history", &["history", "1"]);
+        assert_eq!(status, EXIT_OK, "{}", err);
+        let lines: Vec<&str> = out.lines().collect();
+        assert!(lines[0].starts_with("    1  ") && lines[0].ends_with(" UTC  alice"), "{}", out);
+        assert_eq!(lines[1], "        created");
+        assert!(lines[2].ends_with(" UTC  bob"), "{}", out);
+        assert_eq!(lines[3], "        A (Ground) 'Office' → 'Desk'");
+        assert_eq!(lines[4], "        A<>B 'Motivational imperative' → 'Calling'");
+        assert!(lines[5].starts_with("*   3  "), "{}", out);
+
+        let (status, out, _) = run_in("history", &["undo", "1"]);
+        assert_eq!(status, EXIT_OK);
+        assert_eq!(out, "Undid: Tetrad 1: Work is at revision 2 of 3\n");
+        let (_, out, _) = run_in("history", &["history", "1"]);
+        assert!(out.contains(" bob (undone)\n        name 'Work' → 'Office work'\n"), "{}", out);
+        assert!(out.ends_with("  undo from revision 3 to 2\n"), "{}", out);
+
+        let (_, out, _) = run_in("history", &["show", "1", "--at", "1"]);
+        assert!(out.starts_with("Revision 1 (") && out.contains(" UTC, alice)\nTetrad 1: Work\n"), "{}", out);
+        assert!(out.contains("A (Ground): Office\n"), "{}", out);
+        let (_, out, _) = run_in("history", &["redo", "1"]);
+        assert_eq!(out, "Redid: Tetrad 1: Office work is at revision 3 of 3\n");
+
+        let (status, _, err) = run_in("history", &["redo", "1"]);
+        assert_eq!(status, EXIT_FAILURE);
+        assert!(err.contains("Nothing to redo for system 1"), "{}", err);
+        let (status, _, err) = run_in("history", &["show", "1", "--at", "9"]);
+        assert_eq!(status, EXIT_FAILURE);
+        assert!(err.contains("System 1 has no revision 9 (1 to 3)"), "{}", err);
+        cleanup("history");
+    }
+
+    #[test]
+    fn test_diff_systems_and_revisions() {
+        cleanup("diff");
+        run_in("diff", &["new", "pentad", "--name", "North", "--a", "Sales"]);
+        run_in("diff", &["new", "pentad", "--name", "South", "--a", "Service", "--connective", "A-B=Drive"]);
+        run_in("diff", &["new", "hexad", "--name", "Other"]);
+
+        let (status, out, _) = run_in("diff", &["diff", "1", "2"]);
+        assert_eq!(status, EXIT_OK);
+        let lines: Vec<&str> = out.lines().collect();
+        assert_eq!(lines[0], "Name: 'North' → 'South'");
+        assert!(lines[1].starts_with("A (") && lines[1].ends_with("): 'Sales' → 'Service'"), "{}", out);
+        assert!(lines[2].starts_with("A<>B ") && lines[2].ends_with(": label added 'Drive'"), "{}", out);
+
+        let (_, out, _) = run_in("diff", &["diff", "1", "2", "--color"]);
+        assert!(out.contains("\x1b[32m'South'\x1b[0m"), "{:?}", out);
+        let (_, out, _) = run_in("diff", &["diff", "1", "2", "--json"]);
+        assert!(out.contains("\"change\": \"added\""), "{}", out);
+
+        run_in("diff", &["edit", "1", "--name", "North east"]);
+        let (_, out, _) = run_in("diff", &["diff", "1", "--at", "1"]);
+        assert_eq!(out, "Name: 'North' → 'North east'\n");
+        let (_, out, _) = run_in("diff", &["diff", "2", "2"]);
+        assert_eq!(out, "No differences.\n");
+
+        let (status, _, err) = run_in("diff", &["diff", "1", "3"]);
+        assert_eq!(status, EXIT_FAILURE);
+        assert!(err.contains("not a Pentad and a Hexad"), "{}", err);
+        let (status, _, _) = run_in("diff", &["diff", "1"]);
+        assert_eq!(status, EXIT_USAGE);
+        cleanup("diff");
+    }
+}
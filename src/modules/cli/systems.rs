@@ -0,0 +1,303 @@
+use std::io::Write;
+
+use crate::modules::cli::edits::apply_edits;
+use crate::modules::cli::{create_with, open_store, Arguments, CommandResult, Failure};
+use crate::modules::connective::Position;
+use crate::modules::document::SystemDocument;
+use crate::modules::error::SystematicsError;
+use crate::modules::history::format_timestamp;
+use crate::modules::prompter::{IoPrompter, Prompter};
+use crate::modules::session::{self, Target};
+use crate::modules::store::{Query, Store, StoredSystem};
+use crate::modules::system::{kind_name, order_for_kind};
+
+pub(super) fn new(arguments: &Arguments, out: &mut dyn Write) -> CommandResult {
+    let kind = arguments
+        .positional
+        .get(1)
+        .ok_or_else(|| Failure::Usage("Missing system kind, e.g. 'new triad' or 'new 3'".to_string()))?;
+    let order = kind
+        .parse::<usize>()
+        .ok()
+        .filter(|order| kind_name(*order).is_some())
+        .or_else(|| order_for_kind(kind))
+        .ok_or_else(|| SystematicsError::UnknownKind(kind.clone()))?;
+    let store = open_store(arguments)?;
+
+    let document = if arguments.edits().next().is_none() {
+        // Nothing given on the command line: walk through it interactively
+        let mut prompter = IoPrompter::stdio().with_policy(store.policy()?);
+        SystemDocument::from_system(create_with(order, &mut prompter)?.as_ref())
+    } else {
+        let kind = kind_name(order).unwrap_or_default();
+        let mut document = SystemDocument::canonical(order, &format!("Unnamed {}", kind))?;
+        apply_edits(&mut document, arguments, &store.policy()?)?;
+        document
+    };
+
+    let stored = store.create_document(document)?;
+    if arguments.switch("json") {
+        writeln!(out, "{}", serde_json::to_string_pretty(&stored)?)?;
+    } else {
+        writeln!(out, "Created {} {}: {}", stored.system.kind, stored.id, stored.system.name)?;
+    }
+    Ok(())
+}
+
+pub(super) fn show(arguments: &Arguments, out: &mut dyn Write) -> CommandResult {
+    let store = open_store(arguments)?;
+    if let Some(number) = arguments.number("at")? {
+        let id = arguments.id()?;
+        let revision = store.at_revision(id, number)?;
+        if arguments.switch("json") {
+            writeln!(out, "{}", serde_json::to_string_pretty(&revision)?)?;
+        } else {
+            writeln!(out, "Revision {} ({}, {})", number, format_timestamp(revision.timestamp), revision.author)?;
+            write!(out, "{}", render_document(&revision.system, Some(id)))?;
+        }
+        return Ok(());
+    }
+    let mut stored = store.get(arguments.id()?)?;
+    // Each step of --path follows the system nested at that term
+    for letter in arguments.option("path").unwrap_or_default().chars().filter(char::is_ascii_alphabetic) {
+        let position = Position::new(letter);
+        let child = stored
+            .system
+            .terms
+            .iter()
+            .find(|term| term.position == position)
+            .and_then(|term| term.child)
+            .ok_or_else(|| Failure::Usage(format!("Nothing is nested at {} of {} {}", position, stored.system.kind, stored.id)))?;
+        stored = store.get(child)?;
+    }
+    if arguments.switch("json") {
+        writeln!(out, "{}", serde_json::to_string_pretty(&stored)?)?;
+    } else {
+        write!(out, "{}", render(&stored))?;
+    }
+    Ok(())
+}
+
+pub(super) fn list(arguments: &Arguments, out: &mut dyn Write) -> CommandResult {
+    let query = Query {
+        order: arguments.number("order")?,
+        name: arguments.option("name").map(str::to_string),
+    };
+    let systems = open_store(arguments)?.query(&query)?;
+
+    if arguments.switch("json") {
+        writeln!(out, "{}", serde_json::to_string_pretty(&systems)?)?;
+    } else if systems.is_empty() {
+        writeln!(out, "No systems found.")?;
+    } else {
+        writeln!(out, "{:>4}  {:<8}  Name", "ID", "Kind")?;
+        for stored in systems {
+            writeln!(out, "{:>4}  {:<8}  {}", stored.id, stored.system.kind, stored.system.name)?;
+        }
+    }
+    Ok(())
+}
+
+pub(super) fn edit(arguments: &Arguments, out: &mut dyn Write) -> CommandResult {
+    let id = arguments.id()?;
+    let store = open_store(arguments)?;
+    if arguments.edits().next().is_none() {
+        let mut prompter = IoPrompter::stdio().with_policy(store.policy()?);
+        return edit_session(arguments, &store, id, &mut prompter, out);
+    }
+    let original = store.get(id)?.system;
+    let mut document = original.clone();
+    apply_edits(&mut document, arguments, &store.policy()?)?;
+    let stored = store.update_if_unchanged(id, &original, document)?;
+
+    if arguments.switch("json") {
+        writeln!(out, "{}", serde_json::to_string_pretty(&stored)?)?;
+    } else {
+        writeln!(out, "Updated {} {}: {}", stored.system.kind, stored.id, stored.system.name)?;
+    }
+    Ok(())
+}
+
+/// Edits a saved system question by question, starting at a target such as C or B<>E if given
+fn edit_session(arguments: &Arguments, store: &Store, id: u64, prompter: &mut dyn Prompter, out: &mut dyn Write) -> CommandResult {
+    let first = match arguments.positional.get(2) {
+        Some(text) => {
+            let target = Target::parse(text);
+            Some(target.ok_or_else(|| Failure::Usage(format!("Invalid edit target '{}', expected e.g. C or B<>E", text)))?)
+        }
+        None => None,
+    };
+    if let Some(stored) = session::edit_saved(store, id, prompter, first)?
+        && arguments.switch("json")
+    {
+        writeln!(out, "{}", serde_json::to_string_pretty(&stored)?)?;
+    }
+    Ok(())
+}
+
+pub(super) fn delete(arguments: &Arguments, out: &mut dyn Write) -> CommandResult {
+    let id = arguments.id()?;
+    open_store(arguments)?.delete(id)?;
+    if arguments.switch("json") {
+        writeln!(out, "{}", serde_json::json!({ "deleted": id }))?;
+    } else {
+        writeln!(out, "Deleted {}", id)?;
+    }
+    Ok(())
+}
+
+/// Plain-text view of a stored system
+fn render(stored: &StoredSystem) -> String {
+    render_document(&stored.system, Some(stored.id))
+}
+
+/// Plain-text view of a system document, saved under an id or not
+pub(super) fn render_document(system: &SystemDocument, id: Option<u64>) -> String {
+    let mut text = match id {
+        Some(id) => format!("{} {}: {}\n", system.kind, id, system.name),
+        None => format!("{}: {}\n", system.kind, system.name),
+    };
+    text.push_str(&format!("Core Attribute: {}\n", system.attribute));
+    for term in &system.terms {
+        match &term.canonical {
+            Some(canonical) => text.push_str(&format!("{} ({}): {}", term.position, canonical, term.instance)),
+            None => text.push_str(&format!("- {}", term.instance)),
+        }
+        match term.child {
+            Some(child) => text.push_str(&format!(" [nested: {}]\n", child)),
+            None => text.push('\n'),
+        }
+    }
+    if !system.connectives.is_empty() {
+        text.push_str("Connectives:\n");
+        for connective in &system.connectives {
+            text.push_str(&format!("  {} {}\n", connective.code(), connective.label()));
+        }
+    }
+    if !system.permutations.is_empty() {
+        text.push_str("Six laws:\n");
+        for permutation in &system.permutations {
+            text.push_str(&format!("  {} {}\n", permutation.code(), permutation.display()));
+        }
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::cli::tests::{cleanup, run_in, store_root};
+    use crate::modules::cli::{EXIT_FAILURE, EXIT_OK, EXIT_USAGE};
+    use crate::modules::prompter::ScriptedPrompter;
+    use std::fs;
+
+    #[test]
+    fn test_new_triad_and_show() {
+        cleanup("new");
+        let (status, out, _) = run_in(
+            "new",
+            &["new", "triad", "--name", "Will", "--active", "Intention", "--passive", "Resistance", "--reconciling", "Resolution"],
+        );
+        assert_eq!(status, EXIT_OK);
+        assert_eq!(out, "Created Triad 1: Will\n");
+
+        let (status, out, _) = run_in("new", &["show", "1"]);
+        assert_eq!(status, EXIT_OK);
+        assert!(out.contains("A (Active): Intention"), "{}", out);
+        assert!(out.contains("C (Reconciling): Resolution"), "{}", out);
+        cleanup("new");
+    }
+
+    #[test]
+    fn test_new_with_letters_connectives_and_json() {
+        cleanup("json");
+        let (status, out, _) = run_in(
+            "json",
+            &["new", "tetrad", "--name", "Work", "--b", "Mission", "--connective", "A-B=Calling", "--json"],
+        );
+        assert_eq!(status, EXIT_OK);
+
+        let stored: serde_json::Value = serde_json::from_str(&out).unwrap();
+        assert_eq!(stored["id"], 1);
+        assert_eq!(stored["system"]["terms"][0]["instance"], "Ground");
+        assert_eq!(stored["system"]["terms"][1]["instance"], "Mission");
+        assert_eq!(stored["system"]["connectives"][0]["user_label"], "Calling");
+        cleanup("json");
+    }
+
+    #[test]
+    fn test_list_and_filter() {
+        cleanup("list");
+        run_in("list", &["new", "triad", "--name", "Morning"]);
+        run_in("list", &["new", "pentad", "--name", "Evening", "--higher-potential", "Hope"]);
+
+        let (status, out, _) = run_in("list", &["list"]);
+        assert_eq!(status, EXIT_OK);
+        assert!(out.contains("   1  Triad     Morning"), "{}", out);
+        assert!(out.contains("   2  Pentad    Evening"), "{}", out);
+
+        let (_, out, _) = run_in("list", &["list", "--order", "5", "--json"]);
+        let systems: serde_json::Value = serde_json::from_str(&out).unwrap();
+        assert_eq!(systems.as_array().unwrap().len(), 1);
+        assert_eq!(systems[0]["system"]["terms"][1]["instance"], "Hope");
+
+        let (_, out, _) = run_in("list", &["list", "--name", "nothing"]);
+        assert_eq!(out, "No systems found.\n");
+        cleanup("list");
+    }
+
+    #[test]
+    fn test_edit_and_delete() {
+        cleanup("edit");
+        run_in("edit", &["new", "hexad", "--name", "Plan"]);
+
+        let (status, out, _) = run_in("edit", &["edit", "1", "--name", "Better plan", "--facts", "Data"]);
+        assert_eq!(status, EXIT_OK);
+        assert_eq!(out, "Updated Hexad 1: Better plan\n");
+        let (_, out, _) = run_in("edit", &["show", "1"]);
+        assert!(out.contains("E (Facts): Data"), "{}", out);
+
+        // An edit never slips in while another save holds the lock
+        let lock = store_root("edit").join("1.lock");
+        fs::write(&lock, "").unwrap();
+        let (status, _, err) = run_in("edit", &["edit", "1", "--name", "Lost plan"]);
+        assert_eq!(status, EXIT_FAILURE);
+        assert!(err.starts_with("Error: System 1 is being saved by someone else"), "{}", err);
+        fs::remove_file(&lock).unwrap();
+
+        let (status, out, _) = run_in("edit", &["delete", "1", "--json"]);
+        assert_eq!(status, EXIT_OK);
+        assert_eq!(out.trim(), r#"{"deleted":1}"#);
+        let (status, _, err) = run_in("edit", &["show", "1"]);
+        assert_eq!(status, EXIT_FAILURE);
+        assert_eq!(err, "Error: No system with id 1\n");
+        cleanup("edit");
+    }
+
+    #[test]
+    fn test_interactive_edit_session() {
+        cleanup("session");
+        run_in("session", &["new", "octad", "--name", "Firm"]);
+        let mut args: Vec<String> = ["edit", "1", "B<>E", "--json", "--store"].iter().map(|arg| arg.to_string()).collect();
+        args.push(store_root("session").display().to_string());
+        let arguments = Arguments::parse(&args).unwrap();
+        let store = open_store(&arguments).unwrap();
+
+        let mut prompter = ScriptedPrompter::new(&["Handover", "c", "Platform", ""]);
+        let mut out = Vec::new();
+        edit_session(&arguments, &store, 1, &mut prompter, &mut out).unwrap();
+        let transcript = prompter.transcript();
+        assert!(transcript.contains("Supportive Platform (C) (or press Enter to keep 'Supportive Platform'): "), "{}", transcript);
+        assert!(transcript.ends_with("Saved Octad 1: Firm\n"), "{}", transcript);
+        assert!(String::from_utf8(out).unwrap().contains("\"user_label\": \"Handover\""));
+
+        let (_, out, _) = run_in("session", &["show", "1"]);
+        assert!(out.contains("C (Supportive Platform): Platform\n"), "{}", out);
+        assert!(out.contains("Handover"), "{}", out);
+
+        let (status, _, err) = run_in("session", &["edit", "1", "B<>"]);
+        assert_eq!(status, EXIT_USAGE);
+        assert!(err.contains("Invalid edit target 'B<>'"), "{}", err);
+        cleanup("session");
+    }
+}
@@ -0,0 +1,161 @@
+use std::io::Write;
+
+use crate::modules::cli::{open_store, Arguments, CommandResult, Failure};
+use crate::modules::permutations::{self, Permutation};
+use crate::modules::system::SubTriad;
+
+pub(super) fn triads(arguments: &Arguments, out: &mut dyn Write) -> CommandResult {
+    let id = arguments.id()?;
+    let with = arguments.letters("with")?.unwrap_or_default();
+    let offset = arguments.number("offset")?.unwrap_or(0);
+    let limit = arguments.number("limit")?.unwrap_or(usize::MAX);
+    let system = open_store(arguments)?.get(id)?.system;
+
+    if let Some(position) = with.iter().find(|position| position.index() >= system.terms.len()) {
+        return Err(Failure::Usage(format!("{} has no position {}", system.kind, position)));
+    }
+    let matching: Vec<SubTriad> = system
+        .to_system()?
+        .triads()
+        .into_iter()
+        .filter(|triad| with.iter().all(|position| triad.contains(*position)))
+        .collect();
+    let total = matching.len();
+    let page: Vec<SubTriad> = matching
+        .into_iter()
+        .skip(offset)
+        .take(limit)
+        .map(|triad| if arguments.switch("permutations") { triad.with_six_laws() } else { triad })
+        .collect();
+
+    if arguments.switch("json") {
+        let json = serde_json::json!({ "total": total, "offset": offset, "triads": page });
+        writeln!(out, "{}", serde_json::to_string_pretty(&json)?)?;
+    } else if page.is_empty() {
+        writeln!(out, "No triads found.")?;
+    } else {
+        writeln!(out, "{} {}: {} (triads {}-{} of {})", system.kind, id, system.name, offset + 1, offset + page.len(), total)?;
+        for sub in &page {
+            let triad = &sub.triad;
+            writeln!(out, "{}  {} / {} / {}", sub.code(), triad.active, triad.passive, triad.reconciling)?;
+            for permutation in &sub.permutations {
+                writeln!(out, "     {} {}", permutation.code(), permutation.display())?;
+            }
+        }
+    }
+    Ok(())
+}
+
+pub(super) fn permute(arguments: &Arguments, out: &mut dyn Write) -> CommandResult {
+    let terms = &arguments.positional[1..];
+    let permutations = match terms {
+        [] => permutations::create_interactive()?,
+        [id] if id.parse::<u64>().is_ok() => stored_six_laws(arguments, out)?,
+        [a, b, c] => permutations::generate_six_permutations(a, b, c),
+        _ => return Err(Failure::Usage(format!("permute needs three terms, got {}", terms.len()))),
+    };
+
+    if arguments.switch("json") {
+        writeln!(out, "{}", serde_json::to_string_pretty(&permutations)?)?;
+    } else if !terms.is_empty() {
+        for permutation in &permutations {
+            writeln!(out, "{}", permutation.display())?;
+        }
+    }
+    Ok(())
+}
+
+/// Six laws of a stored Triad, saved back with it when --save is given
+fn stored_six_laws(arguments: &Arguments, out: &mut dyn Write) -> Result<Vec<Permutation>, Failure> {
+    let id = arguments.id()?;
+    let store = open_store(arguments)?;
+    let document = store.get(id)?.system.with_six_laws()?;
+    let permutations = document.permutations.clone();
+
+    if arguments.switch("save") {
+        store.update(id, document)?;
+        if !arguments.switch("json") {
+            writeln!(out, "Saved six laws with Triad {}", id)?;
+        }
+    }
+    Ok(permutations)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::modules::cli::tests::{cleanup, run_in};
+    use crate::modules::cli::{EXIT_FAILURE, EXIT_OK, EXIT_USAGE};
+
+    #[test]
+    fn test_permute() {
+        let (status, out, _) = run_in("permute", &["permute", "Sun", "Moon", "Earth"]);
+        assert_eq!(status, EXIT_OK);
+        assert_eq!(out.lines().count(), 6);
+        assert_eq!(out.lines().next(), Some("Expansion: Sun → Moon → Earth"));
+
+        let (_, out, _) = run_in("permute", &["permute", "Sun", "Moon", "Earth", "--json"]);
+        let permutations: serde_json::Value = serde_json::from_str(&out).unwrap();
+        assert_eq!(permutations[5]["name"], "Freedom");
+        cleanup("permute");
+    }
+
+    #[test]
+    fn test_permute_stored_triad() {
+        cleanup("laws");
+        run_in("laws", &["new", "triad", "--name", "Will", "--active", "Intention", "--passive", "Resistance"]);
+        run_in("laws", &["new", "tetrad", "--name", "Work"]);
+
+        let (status, out, _) = run_in("laws", &["permute", "1"]);
+        assert_eq!(status, EXIT_OK);
+        assert_eq!(out.lines().nth(3), Some("Identity: Resistance → Reconciling → Intention"));
+        let (_, out, _) = run_in("laws", &["show", "1", "--json"]);
+        assert!(!out.contains("permutations"));
+
+        let (_, out, _) = run_in("laws", &["permute", "1", "--save"]);
+        assert!(out.starts_with("Saved six laws with Triad 1\n"), "{}", out);
+        let (_, out, _) = run_in("laws", &["show", "1"]);
+        assert!(out.contains("Six laws:\n  123 Expansion: Intention → Resistance → Reconciling\n"), "{}", out);
+        run_in("laws", &["edit", "1", "--reconciling", "Resolution"]);
+        let (_, out, _) = run_in("laws", &["show", "1", "--json"]);
+        let stored: serde_json::Value = serde_json::from_str(&out).unwrap();
+        assert_eq!(stored["system"]["permutations"][3]["roles"], serde_json::json!([2, 3, 1]));
+        assert_eq!(stored["system"]["permutations"][3]["colouring"], "Resolution");
+
+        let (status, _, err) = run_in("laws", &["permute", "2"]);
+        assert_eq!(status, EXIT_FAILURE);
+        assert_eq!(err, "Error: A Tetrad has no six laws, only a Triad\n");
+        cleanup("laws");
+    }
+
+    #[test]
+    fn test_triads_filter_and_page() {
+        cleanup("triads");
+        run_in("triads", &["new", "hexad", "--name", "Plan", "--a", "Money", "--c", "Options"]);
+
+        let (status, out, _) = run_in("triads", &["triads", "1"]);
+        assert_eq!(status, EXIT_OK);
+        assert!(out.starts_with("Hexad 1: Plan (triads 1-20 of 20)\nABC  Money / Values / Options\n"), "{}", out);
+
+        let (_, out, _) = run_in("triads", &["triads", "1", "--with", "ce", "--offset", "1", "--limit", "2"]);
+        let codes: Vec<&str> = out.lines().skip(1).map(|line| &line[..3]).collect();
+        assert_eq!(codes, ["BCE", "CDE"]);
+        assert!(out.starts_with("Hexad 1: Plan (triads 2-3 of 4)"), "{}", out);
+
+        let (_, out, _) = run_in("triads", &["triads", "1", "--with", "F", "--limit", "1", "--permutations", "--json"]);
+        let page: serde_json::Value = serde_json::from_str(&out).unwrap();
+        assert_eq!(page["total"], 10);
+        assert_eq!(page["triads"][0]["positions"], serde_json::json!(["A", "B", "F"]));
+        assert_eq!(page["triads"][0]["permutations"][5]["name"], "Freedom");
+
+        let (_, out, _) = run_in("triads", &["triads", "1", "--offset", "40"]);
+        assert_eq!(out, "No triads found.\n");
+        let (_, out, _) = run_in("triads", &["triads", "1", "--with", "C,E", "--limit", "1"]);
+        assert!(out.starts_with("Hexad 1: Plan (triads 1-1 of 4)\nACE "), "{}", out);
+        let (status, _, err) = run_in("triads", &["triads", "1", "--with", "C;E"]);
+        assert_eq!(status, EXIT_USAGE);
+        assert!(err.contains("Invalid --with 'C;E'"), "{}", err);
+        let (status, _, _) = run_in("triads", &["triads", "1", "--with", "Z"]);
+        assert_eq!(status, EXIT_USAGE);
+        cleanup("triads");
+    }
+}
@@ -0,0 +1,186 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// Letter of a canonical position within a system (A, B, C, ...)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Position(char);
+
+impl Position {
+    /// Creates a position from its letter (case-insensitive)
+    pub const fn new(letter: char) -> Self {
+        Position(letter.to_ascii_uppercase())
+    }
+
+    /// Number of position letters, A to Z
+    pub const COUNT: usize = 26;
+
+    /// Position for a zero-based term index (0 -> A, 1 -> B, ...), or None past Z
+    pub fn from_index(index: usize) -> Option<Self> {
+        u8::try_from(index)
+            .ok()
+            .filter(|&index| usize::from(index) < Self::COUNT)
+            .map(|index| Position((b'A' + index) as char))
+    }
+
+    /// The position letter
+    pub fn letter(self) -> char {
+        self.0
+    }
+
+    /// Zero-based term index of this position
+    pub fn index(self) -> usize {
+        (self.0 as u8).wrapping_sub(b'A') as usize
+    }
+}
+
+impl From<char> for Position {
+    fn from(letter: char) -> Self {
+        Position::new(letter)
+    }
+}
+
+impl fmt::Display for Position {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// Which way a connective acts between its two positions
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Direction {
+    #[default]
+    Bidirectional,
+    Forward,
+    Backward,
+}
+
+impl Direction {
+    /// Symbol used between position letters, e.g. "<>" in "A<>B"
+    pub fn symbol(self) -> &'static str {
+        match self {
+            Direction::Bidirectional => "<>",
+            Direction::Forward => "->",
+            Direction::Backward => "<-",
+        }
+    }
+
+    /// The same direction seen with the two positions swapped
+    pub fn reversed(self) -> Self {
+        match self {
+            Direction::Bidirectional => Direction::Bidirectional,
+            Direction::Forward => Direction::Backward,
+            Direction::Backward => Direction::Forward,
+        }
+    }
+}
+
+/// A relationship between two canonical positions of a system
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Connective {
+    pub from: Position,
+    pub to: Position,
+    pub canonical_name: String,
+    #[serde(default)]
+    pub user_label: Option<String>,
+    #[serde(default)]
+    pub direction: Direction,
+    #[serde(default)]
+    pub notes: Option<String>,
+}
+
+impl Connective {
+    /// Creates a bidirectional connective carrying its canonical name
+    pub fn new(from: impl Into<Position>, to: impl Into<Position>, canonical_name: &str) -> Self {
+        Connective {
+            from: from.into(),
+            to: to.into(),
+            canonical_name: canonical_name.to_string(),
+            user_label: None,
+            direction: Direction::default(),
+            notes: None,
+        }
+    }
+
+    /// Label to show: the user's label if given, otherwise the canonical name
+    pub fn label(&self) -> &str {
+        self.user_label.as_deref().unwrap_or(&self.canonical_name)
+    }
+
+    /// Check if the user has relabelled this connective
+    pub fn is_relabelled(&self) -> bool {
+        self.user_label.is_some()
+    }
+
+    /// Check if this connective joins two positions, in either order
+    pub fn joins(&self, a: impl Into<Position>, b: impl Into<Position>) -> bool {
+        let (a, b) = (a.into(), b.into());
+        (self.from == a && self.to == b) || (self.from == b && self.to == a)
+    }
+
+    /// Short code such as "A<>B"
+    pub fn code(&self) -> String {
+        format!("{}{}{}", self.from, self.direction.symbol(), self.to)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_position_from_char() {
+        assert_eq!(Position::from('c'), Position::new('C'));
+        assert_eq!(Position::new('C').letter(), 'C');
+        assert_eq!(Position::new('C').index(), 2);
+        assert_eq!(Position::from_index(11).unwrap().to_string(), "L");
+        assert_eq!(Position::from_index(25), Some(Position::new('Z')));
+        assert_eq!(Position::from_index(26), None);
+        assert_eq!(Position::from_index(300), None);
+    }
+
+    #[test]
+    fn test_connective_defaults() {
+        let connective = Connective::new('A', 'B', "Motivational imperative");
+
+        assert_eq!(connective.from, Position::new('A'));
+        assert_eq!(connective.to, Position::new('B'));
+        assert_eq!(connective.label(), "Motivational imperative");
+        assert_eq!(connective.direction, Direction::Bidirectional);
+        assert!(!connective.is_relabelled());
+        assert!(connective.notes.is_none());
+    }
+
+    #[test]
+    fn test_user_label_overrides_canonical_name() {
+        let mut connective = Connective::new('A', 'B', "Aspiration");
+        connective.user_label = Some("Reaching up".to_string());
+
+        assert_eq!(connective.label(), "Reaching up");
+        assert_eq!(connective.canonical_name, "Aspiration");
+        assert!(connective.is_relabelled());
+    }
+
+    #[test]
+    fn test_joins_either_order() {
+        let connective = Connective::new('B', 'E', "Function");
+
+        assert!(connective.joins('B', 'E'));
+        assert!(connective.joins('e', 'b'));
+        assert!(!connective.joins('B', 'C'));
+    }
+
+    #[test]
+    fn test_code_follows_direction() {
+        let mut connective = Connective::new('C', 'D', "Form");
+        assert_eq!(connective.code(), "C<>D");
+
+        connective.direction = Direction::Forward;
+        assert_eq!(connective.code(), "C->D");
+
+        connective.direction = Direction::Backward;
+        assert_eq!(connective.code(), "C<-D");
+    }
+}
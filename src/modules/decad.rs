@@ -0,0 +1,353 @@
+use std::ops::{Deref, DerefMut};
+
+use crate::modules::connective::Connective;
+use crate::modules::error::SystematicsError;
+use crate::modules::instance::SystemInstance;
+use crate::modules::prompter::{IoPrompter, Prompter};
+use crate::modules::system::System;
+use crate::modules::template::{ConnectiveTemplate, SystemTemplate, TermTemplate};
+
+/// Canonical Decad positions and connectives.
+///
+/// Like the Dodecad's, the terms are the attributes of the systems from the Decad
+/// down to the Monad, and each connective keeps the Dodecad's name for the same pair.
+pub static TEMPLATE: SystemTemplate = SystemTemplate {
+    kind: "Decad",
+    order: 10,
+    attribute_description: Decad::TERM_ATTRIBUTE_DESCRIPTION,
+    terms: &[
+        TermTemplate { position: 'A', label: "Creativity" },
+        TermTemplate { position: 'B', label: "Pattern" },
+        TermTemplate { position: 'C', label: "Individuality" },
+        TermTemplate { position: 'D', label: "Structure" },
+        TermTemplate { position: 'E', label: "Repetition" },
+        TermTemplate { position: 'F', label: "Potentiality" },
+        TermTemplate { position: 'G', label: "Subsistence" },
+        TermTemplate { position: 'H', label: "Relatedness" },
+        TermTemplate { position: 'I', label: "Polarity" },
+        TermTemplate { position: 'J', label: "Wholeness" },
+    ],
+    connectives: &[
+        ConnectiveTemplate { from: 'A', to: 'B', name: "Invention", description: "Creativity finding a new form" },
+        ConnectiveTemplate { from: 'A', to: 'C', name: "Originality", description: "Creativity bearing the mark of its maker" },
+        ConnectiveTemplate { from: 'A', to: 'D', name: "Construction", description: "Creativity building something that stands" },
+        ConnectiveTemplate { from: 'A', to: 'E', name: "Craft", description: "Creativity refined through practice" },
+        ConnectiveTemplate { from: 'A', to: 'F', name: "Imagination", description: "Creativity playing among possibilities" },
+        ConnectiveTemplate { from: 'A', to: 'G', name: "Enterprise", description: "Creativity turned to making a living" },
+        ConnectiveTemplate { from: 'A', to: 'H', name: "Collaboration", description: "Creativity shared between people" },
+        ConnectiveTemplate { from: 'A', to: 'I', name: "Tension", description: "Creativity sparked by opposites" },
+        ConnectiveTemplate { from: 'A', to: 'J', name: "Vision", description: "Creativity that sees the whole" },
+        ConnectiveTemplate { from: 'B', to: 'C', name: "Character", description: "Pattern taken up as individual style" },
+        ConnectiveTemplate { from: 'B', to: 'D', name: "Architecture", description: "Pattern realised in enduring structure" },
+        ConnectiveTemplate { from: 'B', to: 'E', name: "Rhythm", description: "Pattern recurring in time" },
+        ConnectiveTemplate { from: 'B', to: 'F', name: "Blueprint", description: "Pattern held as a possibility not yet built" },
+        ConnectiveTemplate { from: 'B', to: 'G', name: "Habitat", description: "Pattern of the conditions that sustain life" },
+        ConnectiveTemplate { from: 'B', to: 'H', name: "Network", description: "Pattern of connections among parts" },
+        ConnectiveTemplate { from: 'B', to: 'I', name: "Symmetry", description: "Pattern balanced between opposites" },
+        ConnectiveTemplate { from: 'B', to: 'J', name: "Harmony", description: "Pattern in which the whole agrees with itself" },
+        ConnectiveTemplate { from: 'C', to: 'D', name: "Role", description: "An individual placed within a structure" },
+        ConnectiveTemplate { from: 'C', to: 'E', name: "Habit", description: "An individual shaped by repetition" },
+        ConnectiveTemplate { from: 'C', to: 'F', name: "Aspiration", description: "An individual reaching toward its potential" },
+        ConnectiveTemplate { from: 'C', to: 'G', name: "Livelihood", description: "An individual sustaining its own existence" },
+        ConnectiveTemplate { from: 'C', to: 'H', name: "Encounter", description: "An individual meeting another" },
+        ConnectiveTemplate { from: 'C', to: 'I', name: "Identity", description: "An individual defined by what it is not" },
+        ConnectiveTemplate { from: 'C', to: 'J', name: "Vocation", description: "An individual finding its place in the whole" },
+        ConnectiveTemplate { from: 'D', to: 'E', name: "Procedure", description: "Structure enacted again and again" },
+        ConnectiveTemplate { from: 'D', to: 'F', name: "Capacity", description: "Structure that makes new things possible" },
+        ConnectiveTemplate { from: 'D', to: 'G', name: "Infrastructure", description: "Structure that keeps life going" },
+        ConnectiveTemplate { from: 'D', to: 'H', name: "Organisation", description: "Structure ordering relationships" },
+        ConnectiveTemplate { from: 'D', to: 'I', name: "Balance", description: "Structure holding opposing forces in check" },
+        ConnectiveTemplate { from: 'D', to: 'J', name: "Framework", description: "Structure that holds the whole together" },
+        ConnectiveTemplate { from: 'E', to: 'F', name: "Practice", description: "Repetition that builds capability" },
+        ConnectiveTemplate { from: 'E', to: 'G', name: "Maintenance", description: "Repetition that keeps things alive" },
+        ConnectiveTemplate { from: 'E', to: 'H', name: "Custom", description: "Repetition shared among people" },
+        ConnectiveTemplate { from: 'E', to: 'I', name: "Cycle", description: "Repetition swinging between poles" },
+        ConnectiveTemplate { from: 'E', to: 'J', name: "Tradition", description: "Repetition that carries the whole forward" },
+        ConnectiveTemplate { from: 'F', to: 'G', name: "Growth", description: "Potential realised as means of living" },
+        ConnectiveTemplate { from: 'F', to: 'H', name: "Opportunity", description: "Potential opened up by relationships" },
+        ConnectiveTemplate { from: 'F', to: 'I', name: "Choice", description: "Potential divided between alternatives" },
+        ConnectiveTemplate { from: 'F', to: 'J', name: "Promise", description: "Potential of the whole not yet realised" },
+        ConnectiveTemplate { from: 'G', to: 'H', name: "Exchange", description: "Subsistence gained through relationships" },
+        ConnectiveTemplate { from: 'G', to: 'I', name: "Scarcity", description: "Subsistence caught between need and supply" },
+        ConnectiveTemplate { from: 'G', to: 'J', name: "Economy", description: "Subsistence organised across the whole" },
+        ConnectiveTemplate { from: 'H', to: 'I', name: "Dialogue", description: "Relatedness that holds difference" },
+        ConnectiveTemplate { from: 'H', to: 'J', name: "Community", description: "Relatedness forming a whole" },
+        ConnectiveTemplate { from: 'I', to: 'J', name: "Reconciliation", description: "Opposites united in the whole" },
+    ],
+};
+
+/// Typed view over a 10-term `SystemInstance` built from the Decad template
+#[derive(Debug, Clone, PartialEq)]
+pub struct Decad {
+    system: SystemInstance,
+}
+
+impl Decad {
+    pub const TERM_ATTRIBUTE_DESCRIPTION: &'static str = "Creativity";
+
+    /// Creates a new Decad with user instances for canonical positions
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        name: &str,
+        creativity: &str,
+        pattern: &str,
+        individuality: &str,
+        structure: &str,
+        repetition: &str,
+        potentiality: &str,
+        subsistence: &str,
+        relatedness: &str,
+        polarity: &str,
+        wholeness: &str,
+    ) -> Self {
+        let instances = [
+            creativity,
+            pattern,
+            individuality,
+            structure,
+            repetition,
+            potentiality,
+            subsistence,
+            relatedness,
+            polarity,
+            wholeness,
+        ];
+        let system = SystemInstance::with_instances(&TEMPLATE, name, &instances)
+            .expect("Decad template has ten positions");
+        Decad { system }
+    }
+
+    /// Interactive creation method on the terminal
+    pub fn create_interactive() -> Result<Self, SystematicsError> {
+        Self::create_with(&mut IoPrompter::stdio())
+    }
+
+    /// Interactive creation through any prompter
+    pub fn create_with(prompter: &mut dyn Prompter) -> Result<Self, SystematicsError> {
+        SystemInstance::create_with(&TEMPLATE, prompter).map(|system| Decad { system })
+    }
+
+    /// Creativity instance (position A)
+    pub fn creativity(&self) -> &str {
+        self.system.instance_at(0)
+    }
+
+    /// Pattern instance (position B)
+    pub fn pattern(&self) -> &str {
+        self.system.instance_at(1)
+    }
+
+    /// Individuality instance (position C)
+    pub fn individuality(&self) -> &str {
+        self.system.instance_at(2)
+    }
+
+    /// Structure instance (position D)
+    pub fn structure(&self) -> &str {
+        self.system.instance_at(3)
+    }
+
+    /// Repetition instance (position E)
+    pub fn repetition(&self) -> &str {
+        self.system.instance_at(4)
+    }
+
+    /// Potentiality instance (position F)
+    pub fn potentiality(&self) -> &str {
+        self.system.instance_at(5)
+    }
+
+    /// Subsistence instance (position G)
+    pub fn subsistence(&self) -> &str {
+        self.system.instance_at(6)
+    }
+
+    /// Relatedness instance (position H)
+    pub fn relatedness(&self) -> &str {
+        self.system.instance_at(7)
+    }
+
+    /// Polarity instance (position I)
+    pub fn polarity(&self) -> &str {
+        self.system.instance_at(8)
+    }
+
+    /// Wholeness instance (position J)
+    pub fn wholeness(&self) -> &str {
+        self.system.instance_at(9)
+    }
+
+    /// Get canonical term names
+    pub fn get_canonical_terms() -> Vec<&'static str> {
+        TEMPLATE.canonical_terms()
+    }
+
+    /// Get all user instances
+    pub fn get_instances(&self) -> Vec<String> {
+        self.system.instances().to_vec()
+    }
+
+    /// Underlying data-driven system
+    pub fn into_instance(self) -> SystemInstance {
+        self.system
+    }
+}
+
+impl Deref for Decad {
+    type Target = SystemInstance;
+
+    fn deref(&self) -> &SystemInstance {
+        &self.system
+    }
+}
+
+impl DerefMut for Decad {
+    fn deref_mut(&mut self) -> &mut SystemInstance {
+        &mut self.system
+    }
+}
+
+impl TryFrom<SystemInstance> for Decad {
+    type Error = SystematicsError;
+
+    fn try_from(system: SystemInstance) -> Result<Self, Self::Error> {
+        if !std::ptr::eq(system.template(), &TEMPLATE) {
+            return Err(SystematicsError::Structure(format!("Expected Decad template, got {}", system.template().kind)));
+        }
+        Ok(Decad { system })
+    }
+}
+
+impl System for Decad {
+    fn kind(&self) -> &'static str {
+        self.system.template().kind
+    }
+
+    fn order(&self) -> usize {
+        self.system.order()
+    }
+
+    fn name(&self) -> &str {
+        &self.system.name
+    }
+
+    fn canonical_terms(&self) -> Vec<&'static str> {
+        Self::get_canonical_terms()
+    }
+
+    fn instances(&self) -> Vec<String> {
+        self.get_instances()
+    }
+
+    fn connectives(&self) -> &[Connective] {
+        self.system.connectives()
+    }
+
+    fn attribute_description(&self) -> &'static str {
+        Self::TERM_ATTRIBUTE_DESCRIPTION
+    }
+
+    fn display(&self) {
+        self.system.display()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Decad {
+        Decad::new("Test", "One", "Two", "Three", "Four", "Five", "Six", "Seven", "Eight", "Nine", "Ten")
+    }
+
+    #[test]
+    fn test_decad_creation() {
+        let decad = sample();
+
+        assert_eq!(decad.name, "Test");
+        assert_eq!(decad.creativity(), "One");
+        assert_eq!(decad.pattern(), "Two");
+        assert_eq!(decad.individuality(), "Three");
+        assert_eq!(decad.structure(), "Four");
+        assert_eq!(decad.repetition(), "Five");
+        assert_eq!(decad.potentiality(), "Six");
+        assert_eq!(decad.subsistence(), "Seven");
+        assert_eq!(decad.relatedness(), "Eight");
+        assert_eq!(decad.polarity(), "Nine");
+        assert_eq!(decad.wholeness(), "Ten");
+
+        // Should have default connectives
+        assert!(decad.has_connectives());
+        assert_eq!(decad.connective_label('A', 'B').unwrap(), "Invention");
+    }
+
+    #[test]
+    fn test_canonical_terms() {
+        let terms = Decad::get_canonical_terms();
+        assert_eq!(terms, vec![
+            "Creativity",
+            "Pattern",
+            "Individuality",
+            "Structure",
+            "Repetition",
+            "Potentiality",
+            "Subsistence",
+            "Relatedness",
+            "Polarity",
+            "Wholeness",
+        ]);
+    }
+
+    #[test]
+    fn test_get_instances() {
+        let instances = sample().get_instances();
+        assert_eq!(instances, vec!["One", "Two", "Three", "Four", "Five", "Six", "Seven", "Eight", "Nine", "Ten"]);
+    }
+
+    #[test]
+    fn test_has_connectives_with_some() {
+        let mut decad = Decad::new("Test", "A", "B", "C", "D", "E", "F", "G", "H", "I", "J");
+        decad.set_connective_label('A', 'B', Some("test connection".to_string())).unwrap();
+
+        assert!(decad.has_connectives());
+    }
+
+    #[test]
+    fn test_reset_connective_label() {
+        let mut decad = sample();
+        decad.set_connective_label('A', 'B', Some("custom connection".to_string())).unwrap();
+        decad.set_connective_label('A', 'B', None).unwrap();
+
+        // Clearing the user label restores the canonical name
+        assert!(decad.has_connectives());
+        assert_eq!(decad.connective_label('A', 'B'), Some("Invention"));
+        assert!(!decad.connective('A', 'B').unwrap().is_relabelled());
+    }
+
+    #[test]
+    fn test_custom_connectives() {
+        let mut decad = sample();
+
+        // Modify one connective while others keep defaults
+        decad.set_connective_label('A', 'J', Some("custom connection".to_string())).unwrap();
+        assert_eq!(decad.connective_label('J', 'A').unwrap(), "custom connection");
+        assert_eq!(decad.connective_label('B', 'C').unwrap(), "Character");
+    }
+
+    #[test]
+    fn test_all_connectives_count() {
+        let decad = sample();
+
+        // Should have exactly 45 connectives (10 choose 2 = 45)
+        assert_eq!(decad.connectives().len(), 45);
+    }
+
+    #[test]
+    fn test_try_from_instance() {
+        let decad = sample();
+        let system = decad.clone().into_instance();
+
+        assert_eq!(system.order(), 10);
+        assert_eq!(Decad::try_from(system).unwrap(), decad);
+    }
+}
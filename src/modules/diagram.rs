@@ -0,0 +1,359 @@
+use std::f64::consts::PI;
+
+use crate::modules::connective::Position;
+use crate::modules::document::{SystemDocument, TermDocument};
+use crate::modules::ennead::{EnneagramFigure, INNER_CYCLE, TRIANGLE};
+
+/// Choices for an SVG rendering
+#[derive(Debug, Clone, PartialEq)]
+pub struct SvgOptions {
+    /// Width and height of the figure itself in pixels; labels get a margin around it
+    pub size: u32,
+    /// Writes each term's canonical name and instance beside its point
+    pub labels: bool,
+    /// Draws every connective as a dashed line, captioned where the user relabelled it
+    pub connectives: bool,
+}
+
+impl Default for SvgOptions {
+    fn default() -> Self {
+        SvgOptions {
+            size: 480,
+            labels: true,
+            connectives: false,
+        }
+    }
+}
+
+impl SvgOptions {
+    /// Space around the figure, as a fraction of its size
+    fn margin(&self) -> f64 {
+        if self.labels { 0.4 } else { 0.08 }
+    }
+}
+
+/// The figure Bennett draws for a system of one order.
+///
+/// Points are in a unit square with y pointing down, one per position (A first).
+#[derive(Debug, Clone, PartialEq)]
+pub struct Geometry {
+    /// Name of the figure, e.g. "hexagram"
+    pub figure: &'static str,
+    pub points: Vec<(f64, f64)>,
+    /// Pairs of point indexes joined by the figure's own lines
+    pub edges: Vec<(usize, usize)>,
+    /// Whether the figure is drawn inside a circle, as the Monad and the enneagram are
+    pub circle: bool,
+}
+
+impl Geometry {
+    /// Where a point lands in an SVG rendered with the given options, as percentages of
+    /// its width and height; used to place inputs over the image
+    pub fn placement(&self, index: usize, options: &SvgOptions) -> (f64, f64) {
+        let margin = options.margin();
+        let (x, y) = self.points[index];
+        let scale = 100.0 / (1.0 + 2.0 * margin);
+        ((x + margin) * scale, (y + margin) * scale)
+    }
+}
+
+/// The figure for a system with the given number of terms (1 to 12)
+pub fn geometry(order: usize) -> Option<Geometry> {
+    let (figure, points, edges, circle) = match order {
+        1 => ("point in a circle", vec![(0.5, 0.5)], Vec::new(), true),
+        2 => ("line", vec![(0.15, 0.5), (0.85, 0.5)], vec![(0, 1)], false),
+        // Active upper left, Passive lower left, Reconciling to the right
+        3 => {
+            let corners = ring(3, 120.0, 0.4);
+            ("triangle", vec![corners[0], corners[2], corners[1]], cycle(3), false)
+        }
+        // Ground at the bottom, Ideal at the top, Instrumental left and Directive right
+        4 => ("square", vec![(0.5, 0.9), (0.5, 0.1), (0.1, 0.5), (0.9, 0.5)], vec![(1, 3), (3, 0), (0, 2), (2, 1)], false),
+        // Quintessence at the tip of a short and a long triangle
+        5 => (
+            "fan",
+            vec![(0.1, 0.5), (0.5, 0.3), (0.5, 0.7), (0.9, 0.1), (0.9, 0.9)],
+            vec![(0, 1), (0, 2), (1, 2), (0, 3), (0, 4), (3, 4)],
+            false,
+        ),
+        6 => ("hexagram", ring(6, 150.0, 0.45), vec![(0, 2), (2, 4), (4, 0), (1, 3), (3, 5), (5, 1)], false),
+        7 => ("heptagon", ring(7, 90.0, 0.45), cycle(7), false),
+        // Smallest Significant Holon to the east, then clockwise; two interlaced squares
+        8 => ("octagram", ring(8, 0.0, 0.45), vec![(0, 2), (2, 4), (4, 6), (6, 0), (1, 3), (3, 5), (5, 7), (7, 1)], false),
+        // Points 1 to 8 clockwise from the top, with 9 (Completion) at the top
+        9 => ("enneagram", ring(9, 50.0, 0.45), figure_edges(&[&TRIANGLE, &INNER_CYCLE]), true),
+        10 => ("decagon", ring(10, 90.0, 0.45), cycle(10), false),
+        11 => ("hendecagon", ring(11, 90.0, 0.45), cycle(11), false),
+        12 => ("dodecagon", ring(12, 90.0, 0.45), cycle(12), false),
+        _ => return None,
+    };
+    Some(Geometry { figure, points, edges, circle })
+}
+
+/// Points evenly spaced clockwise round the centre, starting at an angle in degrees
+/// (0 is east, 90 is north)
+fn ring(count: usize, start: f64, radius: f64) -> Vec<(f64, f64)> {
+    (0..count)
+        .map(|index| {
+            let angle = (start - 360.0 * index as f64 / count as f64) * PI / 180.0;
+            (0.5 + radius * angle.cos(), 0.5 - radius * angle.sin())
+        })
+        .collect()
+}
+
+/// Edges of enneagram figures, as indices of their points' positions
+fn figure_edges(figures: &[&EnneagramFigure]) -> Vec<(usize, usize)> {
+    figures
+        .iter()
+        .flat_map(|figure| figure.edges())
+        .map(|(from, to)| (Position::new(from).index(), Position::new(to).index()))
+        .collect()
+}
+
+/// Edges joining each point to the next and the last back to the first
+fn cycle(count: usize) -> Vec<(usize, usize)> {
+    (0..count).map(|index| (index, (index + 1) % count)).collect()
+}
+
+impl SystemDocument {
+    /// SVG drawing of this system's figure, labelled from its terms
+    pub fn to_svg(&self, options: &SvgOptions) -> String {
+        let Some(geometry) = geometry(self.order) else {
+            return String::new();
+        };
+        let size = options.size as f64;
+        let margin = options.margin() * size;
+        let full = size + 2.0 * margin;
+        let at = |index: usize| (geometry.points[index].0 * size, geometry.points[index].1 * size);
+
+        let mut svg = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{full:.0}\" height=\"{full:.0}\" viewBox=\"{:.1} {:.1} {full:.1} {full:.1}\" font-family=\"sans-serif\" font-size=\"{:.0}\">\n",
+            -margin,
+            -margin,
+            size / 36.0
+        );
+        svg.push_str(&format!("  <title>{}</title>\n", escape(&format!("{}: {}", self.kind, self.name))));
+
+        svg.push_str("  <g class=\"figure\" stroke=\"#333\" stroke-width=\"2\" fill=\"none\">\n");
+        if geometry.circle {
+            svg.push_str(&format!("    <circle cx=\"{0:.1}\" cy=\"{0:.1}\" r=\"{1:.1}\"/>\n", size / 2.0, size * 0.45));
+        }
+        for &(from, to) in &geometry.edges {
+            svg.push_str(&format!("    {}\n", line(at(from), at(to), "")));
+        }
+        svg.push_str("  </g>\n");
+
+        if options.connectives && !self.connectives.is_empty() {
+            let mut captions = String::new();
+            svg.push_str("  <g class=\"connectives\" stroke=\"#999\" stroke-dasharray=\"6 4\">\n");
+            for connective in &self.connectives {
+                let (from, to) = (connective.from.index(), connective.to.index());
+                if from >= geometry.points.len() || to >= geometry.points.len() {
+                    continue;
+                }
+                let title = format!("<title>{}</title>", escape(&format!("{}: {}", connective.code(), connective.label())));
+                svg.push_str(&format!("    {}\n", line(at(from), at(to), &title)));
+                if connective.is_relabelled() {
+                    let ((x1, y1), (x2, y2)) = (at(from), at(to));
+                    captions.push_str(&format!(
+                        "    <text x=\"{:.1}\" y=\"{:.1}\">{}</text>\n",
+                        (x1 + x2) / 2.0,
+                        (y1 + y2) / 2.0,
+                        escape(connective.label())
+                    ));
+                }
+            }
+            svg.push_str("  </g>\n");
+            if !captions.is_empty() {
+                svg.push_str("  <g class=\"connective-labels\" fill=\"#666\" font-size=\"0.85em\" text-anchor=\"middle\">\n");
+                svg.push_str(&captions);
+                svg.push_str("  </g>\n");
+            }
+        }
+
+        svg.push_str("  <g class=\"terms\">\n");
+        for index in 0..geometry.points.len() {
+            let (x, y) = at(index);
+            svg.push_str(&format!("    <circle cx=\"{:.1}\" cy=\"{:.1}\" r=\"{:.1}\" fill=\"#0078d4\"/>\n", x, y, size / 80.0));
+        }
+        if options.labels {
+            for (index, lines) in self.term_labels().into_iter().enumerate().take(geometry.points.len()) {
+                svg.push_str(&format!("    {}\n", label(geometry.points[index], size, &lines)));
+            }
+        }
+        svg.push_str("  </g>\n</svg>\n");
+        svg
+    }
+
+    /// Lines of text for each point: canonical name then instance, or the Monad's terms together
+    fn term_labels(&self) -> Vec<Vec<String>> {
+        if self.order == 1 {
+            let instances: Vec<String> = self.terms.iter().map(|term| term.instance.clone()).collect();
+            let text = if instances.is_empty() { self.name.clone() } else { instances.join(" · ") };
+            return vec![vec![text]];
+        }
+        self.terms.iter().map(term_lines).collect()
+    }
+}
+
+fn term_lines(term: &TermDocument) -> Vec<String> {
+    match term.canonical.as_deref() {
+        Some(canonical) if canonical != term.instance => vec![canonical.to_string(), term.instance.clone()],
+        _ => vec![term.instance.clone()],
+    }
+}
+
+fn line((x1, y1): (f64, f64), (x2, y2): (f64, f64), content: &str) -> String {
+    let start = format!("<line x1=\"{:.1}\" y1=\"{:.1}\" x2=\"{:.1}\" y2=\"{:.1}\"", x1, y1, x2, y2);
+    if content.is_empty() { format!("{}/>", start) } else { format!("{}>{}</line>", start, content) }
+}
+
+/// Text beside a point, pushed away from the centre so it stays clear of the figure;
+/// the first line (the canonical name) is bold
+fn label((x, y): (f64, f64), size: f64, lines: &[String]) -> String {
+    let (dx, dy) = (x - 0.5, y - 0.5);
+    let anchor = if dx.abs() < 0.05 {
+        "middle"
+    } else if dx > 0.0 {
+        "start"
+    } else {
+        "end"
+    };
+    let gap = size / 40.0;
+    let tx = x * size + dx.signum() * gap * if anchor == "middle" { 0.0 } else { 1.0 };
+    let line_height = size / 30.0;
+    let height = line_height * lines.len() as f64;
+    // Above points in the upper half, below those in the lower half, centred otherwise
+    let ty = if dy < -0.05 {
+        y * size - gap - height + line_height * 0.8
+    } else if dy > 0.05 || anchor == "middle" {
+        y * size + gap + line_height * 0.8
+    } else {
+        y * size - height / 2.0 + line_height * 0.8
+    };
+
+    let mut text = format!("<text x=\"{:.1}\" y=\"{:.1}\" text-anchor=\"{}\">", tx, ty, anchor);
+    for (index, content) in lines.iter().enumerate() {
+        if index == 0 && lines.len() > 1 {
+            text.push_str(&format!("<tspan font-weight=\"bold\">{}</tspan>", escape(content)));
+        } else {
+            let dy = if index == 0 { "0" } else { "1.2em" };
+            text.push_str(&format!("<tspan x=\"{:.1}\" dy=\"{}\">{}</tspan>", tx, dy, escape(content)));
+        }
+    }
+    text.push_str("</text>");
+    text
+}
+
+/// Escapes text for use in XML and HTML content and attributes
+pub(crate) fn escape(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::instance::SystemInstance;
+    use crate::modules::monad::Monad;
+    use crate::modules::system::System;
+    use crate::modules::template;
+    use crate::modules::tetrad::Tetrad;
+
+    #[test]
+    fn test_every_order_has_a_figure() {
+        for order in 1..=12 {
+            let geometry = geometry(order).unwrap();
+            assert_eq!(geometry.points.len(), order, "{}", geometry.figure);
+            for &(from, to) in &geometry.edges {
+                assert!(from < order && to < order && from != to, "{}", geometry.figure);
+            }
+            for &(x, y) in &geometry.points {
+                assert!((0.0..=1.0).contains(&x) && (0.0..=1.0).contains(&y), "{}", geometry.figure);
+            }
+        }
+        assert!(geometry(0).is_none());
+        assert!(geometry(13).is_none());
+    }
+
+    #[test]
+    fn test_figures() {
+        let figures: Vec<&str> = (3..=12).map(|order| geometry(order).unwrap().figure).collect();
+        assert_eq!(
+            figures,
+            ["triangle", "square", "fan", "hexagram", "heptagon", "octagram", "enneagram", "decagon", "hendecagon", "dodecagon"]
+        );
+
+        // Completion (9) sits at the top of the enneagram, and Insight at the top of the heptagon
+        let (x, y) = geometry(9).unwrap().points[8];
+        assert!((x - 0.5).abs() < 1e-9 && y < 0.1);
+        let (x, y) = geometry(7).unwrap().points[0];
+        assert!((x - 0.5).abs() < 1e-9 && y < 0.1);
+        assert!(geometry(9).unwrap().circle);
+        assert_eq!(geometry(6).unwrap().edges.len(), 6);
+
+        // The enneagram draws the Ennead's triangle 3-6-9, then its inner cycle 1-4-2-8-5-7
+        assert_eq!(
+            geometry(9).unwrap().edges,
+            [(2, 5), (5, 8), (8, 2), (0, 3), (3, 1), (1, 7), (7, 4), (4, 6), (6, 0)]
+        );
+    }
+
+    #[test]
+    fn test_tetrad_svg() {
+        let mut document = SystemDocument::from_system(&Tetrad::new("Work & play", "Office", "Ideal", "Tools", "Plan"));
+        document.connectives[0].user_label = Some("Calling".to_string());
+
+        let svg = document.to_svg(&SvgOptions::default());
+        assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"864\" height=\"864\""), "{}", svg);
+        assert!(svg.contains("<title>Tetrad: Work &amp; play</title>"));
+        assert_eq!(svg.matches("<line").count(), 4);
+        assert_eq!(svg.matches("<circle").count(), 4);
+        assert!(svg.contains("<tspan font-weight=\"bold\">Ground</tspan>"), "{}", svg);
+        assert!(svg.contains(">Office</tspan>"));
+        // Ideal is the canonical name and the instance, so it is written once
+        assert_eq!(svg.matches(">Ideal<").count(), 1);
+        assert!(!svg.contains("Calling"));
+
+        let options = SvgOptions {
+            connectives: true,
+            ..SvgOptions::default()
+        };
+        let svg = document.to_svg(&options);
+        assert_eq!(svg.matches("<line").count(), 10);
+        assert!(svg.contains("<title>A&lt;&gt;B: Calling</title>"), "{}", svg);
+        assert!(svg.contains(">Calling</text>"));
+        assert!(svg.contains("<title>A&lt;&gt;C: "));
+    }
+
+    #[test]
+    fn test_svg_without_labels() {
+        let instance = SystemInstance::new(template::for_order(12).unwrap(), "Year");
+        let options = SvgOptions {
+            size: 200,
+            labels: false,
+            connectives: false,
+        };
+        let svg = instance.to_svg(&options);
+        assert!(!svg.contains("<text"));
+        assert_eq!(svg.matches("<line").count(), 12);
+        assert!(svg.contains("width=\"232\""), "{}", svg);
+
+        // Points land at the same percentages the overlay uses
+        let (left, top) = geometry(4).unwrap().placement(1, &options);
+        assert!((left - 50.0).abs() < 1e-9);
+        assert!((top - 18.0 / 1.16).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_monad_svg() {
+        let mut monad = Monad::new("Self");
+        let svg = monad.to_svg(&SvgOptions::default());
+        assert!(svg.contains(">Self</tspan>"), "{}", svg);
+
+        monad.add_term("Mind").unwrap();
+        monad.add_term("Body").unwrap();
+        let svg = monad.to_svg(&SvgOptions::default());
+        assert!(svg.contains(">Mind · Body</tspan>"), "{}", svg);
+        assert_eq!(svg.matches("<circle").count(), 2);
+    }
+}
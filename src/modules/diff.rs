@@ -0,0 +1,273 @@
+use serde::Serialize;
+
+use crate::modules::connective::Position;
+use crate::modules::document::{SystemDocument, TermDocument};
+use crate::modules::error::SystematicsError;
+
+const RED: &str = "\x1b[31m";
+const GREEN: &str = "\x1b[32m";
+const BOLD: &str = "\x1b[1m";
+const RESET: &str = "\x1b[0m";
+
+/// How a connective's user label differs between two systems
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LabelChange {
+    /// Only the second system labels the connective
+    Added,
+    /// Both label it, differently
+    Changed,
+    /// Only the first system labels it; the second keeps the canonical name
+    Removed,
+}
+
+/// A position whose instance differs; None where a Monad has no term at the position
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct TermDiff {
+    pub position: Position,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub canonical: Option<String>,
+    pub before: Option<String>,
+    pub after: Option<String>,
+}
+
+/// A connective whose user label differs
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ConnectiveDiff {
+    pub connective: String,
+    pub canonical_name: String,
+    pub change: LabelChange,
+    pub before: Option<String>,
+    pub after: Option<String>,
+}
+
+/// Name change between two systems
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct NameDiff {
+    pub before: String,
+    pub after: String,
+}
+
+/// What differs between two systems of the same order, by canonical position and connective
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SystemDiff {
+    pub kind: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<NameDiff>,
+    pub terms: Vec<TermDiff>,
+    pub connectives: Vec<ConnectiveDiff>,
+}
+
+/// Compares two systems of the same order: their names, the instance at each canonical
+/// position and each connective's user label
+pub fn diff(a: &SystemDocument, b: &SystemDocument) -> Result<SystemDiff, SystematicsError> {
+    if a.order != b.order {
+        return Err(SystematicsError::Structure(format!(
+            "Only systems of the same order can be compared, not a {} and a {}",
+            a.kind, b.kind
+        )));
+    }
+
+    let name = (a.name != b.name).then(|| NameDiff {
+        before: a.name.clone(),
+        after: b.name.clone(),
+    });
+
+    let mut terms = Vec::new();
+    for index in 0..a.terms.len().max(b.terms.len()) {
+        let (before, after) = (a.terms.get(index), b.terms.get(index));
+        let instance = |term: Option<&TermDocument>| term.map(|term| term.instance.clone());
+        if let Some(term) = after.or(before)
+            && instance(before) != instance(after)
+        {
+            terms.push(TermDiff {
+                position: term.position,
+                canonical: term.canonical.clone(),
+                before: instance(before),
+                after: instance(after),
+            });
+        }
+    }
+
+    let mut connectives = Vec::new();
+    for connective in &a.connectives {
+        let Some(other) = b.connectives.iter().find(|other| other.joins(connective.from, connective.to)) else {
+            continue;
+        };
+        let change = match (&connective.user_label, &other.user_label) {
+            (before, after) if before == after => continue,
+            (None, Some(_)) => LabelChange::Added,
+            (Some(_), None) => LabelChange::Removed,
+            _ => LabelChange::Changed,
+        };
+        connectives.push(ConnectiveDiff {
+            connective: connective.code(),
+            canonical_name: connective.canonical_name.clone(),
+            change,
+            before: connective.user_label.clone(),
+            after: other.user_label.clone(),
+        });
+    }
+
+    Ok(SystemDiff {
+        kind: a.kind.clone(),
+        name,
+        terms,
+        connectives,
+    })
+}
+
+impl SystemDiff {
+    /// Check if the two systems have the same name, instances and connective labels
+    pub fn is_empty(&self) -> bool {
+        self.name.is_none() && self.terms.is_empty() && self.connectives.is_empty()
+    }
+
+    /// One line per difference; with `colored`, old values are red and new ones green
+    /// using ANSI escapes for a terminal
+    pub fn to_text(&self, colored: bool) -> String {
+        let paint = |color: &str, text: String| if colored { format!("{}{}{}", color, text, RESET) } else { text };
+        let value = |value: &Option<String>| value.as_ref().map_or_else(|| "(none)".to_string(), |value| format!("'{}'", value));
+
+        if self.is_empty() {
+            return "No differences.\n".to_string();
+        }
+        let mut text = String::new();
+        if let Some(name) = &self.name {
+            text.push_str(&format!(
+                "{}: {} → {}\n",
+                paint(BOLD, "Name".to_string()),
+                paint(RED, format!("'{}'", name.before)),
+                paint(GREEN, format!("'{}'", name.after))
+            ));
+        }
+        for term in &self.terms {
+            let label = match &term.canonical {
+                Some(canonical) => format!("{} ({})", term.position, canonical),
+                None => term.position.to_string(),
+            };
+            text.push_str(&format!(
+                "{}: {} → {}\n",
+                paint(BOLD, label),
+                paint(RED, value(&term.before)),
+                paint(GREEN, value(&term.after))
+            ));
+        }
+        for connective in &self.connectives {
+            let label = paint(BOLD, format!("{} {}", connective.connective, connective.canonical_name));
+            let line = match connective.change {
+                LabelChange::Added => format!("{}: label {}", label, paint(GREEN, format!("added {}", value(&connective.after)))),
+                LabelChange::Removed => {
+                    format!("{}: label {}", label, paint(RED, format!("removed {}", value(&connective.before))))
+                }
+                LabelChange::Changed => format!(
+                    "{}: label {} → {}",
+                    label,
+                    paint(RED, value(&connective.before)),
+                    paint(GREEN, value(&connective.after))
+                ),
+            };
+            text.push_str(&line);
+            text.push('\n');
+        }
+        text
+    }
+
+    /// Pretty-printed JSON
+    pub fn to_json(&self) -> Result<String, SystematicsError> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::hexad::Hexad;
+    use crate::modules::monad::Monad;
+    use crate::modules::pentad::Pentad;
+
+    fn hexads() -> (SystemDocument, SystemDocument) {
+        let mut january = SystemDocument::from_system(&Hexad::new("Team January", "People", "Trust", "Hire", "Budget", "Churn", "Growth"));
+        january.connectives[1].user_label = Some("Old label".to_string());
+        january.connectives[2].user_label = Some("Kept".to_string());
+        january.connectives[3].user_label = Some("Dropped".to_string());
+        let mut june = january.clone();
+        june.name = "Team June".to_string();
+        june.terms[0].instance = "Staff".to_string();
+        june.terms[5].instance = "Scale".to_string();
+        june.connectives[0].user_label = Some("Calling".to_string());
+        june.connectives[1].user_label = Some("New label".to_string());
+        june.connectives[3].user_label = None;
+        (january, june)
+    }
+
+    #[test]
+    fn test_diff_reports_names_terms_and_labels() {
+        let (january, june) = hexads();
+        let diff = diff(&january, &june).unwrap();
+
+        assert_eq!(diff.kind, "Hexad");
+        assert_eq!(diff.name, Some(NameDiff { before: "Team January".to_string(), after: "Team June".to_string() }));
+        let positions: Vec<Position> = diff.terms.iter().map(|term| term.position).collect();
+        assert_eq!(positions, vec![Position::new('A'), Position::new('F')]);
+        assert_eq!(diff.terms[0].canonical.as_deref(), Some("Resources"));
+        let changes: Vec<LabelChange> = diff.connectives.iter().map(|connective| connective.change).collect();
+        assert_eq!(changes, vec![LabelChange::Added, LabelChange::Changed, LabelChange::Removed]);
+    }
+
+    #[test]
+    fn test_text_output() {
+        let (january, june) = hexads();
+        let text = diff(&january, &june).unwrap().to_text(false);
+        let lines: Vec<&str> = text.lines().collect();
+
+        assert_eq!(lines[0], "Name: 'Team January' → 'Team June'");
+        assert_eq!(lines[1], "A (Resources): 'People' → 'Staff'");
+        assert_eq!(lines[2], "F (Priorities): 'Growth' → 'Scale'");
+        assert!(lines[3].ends_with(": label added 'Calling'"), "{}", text);
+        assert!(lines[4].ends_with(": label 'Old label' → 'New label'"), "{}", text);
+        assert!(lines[5].ends_with(": label removed 'Dropped'"), "{}", text);
+        assert_eq!(lines.len(), 6);
+        assert!(!text.contains('\x1b'));
+        assert_eq!(diff(&june, &june).unwrap().to_text(false), "No differences.\n");
+    }
+
+    #[test]
+    fn test_colored_output() {
+        let (january, june) = hexads();
+        let text = diff(&january, &june).unwrap().to_text(true);
+
+        assert!(text.starts_with("\x1b[1mName\x1b[0m: \x1b[31m'Team January'\x1b[0m → \x1b[32m'Team June'\x1b[0m\n"), "{:?}", text);
+    }
+
+    #[test]
+    fn test_json_output() {
+        let (january, june) = hexads();
+        let json: serde_json::Value = serde_json::from_str(&diff(&january, &june).unwrap().to_json().unwrap()).unwrap();
+
+        assert_eq!(json["name"]["after"], "Team June");
+        assert_eq!(json["terms"][0]["position"], "A");
+        assert_eq!(json["terms"][0]["before"], "People");
+        assert_eq!(json["connectives"][0]["change"], "added");
+        assert_eq!(json["connectives"][0]["before"], serde_json::Value::Null);
+    }
+
+    #[test]
+    fn test_orders_must_match() {
+        let pentad = SystemDocument::from_system(&Pentad::new("Five", "A", "B", "C", "D", "E"));
+        let (hexad, _) = hexads();
+        let error = diff(&pentad, &hexad).unwrap_err();
+        assert_eq!(error.to_string(), "Only systems of the same order can be compared, not a Pentad and a Hexad");
+    }
+
+    #[test]
+    fn test_monads_with_different_term_counts() {
+        let mut one = Monad::new("Whole");
+        one.terms = vec!["Body".to_string()];
+        let mut two = one.clone();
+        two.terms.push("Mind".to_string());
+
+        let diff = diff(&SystemDocument::from_system(&one), &SystemDocument::from_system(&two)).unwrap();
+        assert_eq!(diff.to_text(false), "B: (none) → 'Mind'\n");
+    }
+}
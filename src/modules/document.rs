@@ -0,0 +1,482 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::modules::authoring::{authoring_key, AuthoringFormat};
+use crate::modules::connective::{Connective, Position};
+use crate::modules::dyad::Dyad;
+use crate::modules::error::SystematicsError;
+use crate::modules::instance::SystemInstance;
+use crate::modules::monad::Monad;
+use crate::modules::permutations::{generate_six_permutations, Permutation};
+use crate::modules::store::write_atomic;
+use crate::modules::system::{kind_name, System};
+use crate::modules::template;
+use crate::modules::triad::Triad;
+use crate::modules::validation::ValidationPolicy;
+
+/// Version of the JSON layout written by `SystemDocument`
+pub const SCHEMA_VERSION: u32 = 1;
+
+/// A canonical position and the user's instance for it
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TermDocument {
+    pub position: Position,
+    /// Canonical term label; absent for the Monad, which has no fixed positions
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub canonical: Option<String>,
+    pub instance: String,
+    /// Store id of a system elaborating this term, e.g. a Tetrad for one Octad term
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub child: Option<u64>,
+}
+
+/// Versioned, serializable form of any system.
+///
+/// Built from the `System` trait, so every order shares one JSON layout:
+/// name, order, instances, connectives and free-form metadata.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SystemDocument {
+    pub schema_version: u32,
+    pub kind: String,
+    pub order: usize,
+    pub name: String,
+    pub attribute: String,
+    pub terms: Vec<TermDocument>,
+    #[serde(default)]
+    pub connectives: Vec<Connective>,
+    #[serde(default)]
+    pub metadata: BTreeMap<String, String>,
+    /// A Triad's six laws, when saved with it
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub permutations: Vec<Permutation>,
+}
+
+impl SystemDocument {
+    /// Captures a system of any order. A Monad's terms past position Z, which
+    /// `Monad::add_term` never allows, have no letter and are left out.
+    pub fn from_system<S: System + ?Sized>(system: &S) -> Self {
+        let canonical = system.canonical_terms();
+        let terms = system
+            .instances()
+            .into_iter()
+            .enumerate()
+            .map_while(|(index, instance)| {
+                Some(TermDocument {
+                    position: Position::from_index(index)?,
+                    canonical: canonical.get(index).map(|label| label.to_string()),
+                    instance,
+                    child: None,
+                })
+            })
+            .collect();
+
+        SystemDocument {
+            schema_version: SCHEMA_VERSION,
+            kind: system.kind().to_string(),
+            order: system.order(),
+            name: system.name().to_string(),
+            attribute: system.attribute_description().to_string(),
+            terms,
+            connectives: system.connectives().to_vec(),
+            metadata: BTreeMap::new(),
+            permutations: Vec::new(),
+        }
+    }
+
+    /// Document for a new system of the given order, with canonical terms as instances
+    pub fn canonical(order: usize, name: &str) -> Result<Self, SystematicsError> {
+        let system: Box<dyn System> = match order {
+            1 => Box::new(Monad::new(name)),
+            2 => Box::new(Dyad::new(name, "Essence", "Existence")),
+            3 => Box::new(Triad::new(name, "Active", "Passive", "Reconciling")),
+            _ => {
+                let template =
+                    template::for_order(order).ok_or(SystematicsError::UnknownOrder(order))?;
+                Box::new(SystemInstance::new(template, name))
+            }
+        };
+        Ok(Self::from_system(system.as_ref()))
+    }
+
+    /// Adds a metadata entry, replacing any previous value for the key
+    pub fn with_metadata(mut self, key: &str, value: &str) -> Self {
+        self.metadata.insert(key.to_string(), value.to_string());
+        self
+    }
+
+    /// Saves a Triad's six laws with it, generated from its current instances
+    pub fn with_six_laws(mut self) -> Result<Self, SystematicsError> {
+        self.check()?;
+        if self.order != 3 {
+            return Err(SystematicsError::Structure(format!("A {} has no six laws, only a Triad", self.kind)));
+        }
+        self.permutations = generate_six_permutations(&self.terms[0].instance, &self.terms[1].instance, &self.terms[2].instance);
+        Ok(self)
+    }
+
+    /// Nested systems by the position of the term they elaborate
+    pub fn children(&self) -> Vec<(Position, u64)> {
+        self.terms.iter().filter_map(|term| term.child.map(|child| (term.position, child))).collect()
+    }
+
+    /// User instances in positional order
+    pub fn instances(&self) -> Vec<String> {
+        self.terms.iter().map(|term| term.instance.clone()).collect()
+    }
+
+    /// Cleans the name, instances and connective labels with the policy, naming a
+    /// rejected value by its authoring key (e.g. "ground")
+    pub fn validate_values(&mut self, policy: &ValidationPolicy) -> Result<(), SystematicsError> {
+        let check = |field: &str, value: &str| policy.validate(value).map_err(|reason| SystematicsError::validation(field, reason));
+        self.name = check("name", &self.name)?;
+        for term in &mut self.terms {
+            let field = term.canonical.as_deref().map_or_else(|| "term".to_string(), authoring_key);
+            term.instance = check(&field, &term.instance)?;
+        }
+        for connective in &mut self.connectives {
+            if let Some(label) = &connective.user_label {
+                connective.user_label = Some(check(&authoring_key(&connective.canonical_name), label)?);
+            }
+        }
+        Ok(())
+    }
+
+    /// Pretty-printed JSON
+    pub fn to_json(&self) -> Result<String, SystematicsError> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    /// Parses JSON, rejecting documents written with another schema version
+    pub fn from_json(json: &str) -> Result<Self, SystematicsError> {
+        let value: serde_json::Value = serde_json::from_str(json)?;
+        let version = value
+            .get("schema_version")
+            .ok_or_else(|| SystematicsError::Parse("Missing schema_version in system document".to_string()))?;
+        let version = version
+            .as_u64()
+            .ok_or_else(|| SystematicsError::Parse(format!("Invalid schema_version {} in system document", version)))?;
+        if version != u64::from(SCHEMA_VERSION) {
+            return Err(SystematicsError::Parse(format!(
+                "Unsupported schema version {} (this build reads version {})",
+                version, SCHEMA_VERSION
+            )));
+        }
+
+        let document: SystemDocument = serde_json::from_value(value)?;
+        document.check()?;
+        Ok(document)
+    }
+
+    /// Writes the document to a file: TOML or YAML for .toml, .yaml and .yml paths, JSON otherwise
+    pub fn save(&self, path: &Path) -> Result<(), SystematicsError> {
+        let text = match AuthoringFormat::for_path(path) {
+            Some(format) => self.to_authoring(format),
+            None => self.to_json()?,
+        };
+        write_atomic(path, &text)
+    }
+
+    /// Reads a document from a file, choosing the format by extension as `save` does,
+    /// and cleans its values with the policy
+    pub fn load(path: &Path, policy: &ValidationPolicy) -> Result<Self, SystematicsError> {
+        let text = fs::read_to_string(path)?;
+        match AuthoringFormat::for_path(path) {
+            Some(format) => Self::from_authoring(&text, format, policy),
+            None => {
+                let mut document = Self::from_json(&text)?;
+                document.validate_values(policy)?;
+                Ok(document)
+            }
+        }
+    }
+
+    /// Rebuilds a template-driven system (orders 4 to 12)
+    pub fn to_instance(&self) -> Result<SystemInstance, SystematicsError> {
+        let template = template::for_order(self.order)
+            .ok_or(SystematicsError::UnknownOrder(self.order))?;
+        SystemInstance::from_parts(template, &self.name, self.instances(), self.connectives.clone())
+    }
+
+    /// Rebuilds the system this document was captured from
+    pub fn to_system(&self) -> Result<Box<dyn System>, SystematicsError> {
+        self.check()?;
+        let instances = self.instances();
+        let system: Box<dyn System> = match self.order {
+            1 => {
+                let mut monad = Monad::new(&self.name);
+                monad.terms = instances;
+                Box::new(monad)
+            }
+            2 => Box::new(Dyad::new(&self.name, &instances[0], &instances[1])),
+            3 => Box::new(Triad::new(&self.name, &instances[0], &instances[1], &instances[2])),
+            _ => Box::new(self.to_instance()?),
+        };
+        Ok(system)
+    }
+
+    /// Checks the document is internally consistent for its order
+    fn check(&self) -> Result<(), SystematicsError> {
+        let expected_kind =
+            kind_name(self.order).ok_or(SystematicsError::UnknownOrder(self.order))?;
+        if self.kind != expected_kind {
+            return Err(SystematicsError::Structure(format!(
+                "Order {} is a {}, not a {}",
+                self.order, expected_kind, self.kind
+            )));
+        }
+
+        // The Monad holds any number of terms; every other order has one per position
+        if self.order > 1 && self.terms.len() != self.order {
+            return Err(SystematicsError::Structure(format!(
+                "{} needs {} terms, got {}",
+                self.kind,
+                self.order,
+                self.terms.len()
+            )));
+        }
+        if self.terms.len() > Monad::MAX_TERMS {
+            return Err(SystematicsError::Structure(format!(
+                "A Monad holds at most {} terms, got {}",
+                Monad::MAX_TERMS,
+                self.terms.len()
+            )));
+        }
+        for (index, term) in self.terms.iter().enumerate() {
+            if let Some(expected) = Position::from_index(index)
+                && term.position != expected
+            {
+                return Err(SystematicsError::Structure(format!(
+                    "Term {} is out of order (expected {})",
+                    term.position, expected
+                )));
+            }
+        }
+        if self.order != 3 && !self.permutations.is_empty() {
+            return Err(SystematicsError::Structure(format!("A {} has no six laws, only a Triad", self.kind)));
+        }
+        if self.order <= 3 && !self.connectives.is_empty() {
+            return Err(SystematicsError::Structure(format!(
+                "{} has no connectives, got {}",
+                self.kind,
+                self.connectives.len()
+            )));
+        }
+        if self.order >= 4 {
+            self.check_connectives()?;
+        }
+        Ok(())
+    }
+
+    /// Every pair of positions needs exactly one connective, as the template lists them
+    fn check_connectives(&self) -> Result<(), SystematicsError> {
+        let template = template::for_order(self.order).ok_or(SystematicsError::UnknownOrder(self.order))?;
+        for (index, connective) in self.connectives.iter().enumerate() {
+            if self.connectives[..index].iter().any(|earlier| earlier.joins(connective.from, connective.to)) {
+                return Err(SystematicsError::Structure(format!(
+                    "{} has connective {}<>{} more than once",
+                    self.kind, connective.from, connective.to
+                )));
+            }
+        }
+        if self.connectives.len() != template.connectives.len() {
+            return Err(SystematicsError::Structure(format!(
+                "{} needs {} connectives, got {}",
+                self.kind,
+                template.connectives.len(),
+                self.connectives.len()
+            )));
+        }
+        for slot in template.connectives {
+            if !self.connectives.iter().any(|connective| connective.joins(slot.from, slot.to)) {
+                return Err(SystematicsError::Structure(format!(
+                    "{} is missing connective {}<>{}",
+                    self.kind, slot.from, slot.to
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::connective::Direction;
+    use crate::modules::hexad::Hexad;
+    use crate::modules::tetrad::Tetrad;
+
+    #[test]
+    fn test_document_fields() {
+        let tetrad = Tetrad::new("Work", "Office", "Mission", "Tools", "Plan");
+        let document = SystemDocument::from_system(&tetrad);
+
+        assert_eq!(document.schema_version, SCHEMA_VERSION);
+        assert_eq!(document.kind, "Tetrad");
+        assert_eq!(document.order, 4);
+        assert_eq!(document.terms[1].position, Position::new('B'));
+        assert_eq!(document.terms[1].canonical.as_deref(), Some("Ideal"));
+        assert_eq!(document.terms[1].instance, "Mission");
+        assert_eq!(document.connectives.len(), 6);
+    }
+
+    #[test]
+    fn test_json_round_trip_is_lossless() {
+        let mut hexad = Hexad::new("Decision", "Budget", "Care", "Paths", "Tests", "Data", "Goals");
+        hexad.set_connective_label('A', 'B', Some("Cost of care".to_string())).unwrap();
+        let connective = hexad.connective_mut('C', 'F').unwrap();
+        connective.direction = Direction::Backward;
+        connective.notes = Some("Priorities prune the options".to_string());
+
+        let document = SystemDocument::from_system(&hexad).with_metadata("source", "workshop");
+        let json = document.to_json().unwrap();
+        let loaded = SystemDocument::from_json(&json).unwrap();
+
+        assert_eq!(loaded, document);
+        assert_eq!(loaded.metadata["source"], "workshop");
+        assert_eq!(Hexad::try_from(loaded.to_instance().unwrap()).unwrap(), hexad);
+    }
+
+    #[test]
+    fn test_round_trip_every_order() {
+        let mut monad = Monad::new("One");
+        monad.add_term("Whole").unwrap();
+        let systems: Vec<Box<dyn System>> = vec![
+            Box::new(monad),
+            Box::new(Dyad::new("Two", "Essence", "Existence")),
+            Box::new(Triad::new("Three", "Push", "Pull", "Balance")),
+        ];
+        let systems = systems
+            .into_iter()
+            .chain(template::all().into_iter().map(|t| Box::new(SystemInstance::new(t, t.kind)) as Box<dyn System>));
+
+        for system in systems {
+            let document = SystemDocument::from_system(system.as_ref());
+            let loaded = SystemDocument::from_json(&document.to_json().unwrap()).unwrap();
+            let rebuilt = loaded.to_system().unwrap();
+
+            assert_eq!(rebuilt.kind(), system.kind());
+            assert_eq!(rebuilt.name(), system.name());
+            assert_eq!(rebuilt.instances(), system.instances());
+            assert_eq!(rebuilt.connectives(), system.connectives());
+        }
+    }
+
+    #[test]
+    fn test_canonical_documents() {
+        for order in 1..=12 {
+            let document = SystemDocument::canonical(order, "Blank").unwrap();
+            assert_eq!(document.order, order);
+            assert!(document.terms.iter().all(|term| term.canonical.as_ref() == Some(&term.instance)));
+            assert!(document.to_system().is_ok());
+        }
+        assert!(SystemDocument::canonical(13, "Blank").is_err());
+    }
+
+    #[test]
+    fn test_schema_version_mismatch() {
+        let tetrad = Tetrad::new("Work", "Office", "Mission", "Tools", "Plan");
+        let json = SystemDocument::from_system(&tetrad)
+            .to_json()
+            .unwrap()
+            .replace("\"schema_version\": 1", "\"schema_version\": 2");
+
+        let error = SystemDocument::from_json(&json).unwrap_err();
+        assert!(matches!(error, SystematicsError::Parse(_)));
+        assert_eq!(error.to_string(), "Unsupported schema version 2 (this build reads version 1)");
+    }
+
+    #[test]
+    fn test_missing_schema_version() {
+        let error = SystemDocument::from_json(r#"{"kind": "Triad"}"#).unwrap_err();
+        assert_eq!(error.to_string(), "Missing schema_version in system document");
+    }
+
+    #[test]
+    fn test_inconsistent_documents_are_rejected() {
+        let tetrad = Tetrad::new("Work", "Office", "Mission", "Tools", "Plan");
+        let document = SystemDocument::from_system(&tetrad);
+
+        let mut wrong_kind = document.clone();
+        wrong_kind.kind = "Pentad".to_string();
+        let error = SystemDocument::from_json(&wrong_kind.to_json().unwrap()).unwrap_err();
+        assert!(matches!(error, SystematicsError::Structure(_)));
+        assert_eq!(error.to_string(), "Order 4 is a Tetrad, not a Pentad");
+
+        let mut missing_term = document.clone();
+        missing_term.terms.pop();
+        let error = SystemDocument::from_json(&missing_term.to_json().unwrap()).unwrap_err();
+        assert_eq!(error.to_string(), "Tetrad needs 4 terms, got 3");
+
+        let mut missing_connective = document.clone();
+        missing_connective.connectives.remove(0);
+        let error = SystemDocument::from_json(&missing_connective.to_json().unwrap()).unwrap_err();
+        assert_eq!(error.to_string(), "Tetrad needs 6 connectives, got 5");
+
+        let mut duplicate_connective = document.clone();
+        duplicate_connective.connectives[5] = duplicate_connective.connectives[0].clone();
+        let error = SystemDocument::from_json(&duplicate_connective.to_json().unwrap()).unwrap_err();
+        assert_eq!(error.to_string(), "Tetrad has connective A<>B more than once");
+
+        let mut stray_connective = document;
+        stray_connective.connectives[5].to = Position::new('E');
+        let error = SystemDocument::from_json(&stray_connective.to_json().unwrap()).unwrap_err();
+        assert_eq!(error.to_string(), "Tetrad is missing connective C<>D");
+
+        let mut crowded = SystemDocument::from_system(&Monad::new("Crowd"));
+        crowded.terms = (0..27)
+            .map(|index| TermDocument {
+                position: Position::new('A'),
+                canonical: None,
+                instance: index.to_string(),
+                child: None,
+            })
+            .collect();
+        let error = SystemDocument::from_json(&crowded.to_json().unwrap()).unwrap_err();
+        assert_eq!(error.to_string(), "A Monad holds at most 26 terms, got 27");
+
+        let mut unknown_order = SystemDocument::from_system(&tetrad);
+        unknown_order.order = 13;
+        assert!(matches!(unknown_order.to_system(), Err(SystematicsError::UnknownOrder(13))));
+    }
+
+    #[test]
+    fn test_six_laws_saved_with_a_triad() {
+        let triad = Triad::new("Will", "Intention", "Resistance", "Resolution");
+        let document = SystemDocument::from_system(&triad).with_six_laws().unwrap();
+
+        assert_eq!(document.permutations, triad.six_laws());
+        let loaded = SystemDocument::from_json(&document.to_json().unwrap()).unwrap();
+        assert_eq!(loaded.permutations[5].roles, [3, 2, 1]);
+
+        // Documents without laws don't mention them
+        assert!(!SystemDocument::from_system(&triad).to_json().unwrap().contains("permutations"));
+        let tetrad = SystemDocument::from_system(&Tetrad::new("Work", "G", "I", "In", "D"));
+        assert_eq!(tetrad.with_six_laws().unwrap_err().to_string(), "A Tetrad has no six laws, only a Triad");
+    }
+
+    #[test]
+    fn test_save_and_load_file() {
+        let path = std::env::temp_dir().join(format!("systematics-document-{}.json", std::process::id()));
+        let document = SystemDocument::from_system(&Triad::new("Will", "Intention", "Resistance", "Resolution"));
+
+        document.save(&path).unwrap();
+        let loaded = SystemDocument::load(&path, &ValidationPolicy::default()).unwrap();
+        assert_eq!(loaded, document);
+
+        // The workspace policy applies to every file read, in any format
+        let strict = ValidationPolicy {
+            max_length: 8,
+            ..ValidationPolicy::default()
+        };
+        let error = SystemDocument::load(&path, &strict).unwrap_err();
+        assert_eq!(error.to_string(), "active is too long (max 8 characters)");
+        let toml = path.with_extension("toml");
+        document.save(&toml).unwrap();
+        assert_eq!(SystemDocument::load(&toml, &strict).unwrap_err().to_string(), error.to_string());
+        fs::remove_file(&path).unwrap();
+        fs::remove_file(&toml).unwrap();
+    }
+}
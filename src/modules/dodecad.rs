@@ -1,24 +1,111 @@
-#[derive(Debug)]
+use std::ops::{Deref, DerefMut};
+
+use crate::modules::connective::Connective;
+use crate::modules::error::SystematicsError;
+use crate::modules::instance::SystemInstance;
+use crate::modules::prompter::{IoPrompter, Prompter};
+use crate::modules::system::System;
+use crate::modules::template::{ConnectiveTemplate, SystemTemplate, TermTemplate};
+
+/// Canonical Dodecad positions and connectives
+pub static TEMPLATE: SystemTemplate = SystemTemplate {
+    kind: "Dodecad",
+    order: 12,
+    attribute_description: Dodecad::TERM_ATTRIBUTE_DESCRIPTION,
+    terms: &[
+        TermTemplate { position: 'A', label: "Autocracy" },
+        TermTemplate { position: 'B', label: "Domination" },
+        TermTemplate { position: 'C', label: "Creativity" },
+        TermTemplate { position: 'D', label: "Pattern" },
+        TermTemplate { position: 'E', label: "Individuality" },
+        TermTemplate { position: 'F', label: "Structure" },
+        TermTemplate { position: 'G', label: "Repetition" },
+        TermTemplate { position: 'H', label: "Potentiality" },
+        TermTemplate { position: 'I', label: "Subsistence" },
+        TermTemplate { position: 'J', label: "Relatedness" },
+        TermTemplate { position: 'K', label: "Polarity" },
+        TermTemplate { position: 'L', label: "Wholeness" },
+    ],
+    connectives: &[
+        ConnectiveTemplate { from: 'A', to: 'B', name: "Sovereignty", description: "Self-rule asserting itself over others" },
+        ConnectiveTemplate { from: 'A', to: 'C', name: "Initiative", description: "Self-determination giving rise to the new" },
+        ConnectiveTemplate { from: 'A', to: 'D', name: "Design", description: "Self-rule choosing the form it will take" },
+        ConnectiveTemplate { from: 'A', to: 'E', name: "Selfhood", description: "Autonomy expressed as a distinct identity" },
+        ConnectiveTemplate { from: 'A', to: 'F', name: "Governance", description: "Self-rule embodied in lasting institutions" },
+        ConnectiveTemplate { from: 'A', to: 'G', name: "Discipline", description: "Self-rule sustained through repeated practice" },
+        ConnectiveTemplate { from: 'A', to: 'H', name: "Will", description: "Self-determination acting on what might be" },
+        ConnectiveTemplate { from: 'A', to: 'I', name: "Independence", description: "Self-rule secured by its own means of living" },
+        ConnectiveTemplate { from: 'A', to: 'J', name: "Diplomacy", description: "Self-rule negotiating its place among others" },
+        ConnectiveTemplate { from: 'A', to: 'K', name: "Resolve", description: "Self-rule holding firm between opposing pulls" },
+        ConnectiveTemplate { from: 'A', to: 'L', name: "Integrity", description: "Self-rule answering to the whole it belongs to" },
+        ConnectiveTemplate { from: 'B', to: 'C', name: "Conquest", description: "Power opening up new ground" },
+        ConnectiveTemplate { from: 'B', to: 'D', name: "Order", description: "Power imposing its form on things" },
+        ConnectiveTemplate { from: 'B', to: 'E', name: "Mastery", description: "Power concentrated in a single agent" },
+        ConnectiveTemplate { from: 'B', to: 'F', name: "Hierarchy", description: "Power fixed into ranks and offices" },
+        ConnectiveTemplate { from: 'B', to: 'G', name: "Enforcement", description: "Power maintained by repeated sanction" },
+        ConnectiveTemplate { from: 'B', to: 'H', name: "Ambition", description: "Power reaching toward what could be won" },
+        ConnectiveTemplate { from: 'B', to: 'I', name: "Control", description: "Power over the means of subsistence" },
+        ConnectiveTemplate { from: 'B', to: 'J', name: "Influence", description: "Power exercised through relationships" },
+        ConnectiveTemplate { from: 'B', to: 'K', name: "Conflict", description: "Power meeting opposing power" },
+        ConnectiveTemplate { from: 'B', to: 'L', name: "Authority", description: "Power the whole accepts as legitimate" },
+        ConnectiveTemplate { from: 'C', to: 'D', name: "Invention", description: "Creativity finding a new form" },
+        ConnectiveTemplate { from: 'C', to: 'E', name: "Originality", description: "Creativity bearing the mark of its maker" },
+        ConnectiveTemplate { from: 'C', to: 'F', name: "Construction", description: "Creativity building something that stands" },
+        ConnectiveTemplate { from: 'C', to: 'G', name: "Craft", description: "Creativity refined through practice" },
+        ConnectiveTemplate { from: 'C', to: 'H', name: "Imagination", description: "Creativity playing among possibilities" },
+        ConnectiveTemplate { from: 'C', to: 'I', name: "Enterprise", description: "Creativity turned to making a living" },
+        ConnectiveTemplate { from: 'C', to: 'J', name: "Collaboration", description: "Creativity shared between people" },
+        ConnectiveTemplate { from: 'C', to: 'K', name: "Tension", description: "Creativity sparked by opposites" },
+        ConnectiveTemplate { from: 'C', to: 'L', name: "Vision", description: "Creativity that sees the whole" },
+        ConnectiveTemplate { from: 'D', to: 'E', name: "Character", description: "Pattern taken up as individual style" },
+        ConnectiveTemplate { from: 'D', to: 'F', name: "Architecture", description: "Pattern realised in enduring structure" },
+        ConnectiveTemplate { from: 'D', to: 'G', name: "Rhythm", description: "Pattern recurring in time" },
+        ConnectiveTemplate { from: 'D', to: 'H', name: "Blueprint", description: "Pattern held as a possibility not yet built" },
+        ConnectiveTemplate { from: 'D', to: 'I', name: "Habitat", description: "Pattern of the conditions that sustain life" },
+        ConnectiveTemplate { from: 'D', to: 'J', name: "Network", description: "Pattern of connections among parts" },
+        ConnectiveTemplate { from: 'D', to: 'K', name: "Symmetry", description: "Pattern balanced between opposites" },
+        ConnectiveTemplate { from: 'D', to: 'L', name: "Harmony", description: "Pattern in which the whole agrees with itself" },
+        ConnectiveTemplate { from: 'E', to: 'F', name: "Role", description: "An individual placed within a structure" },
+        ConnectiveTemplate { from: 'E', to: 'G', name: "Habit", description: "An individual shaped by repetition" },
+        ConnectiveTemplate { from: 'E', to: 'H', name: "Aspiration", description: "An individual reaching toward its potential" },
+        ConnectiveTemplate { from: 'E', to: 'I', name: "Livelihood", description: "An individual sustaining its own existence" },
+        ConnectiveTemplate { from: 'E', to: 'J', name: "Encounter", description: "An individual meeting another" },
+        ConnectiveTemplate { from: 'E', to: 'K', name: "Identity", description: "An individual defined by what it is not" },
+        ConnectiveTemplate { from: 'E', to: 'L', name: "Vocation", description: "An individual finding its place in the whole" },
+        ConnectiveTemplate { from: 'F', to: 'G', name: "Procedure", description: "Structure enacted again and again" },
+        ConnectiveTemplate { from: 'F', to: 'H', name: "Capacity", description: "Structure that makes new things possible" },
+        ConnectiveTemplate { from: 'F', to: 'I', name: "Infrastructure", description: "Structure that keeps life going" },
+        ConnectiveTemplate { from: 'F', to: 'J', name: "Organisation", description: "Structure ordering relationships" },
+        ConnectiveTemplate { from: 'F', to: 'K', name: "Balance", description: "Structure holding opposing forces in check" },
+        ConnectiveTemplate { from: 'F', to: 'L', name: "Framework", description: "Structure that holds the whole together" },
+        ConnectiveTemplate { from: 'G', to: 'H', name: "Practice", description: "Repetition that builds capability" },
+        ConnectiveTemplate { from: 'G', to: 'I', name: "Maintenance", description: "Repetition that keeps things alive" },
+        ConnectiveTemplate { from: 'G', to: 'J', name: "Custom", description: "Repetition shared among people" },
+        ConnectiveTemplate { from: 'G', to: 'K', name: "Cycle", description: "Repetition swinging between poles" },
+        ConnectiveTemplate { from: 'G', to: 'L', name: "Tradition", description: "Repetition that carries the whole forward" },
+        ConnectiveTemplate { from: 'H', to: 'I', name: "Growth", description: "Potential realised as means of living" },
+        ConnectiveTemplate { from: 'H', to: 'J', name: "Opportunity", description: "Potential opened up by relationships" },
+        ConnectiveTemplate { from: 'H', to: 'K', name: "Choice", description: "Potential divided between alternatives" },
+        ConnectiveTemplate { from: 'H', to: 'L', name: "Promise", description: "Potential of the whole not yet realised" },
+        ConnectiveTemplate { from: 'I', to: 'J', name: "Exchange", description: "Subsistence gained through relationships" },
+        ConnectiveTemplate { from: 'I', to: 'K', name: "Scarcity", description: "Subsistence caught between need and supply" },
+        ConnectiveTemplate { from: 'I', to: 'L', name: "Economy", description: "Subsistence organised across the whole" },
+        ConnectiveTemplate { from: 'J', to: 'K', name: "Dialogue", description: "Relatedness that holds difference" },
+        ConnectiveTemplate { from: 'J', to: 'L', name: "Community", description: "Relatedness forming a whole" },
+        ConnectiveTemplate { from: 'K', to: 'L', name: "Reconciliation", description: "Opposites united in the whole" },
+    ],
+};
+
+/// Typed view over a 12-term `SystemInstance` built from the Dodecad template
+#[derive(Debug, Clone, PartialEq)]
 pub struct Dodecad {
-    pub name: String,
-    pub autocracy: String,
-    pub domination: String,
-    pub creativity: String,
-    pub pattern: String,
-    pub individuality: String,
-    pub structure: String,
-    pub repetition: String,
-    pub potentiality: String,
-    pub subsistence: String,
-    pub relatedness: String,
-    pub polarity: String,
-    pub wholeness: String,
+    system: SystemInstance,
 }
 
 impl Dodecad {
     pub const TERM_ATTRIBUTE_DESCRIPTION: &'static str = "Totality";
 
-    /// Creates a new Dodecad.
+    /// Creates a new Dodecad with user instances for canonical positions
     #[allow(clippy::too_many_arguments)]
     pub fn new(
         name: &str,
@@ -35,20 +122,287 @@ impl Dodecad {
         polarity: &str,
         wholeness: &str,
     ) -> Self {
-        Dodecad {
-            name: name.to_string(),
-            autocracy: autocracy.to_string(),
-            domination: domination.to_string(),
-            creativity: creativity.to_string(),
-            pattern: pattern.to_string(),
-            individuality: individuality.to_string(),
-            structure: structure.to_string(),
-            repetition: repetition.to_string(),
-            potentiality: potentiality.to_string(),
-            subsistence: subsistence.to_string(),
-            relatedness: relatedness.to_string(),
-            polarity: polarity.to_string(),
-            wholeness: wholeness.to_string(),
+        let instances = [
+            autocracy,
+            domination,
+            creativity,
+            pattern,
+            individuality,
+            structure,
+            repetition,
+            potentiality,
+            subsistence,
+            relatedness,
+            polarity,
+            wholeness,
+        ];
+        let system = SystemInstance::with_instances(&TEMPLATE, name, &instances)
+            .expect("Dodecad template has twelve positions");
+        Dodecad { system }
+    }
+
+    /// Interactive creation method on the terminal
+    pub fn create_interactive() -> Result<Self, SystematicsError> {
+        Self::create_with(&mut IoPrompter::stdio())
+    }
+
+    /// Interactive creation through any prompter
+    pub fn create_with(prompter: &mut dyn Prompter) -> Result<Self, SystematicsError> {
+        SystemInstance::create_with(&TEMPLATE, prompter).map(|system| Dodecad { system })
+    }
+
+    /// Autocracy instance (position A)
+    pub fn autocracy(&self) -> &str {
+        self.system.instance_at(0)
+    }
+
+    /// Domination instance (position B)
+    pub fn domination(&self) -> &str {
+        self.system.instance_at(1)
+    }
+
+    /// Creativity instance (position C)
+    pub fn creativity(&self) -> &str {
+        self.system.instance_at(2)
+    }
+
+    /// Pattern instance (position D)
+    pub fn pattern(&self) -> &str {
+        self.system.instance_at(3)
+    }
+
+    /// Individuality instance (position E)
+    pub fn individuality(&self) -> &str {
+        self.system.instance_at(4)
+    }
+
+    /// Structure instance (position F)
+    pub fn structure(&self) -> &str {
+        self.system.instance_at(5)
+    }
+
+    /// Repetition instance (position G)
+    pub fn repetition(&self) -> &str {
+        self.system.instance_at(6)
+    }
+
+    /// Potentiality instance (position H)
+    pub fn potentiality(&self) -> &str {
+        self.system.instance_at(7)
+    }
+
+    /// Subsistence instance (position I)
+    pub fn subsistence(&self) -> &str {
+        self.system.instance_at(8)
+    }
+
+    /// Relatedness instance (position J)
+    pub fn relatedness(&self) -> &str {
+        self.system.instance_at(9)
+    }
+
+    /// Polarity instance (position K)
+    pub fn polarity(&self) -> &str {
+        self.system.instance_at(10)
+    }
+
+    /// Wholeness instance (position L)
+    pub fn wholeness(&self) -> &str {
+        self.system.instance_at(11)
+    }
+
+    /// Get canonical term names
+    pub fn get_canonical_terms() -> Vec<&'static str> {
+        TEMPLATE.canonical_terms()
+    }
+
+    /// Get all user instances
+    pub fn get_instances(&self) -> Vec<String> {
+        self.system.instances().to_vec()
+    }
+
+    /// Underlying data-driven system
+    pub fn into_instance(self) -> SystemInstance {
+        self.system
+    }
+}
+
+impl Deref for Dodecad {
+    type Target = SystemInstance;
+
+    fn deref(&self) -> &SystemInstance {
+        &self.system
+    }
+}
+
+impl DerefMut for Dodecad {
+    fn deref_mut(&mut self) -> &mut SystemInstance {
+        &mut self.system
+    }
+}
+
+impl TryFrom<SystemInstance> for Dodecad {
+    type Error = SystematicsError;
+
+    fn try_from(system: SystemInstance) -> Result<Self, Self::Error> {
+        if !std::ptr::eq(system.template(), &TEMPLATE) {
+            return Err(SystematicsError::Structure(format!("Expected Dodecad template, got {}", system.template().kind)));
         }
+        Ok(Dodecad { system })
+    }
+}
+
+impl System for Dodecad {
+    fn kind(&self) -> &'static str {
+        self.system.template().kind
+    }
+
+    fn order(&self) -> usize {
+        self.system.order()
     }
-} 
\ No newline at end of file
+
+    fn name(&self) -> &str {
+        &self.system.name
+    }
+
+    fn canonical_terms(&self) -> Vec<&'static str> {
+        Self::get_canonical_terms()
+    }
+
+    fn instances(&self) -> Vec<String> {
+        self.get_instances()
+    }
+
+    fn connectives(&self) -> &[Connective] {
+        self.system.connectives()
+    }
+
+    fn attribute_description(&self) -> &'static str {
+        Self::TERM_ATTRIBUTE_DESCRIPTION
+    }
+
+    fn display(&self) {
+        self.system.display()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Dodecad {
+        Dodecad::new("Test", "One", "Two", "Three", "Four", "Five", "Six", "Seven", "Eight", "Nine", "Ten", "Eleven", "Twelve")
+    }
+
+    #[test]
+    fn test_dodecad_creation() {
+        let dodecad = sample();
+
+        assert_eq!(dodecad.name, "Test");
+        assert_eq!(dodecad.autocracy(), "One");
+        assert_eq!(dodecad.domination(), "Two");
+        assert_eq!(dodecad.creativity(), "Three");
+        assert_eq!(dodecad.pattern(), "Four");
+        assert_eq!(dodecad.individuality(), "Five");
+        assert_eq!(dodecad.structure(), "Six");
+        assert_eq!(dodecad.repetition(), "Seven");
+        assert_eq!(dodecad.potentiality(), "Eight");
+        assert_eq!(dodecad.subsistence(), "Nine");
+        assert_eq!(dodecad.relatedness(), "Ten");
+        assert_eq!(dodecad.polarity(), "Eleven");
+        assert_eq!(dodecad.wholeness(), "Twelve");
+
+        // Should have default connectives
+        assert!(dodecad.has_connectives());
+        assert_eq!(dodecad.connective_label('A', 'B').unwrap(), "Sovereignty");
+    }
+
+    #[test]
+    fn test_canonical_terms() {
+        let terms = Dodecad::get_canonical_terms();
+        assert_eq!(terms, vec![
+            "Autocracy",
+            "Domination",
+            "Creativity",
+            "Pattern",
+            "Individuality",
+            "Structure",
+            "Repetition",
+            "Potentiality",
+            "Subsistence",
+            "Relatedness",
+            "Polarity",
+            "Wholeness",
+        ]);
+    }
+
+    #[test]
+    fn test_get_instances() {
+        let instances = sample().get_instances();
+        assert_eq!(instances, vec!["One", "Two", "Three", "Four", "Five", "Six", "Seven", "Eight", "Nine", "Ten", "Eleven", "Twelve"]);
+    }
+
+    #[test]
+    fn test_has_connectives_with_some() {
+        let mut dodecad = Dodecad::new("Test", "A", "B", "C", "D", "E", "F", "G", "H", "I", "J", "K", "L");
+        dodecad.set_connective_label('A', 'B', Some("test connection".to_string())).unwrap();
+
+        assert!(dodecad.has_connectives());
+    }
+
+    #[test]
+    fn test_reset_connective_label() {
+        let mut dodecad = sample();
+        dodecad.set_connective_label('A', 'B', Some("custom connection".to_string())).unwrap();
+        dodecad.set_connective_label('A', 'B', None).unwrap();
+
+        // Clearing the user label restores the canonical name
+        assert!(dodecad.has_connectives());
+        assert_eq!(dodecad.connective_label('A', 'B'), Some("Sovereignty"));
+        assert!(!dodecad.connective('A', 'B').unwrap().is_relabelled());
+    }
+
+    #[test]
+    fn test_custom_connectives() {
+        let mut dodecad = sample();
+
+        // Modify one connective while others keep defaults
+        dodecad.set_connective_label('A', 'L', Some("custom connection".to_string())).unwrap();
+        assert_eq!(dodecad.connective_label('L', 'A').unwrap(), "custom connection");
+        assert_eq!(dodecad.connective_label('B', 'C').unwrap(), "Conquest");
+    }
+
+    #[test]
+    fn test_all_connectives_count() {
+        let dodecad = sample();
+
+        // Should have exactly 66 connectives (12 choose 2 = 66)
+        assert_eq!(dodecad.connectives().len(), 66);
+    }
+
+    #[test]
+    fn test_try_from_instance() {
+        let dodecad = sample();
+        let system = dodecad.clone().into_instance();
+
+        assert_eq!(system.order(), 12);
+        assert_eq!(Dodecad::try_from(system).unwrap(), dodecad);
+    }
+
+    #[test]
+    fn test_term_attribute_description() {
+        assert_eq!(Dodecad::TERM_ATTRIBUTE_DESCRIPTION, "Totality");
+        assert_eq!(sample().attribute_description(), "Totality");
+    }
+
+    #[test]
+    fn test_canonical_defaults_behavior() {
+        // Pressing Enter at every prompt leaves the canonical terms as instances
+        let dodecad = SystemInstance::new(&TEMPLATE, "Unnamed Dodecad");
+        let dodecad = Dodecad::try_from(dodecad).unwrap();
+
+        assert_eq!(dodecad.autocracy(), "Autocracy");
+        assert_eq!(dodecad.wholeness(), "Wholeness");
+        assert_eq!(dodecad.get_instances(), Dodecad::get_canonical_terms());
+    }
+}
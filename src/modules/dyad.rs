@@ -1,6 +1,9 @@
-use std::io::{self, Write};
+use crate::modules::connective::Connective;
+use crate::modules::error::SystematicsError;
+use crate::modules::prompter::{IoPrompter, Prompter};
+use crate::modules::system::System;
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Dyad {
     pub name: String,
     pub essence: String,
@@ -19,125 +22,25 @@ impl Dyad {
         }
     }
     
-    /// Interactive creation method - handles all input/output internally
-    pub fn create_interactive() -> Result<Self, Box<dyn std::error::Error>> {
-        println!("\n--- Creating a Dyad ---");
-        
-        // Helper for required input - loops until valid input is provided
-        let get_required_input = |prompt: &str, field_name: &str| -> Result<String, Box<dyn std::error::Error>> {
-            loop {
-                let mut input = String::new();
-                print!("{}", prompt);
-                
-                if let Err(e) = io::stdout().flush() {
-                    return Err(format!("Could not flush output: {}", e).into());
-                }
-                
-                match io::stdin().read_line(&mut input) {
-                    Ok(_) => {
-                        let trimmed = input.trim();
-                        
-                        // Check for empty input - prompt again
-                        if trimmed.is_empty() {
-                            println!("{} is required. Please enter a value.", field_name);
-                            continue;
-                        }
-                        
-                        // Check for reasonable length (1-100 characters)
-                        if trimmed.len() > 100 {
-                            println!("{} is too long (max 100 characters). Please try again.", field_name);
-                            continue;
-                        }
-                        
-                        // Check for valid characters (letters, numbers, spaces, basic punctuation)
-                        if !trimmed.chars().all(|c| c.is_alphanumeric() || c.is_whitespace() || ".,!?'-()".contains(c)) {
-                            println!("{} contains invalid characters. Please use only letters, numbers, spaces, and basic punctuation.", field_name);
-                            continue;
-                        }
-                        
-                        return Ok(trimmed.to_string());
-                    }
-                    Err(e) => return Err(format!("Error reading {}: {}", field_name, e).into())
-                }
-            }
-        };
-        
-        // Helper for optional input with default
-        let get_optional_input = |prompt: &str, default: &str| -> Result<String, Box<dyn std::error::Error>> {
-            let mut input = String::new();
-            print!("{}", prompt);
-            
-            if let Err(e) = io::stdout().flush() {
-                eprintln!("Warning: Could not flush output: {}", e);
-            }
-            
-            match io::stdin().read_line(&mut input) {
-                Ok(_) => {
-                    let trimmed = input.trim();
-                    
-                    if trimmed.is_empty() {
-                        Ok(default.to_string())
-                    } else {
-                        // Validate optional input if provided
-                        if trimmed.len() > 100 {
-                            return Err("Input is too long (max 100 characters)".into());
-                        }
-                        
-                        if !trimmed.chars().all(|c| c.is_alphanumeric() || c.is_whitespace() || ".,!?'-()".contains(c)) {
-                            return Err("Input contains invalid characters".into());
-                        }
-                        
-                        Ok(trimmed.to_string())
-                    }
-                }
-                Err(e) => {
-                    eprintln!("Error reading input: {}. Using default: {}", e, default);
-                    Ok(default.to_string())
-                }
-            }
-        };
-        
-        // Helper for yes/no input
-        let _get_yes_no_input = |prompt: &str, default: &str| -> Result<String, Box<dyn std::error::Error>> {
-            loop {
-                let mut input = String::new();
-                print!("{}", prompt);
-                
-                if let Err(e) = io::stdout().flush() {
-                    eprintln!("Warning: Could not flush output: {}", e);
-                }
-                
-                match io::stdin().read_line(&mut input) {
-                    Ok(_) => {
-                        let trimmed = input.trim().to_lowercase();
-                        
-                        if trimmed.is_empty() {
-                            return Ok(default.to_string());
-                        }
-                        
-                        match trimmed.as_str() {
-                            "y" | "yes" | "n" | "no" => return Ok(trimmed),
-                            _ => {
-                                println!("Please enter 'y' or 'n' (or press Enter for default: {})", default);
-                                continue;
-                            }
-                        }
-                    }
-                    Err(e) => return Err(format!("Error reading input: {}", e).into())
-                }
-            }
-        };
-        
+    /// Interactive creation method on the terminal
+    pub fn create_interactive() -> Result<Self, SystematicsError> {
+        Self::create_with(&mut IoPrompter::stdio())
+    }
+
+    /// Interactive creation through any prompter
+    pub fn create_with(prompter: &mut dyn Prompter) -> Result<Self, SystematicsError> {
+        prompter.say("\n--- Creating a Dyad ---")?;
+
         // Get required inputs
-        let name = get_optional_input("Enter a name for your Dyad (or press Enter for 'Unnamed Dyad'): ", "Unnamed Dyad")?;
-        let essence = get_required_input("Enter the Essence instance: ", "Essence instance")?;
-        let existence = get_required_input("Enter the Existence instance: ", "Existence instance")?;
+        let name = prompter.optional("Enter a name for your Dyad (or press Enter for 'Unnamed Dyad'): ", "Unnamed Dyad")?;
+        let essence = prompter.required("Enter the Essence instance: ", "Essence instance")?;
+        let existence = prompter.required("Enter the Existence instance: ", "Existence instance")?;
 
         let dyad = Dyad::new(&name, &essence, &existence);
-        
+
         // Display the created dyad
-        dyad.display();
-        
+        prompter.say(&dyad.details())?;
+
         Ok(dyad)
     }
     
@@ -159,20 +62,61 @@ impl Dyad {
         vec![self.essence.clone(), self.existence.clone()]
     }
     
+    /// Formatted details of the dyad
+    pub fn details(&self) -> String {
+        format!(
+            "\n--- Dyad Details ---\nDyad Name: {}\nCore Attribute: {}\nEssence: {}\nExistence: {}\n---------------------",
+            self.name,
+            Self::TERM_ATTRIBUTE_DESCRIPTION,
+            self.essence,
+            self.existence
+        )
+    }
+
     /// Display dyad details
     pub fn display(&self) {
-        println!("\n--- Dyad Details ---");
-        println!("Dyad Name: {}", self.name);
-        println!("Core Attribute: {}", Self::TERM_ATTRIBUTE_DESCRIPTION);
-        println!("Essence: {}", self.essence);
-        println!("Existence: {}", self.existence);
-        println!("---------------------");
+        println!("{}", self.details());
+    }
+}
+
+impl System for Dyad {
+    fn kind(&self) -> &'static str {
+        "Dyad"
+    }
+
+    fn order(&self) -> usize {
+        2
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn canonical_terms(&self) -> Vec<&'static str> {
+        Self::get_canonical_terms()
+    }
+
+    fn instances(&self) -> Vec<String> {
+        self.get_instances()
+    }
+
+    fn connectives(&self) -> &[Connective] {
+        &[]
+    }
+
+    fn attribute_description(&self) -> &'static str {
+        Self::TERM_ATTRIBUTE_DESCRIPTION
+    }
+
+    fn display(&self) {
+        Dyad::display(self)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::modules::prompter::ScriptedPrompter;
 
     #[test]
     fn test_dyad_creation() {
@@ -355,4 +299,21 @@ mod tests {
         let description = Dyad::TERM_ATTRIBUTE_DESCRIPTION;
         assert!(description.contains("polarity") || description.contains("force") || description.contains("Complementarity"));
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_create_with_scripted_session() {
+        let mut prompter = ScriptedPrompter::new(&["", "", "Being", "Doing"]);
+        let dyad = Dyad::create_with(&mut prompter).unwrap();
+
+        assert_eq!(dyad, Dyad::new("Unnamed Dyad", "Being", "Doing"));
+        assert!(prompter.transcript().contains("Essence instance is required. Please enter a value."));
+    }
+
+    #[test]
+    fn test_create_with_ends_without_required_input() {
+        let mut prompter = ScriptedPrompter::new(&["Pair", "Being"]);
+        let error = Dyad::create_with(&mut prompter).unwrap_err();
+
+        assert_eq!(error.to_string(), "Input ended before Existence instance was given");
+    }
+}
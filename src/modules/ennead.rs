@@ -0,0 +1,511 @@
+use std::ops::{Deref, DerefMut};
+
+use crate::modules::connective::Connective;
+use crate::modules::error::SystematicsError;
+use crate::modules::instance::SystemInstance;
+use crate::modules::prompter::{IoPrompter, Prompter};
+use crate::modules::system::System;
+use crate::modules::template::{ConnectiveTemplate, SystemTemplate, TermTemplate};
+
+/// Canonical Ennead positions and connectives.
+///
+/// Positions A to I are the enneagram points 1 to 9, and the descriptions of
+/// connectives on a figure (circle, inner cycle, triangle) say which line they are.
+/// The term labels follow the stages of a process around the enneagram; they are
+/// provisional working labels, not taken from Bennett, and may change.
+pub static TEMPLATE: SystemTemplate = SystemTemplate {
+    kind: "Ennead",
+    order: 9,
+    attribute_description: Ennead::TERM_ATTRIBUTE_DESCRIPTION,
+    terms: &[
+        TermTemplate { position: 'A', label: "Initiation" },
+        TermTemplate { position: 'B', label: "Engagement" },
+        TermTemplate { position: 'C', label: "First Shock" },
+        TermTemplate { position: 'D', label: "Development" },
+        TermTemplate { position: 'E', label: "Transformation" },
+        TermTemplate { position: 'F', label: "Second Shock" },
+        TermTemplate { position: 'G', label: "Realisation" },
+        TermTemplate { position: 'H', label: "Fulfilment" },
+        TermTemplate { position: 'I', label: "Completion" },
+    ],
+    connectives: &[
+        ConnectiveTemplate { from: 'A', to: 'B', name: "Entry", description: "Step around the circle from Initiation to Engagement" },
+        ConnectiveTemplate { from: 'A', to: 'C', name: "Impetus", description: "The first effort meeting the first shock" },
+        ConnectiveTemplate { from: 'A', to: 'D', name: "Planning", description: "Inner-cycle line between Initiation and Development" },
+        ConnectiveTemplate { from: 'A', to: 'E', name: "Catalyst", description: "What was begun undergoing transformation" },
+        ConnectiveTemplate { from: 'A', to: 'F', name: "Recollection", description: "The original impulse recalled at the second shock" },
+        ConnectiveTemplate { from: 'A', to: 'G', name: "Return", description: "Inner-cycle line between Initiation and Realisation" },
+        ConnectiveTemplate { from: 'A', to: 'H', name: "Intent", description: "The opening aim measured against its fulfilment" },
+        ConnectiveTemplate { from: 'A', to: 'I', name: "Recurrence", description: "Step around the circle from Completion back to Initiation" },
+        ConnectiveTemplate { from: 'B', to: 'C', name: "Effort", description: "Step around the circle from Engagement to First Shock" },
+        ConnectiveTemplate { from: 'B', to: 'D', name: "Practice", description: "Inner-cycle line between Engagement and Development" },
+        ConnectiveTemplate { from: 'B', to: 'E', name: "Immersion", description: "Engagement deepening into transformation" },
+        ConnectiveTemplate { from: 'B', to: 'F', name: "Commitment", description: "Engagement tested at the second shock" },
+        ConnectiveTemplate { from: 'B', to: 'G', name: "Investment", description: "Engagement paying off in realisation" },
+        ConnectiveTemplate { from: 'B', to: 'H', name: "Perseverance", description: "Inner-cycle line between Engagement and Fulfilment" },
+        ConnectiveTemplate { from: 'B', to: 'I', name: "Dedication", description: "Engagement carried through to completion" },
+        ConnectiveTemplate { from: 'C', to: 'D', name: "Adjustment", description: "Step around the circle from First Shock to Development" },
+        ConnectiveTemplate { from: 'C', to: 'E', name: "Breakthrough", description: "The first shock opening the way to transformation" },
+        ConnectiveTemplate { from: 'C', to: 'F', name: "Intervention", description: "Triangle line joining the shock points First Shock and Second Shock" },
+        ConnectiveTemplate { from: 'C', to: 'G', name: "Insight", description: "What the first shock taught, realised later" },
+        ConnectiveTemplate { from: 'C', to: 'H', name: "Lesson", description: "The first shock bearing fruit in fulfilment" },
+        ConnectiveTemplate { from: 'C', to: 'I', name: "Impulse", description: "Triangle line joining the shock points First Shock and Completion" },
+        ConnectiveTemplate { from: 'D', to: 'E', name: "Deepening", description: "Step around the circle from Development to Transformation" },
+        ConnectiveTemplate { from: 'D', to: 'F', name: "Maturity", description: "Development ready for the second shock" },
+        ConnectiveTemplate { from: 'D', to: 'G', name: "Progress", description: "Development arriving at realisation" },
+        ConnectiveTemplate { from: 'D', to: 'H', name: "Cultivation", description: "Development ripening into fulfilment" },
+        ConnectiveTemplate { from: 'D', to: 'I', name: "Growth", description: "Development reaching completion" },
+        ConnectiveTemplate { from: 'E', to: 'F', name: "Crisis", description: "Step around the circle from Transformation to Second Shock" },
+        ConnectiveTemplate { from: 'E', to: 'G', name: "Emergence", description: "Inner-cycle line between Transformation and Realisation" },
+        ConnectiveTemplate { from: 'E', to: 'H', name: "Refinement", description: "Inner-cycle line between Transformation and Fulfilment" },
+        ConnectiveTemplate { from: 'E', to: 'I', name: "Metamorphosis", description: "Transformation made complete" },
+        ConnectiveTemplate { from: 'F', to: 'G', name: "Turning", description: "Step around the circle from Second Shock to Realisation" },
+        ConnectiveTemplate { from: 'F', to: 'H', name: "Release", description: "The second shock freeing fulfilment" },
+        ConnectiveTemplate { from: 'F', to: 'I', name: "Resolution", description: "Triangle line joining the shock points Second Shock and Completion" },
+        ConnectiveTemplate { from: 'G', to: 'H', name: "Ripening", description: "Step around the circle from Realisation to Fulfilment" },
+        ConnectiveTemplate { from: 'G', to: 'I', name: "Consummation", description: "Realisation brought to completion" },
+        ConnectiveTemplate { from: 'H', to: 'I', name: "Closure", description: "Step around the circle from Fulfilment to Completion" },
+    ],
+};
+
+/// A closed path through enneagram points, drawn as its own figure
+#[derive(Debug, PartialEq)]
+pub struct EnneagramFigure {
+    pub name: &'static str,
+    pub points: &'static [u8],
+}
+
+/// The nine points in sequence around the circle
+pub static CIRCLE: EnneagramFigure = EnneagramFigure {
+    name: "Circle",
+    points: &[1, 2, 3, 4, 5, 6, 7, 8, 9],
+};
+
+/// The inner recurring cycle 1-4-2-8-5-7
+pub static INNER_CYCLE: EnneagramFigure = EnneagramFigure {
+    name: "Inner cycle",
+    points: &[1, 4, 2, 8, 5, 7],
+};
+
+/// The triangle of shock points 3-6-9
+pub static TRIANGLE: EnneagramFigure = EnneagramFigure {
+    name: "Triangle",
+    points: &[3, 6, 9],
+};
+
+impl EnneagramFigure {
+    /// Position letters of the figure's points, in figure order
+    pub fn positions(&self) -> Vec<char> {
+        self.points.iter().filter_map(|&point| Ennead::position_for_point(point)).collect()
+    }
+
+    /// Directed edges of the closed figure, as (from, to) position letters
+    pub fn edges(&self) -> Vec<(char, char)> {
+        let positions = self.positions();
+        positions
+            .iter()
+            .zip(positions.iter().cycle().skip(1))
+            .map(|(&from, &to)| (from, to))
+            .collect()
+    }
+
+    /// Whether the figure joins two positions directly, in either direction
+    pub fn contains_edge(&self, a: char, b: char) -> bool {
+        self.edges().iter().any(|&(from, to)| (from == a && to == b) || (from == b && to == a))
+    }
+}
+
+/// Typed view over a 9-term `SystemInstance` built from the Ennead template
+#[derive(Debug, Clone, PartialEq)]
+pub struct Ennead {
+    system: SystemInstance,
+}
+
+impl Ennead {
+    pub const TERM_ATTRIBUTE_DESCRIPTION: &'static str = "Pattern";
+
+    /// Creates a new Ennead with user instances for enneagram points 1 to 9
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        name: &str,
+        initiation: &str,
+        engagement: &str,
+        first_shock: &str,
+        development: &str,
+        transformation: &str,
+        second_shock: &str,
+        realisation: &str,
+        fulfilment: &str,
+        completion: &str,
+    ) -> Self {
+        let instances = [
+            initiation,
+            engagement,
+            first_shock,
+            development,
+            transformation,
+            second_shock,
+            realisation,
+            fulfilment,
+            completion,
+        ];
+        let system = SystemInstance::with_instances(&TEMPLATE, name, &instances)
+            .expect("Ennead template has nine positions");
+        Ennead { system }
+    }
+
+    /// Interactive creation method on the terminal
+    pub fn create_interactive() -> Result<Self, SystematicsError> {
+        Self::create_with(&mut IoPrompter::stdio())
+    }
+
+    /// Interactive creation through any prompter
+    pub fn create_with(prompter: &mut dyn Prompter) -> Result<Self, SystematicsError> {
+        let ennead = SystemInstance::create_with(&TEMPLATE, prompter).map(|system| Ennead { system })?;
+        prompter.say(&ennead.figures_details())?;
+        Ok(ennead)
+    }
+
+    /// Position letter for an enneagram point (1 -> 'A', ..., 9 -> 'I'), or None
+    /// outside 1 to 9
+    pub fn position_for_point(point: u8) -> Option<char> {
+        (1..=9).contains(&point).then(|| (b'A' + point - 1) as char)
+    }
+
+    /// User instance at an enneagram point (1 to 9), or None outside that range
+    pub fn point(&self, point: u8) -> Option<&str> {
+        (1..=9).contains(&point).then(|| self.system.instance_at(usize::from(point) - 1))
+    }
+
+    /// Initiation instance (point 1)
+    pub fn initiation(&self) -> &str {
+        self.system.instance_at(0)
+    }
+
+    /// Engagement instance (point 2)
+    pub fn engagement(&self) -> &str {
+        self.system.instance_at(1)
+    }
+
+    /// First Shock instance (point 3)
+    pub fn first_shock(&self) -> &str {
+        self.system.instance_at(2)
+    }
+
+    /// Development instance (point 4)
+    pub fn development(&self) -> &str {
+        self.system.instance_at(3)
+    }
+
+    /// Transformation instance (point 5)
+    pub fn transformation(&self) -> &str {
+        self.system.instance_at(4)
+    }
+
+    /// Second Shock instance (point 6)
+    pub fn second_shock(&self) -> &str {
+        self.system.instance_at(5)
+    }
+
+    /// Realisation instance (point 7)
+    pub fn realisation(&self) -> &str {
+        self.system.instance_at(6)
+    }
+
+    /// Fulfilment instance (point 8)
+    pub fn fulfilment(&self) -> &str {
+        self.system.instance_at(7)
+    }
+
+    /// Completion instance (point 9)
+    pub fn completion(&self) -> &str {
+        self.system.instance_at(8)
+    }
+
+    /// User instances along a figure, in figure order
+    pub fn figure_instances(&self, figure: &EnneagramFigure) -> Vec<&str> {
+        figure.points.iter().filter_map(|&point| self.point(point)).collect()
+    }
+
+    /// User instances along the inner cycle 1-4-2-8-5-7
+    pub fn inner_cycle(&self) -> Vec<&str> {
+        self.figure_instances(&INNER_CYCLE)
+    }
+
+    /// User instances on the triangle 3-6-9
+    pub fn triangle(&self) -> Vec<&str> {
+        self.figure_instances(&TRIANGLE)
+    }
+
+    /// Get canonical term names
+    pub fn get_canonical_terms() -> Vec<&'static str> {
+        TEMPLATE.canonical_terms()
+    }
+
+    /// Get all user instances
+    pub fn get_instances(&self) -> Vec<String> {
+        self.system.instances().to_vec()
+    }
+
+    /// Underlying data-driven system
+    pub fn into_instance(self) -> SystemInstance {
+        self.system
+    }
+
+    /// Formatted inner cycle and triangle, traced through the user's instances
+    pub fn figures_details(&self) -> String {
+        let mut text = String::from("\nEnneagram Figures:");
+        for figure in [&INNER_CYCLE, &TRIANGLE] {
+            let points: Vec<String> = figure.points.iter().map(|point| point.to_string()).collect();
+            let mut path = self.figure_instances(figure);
+            path.push(path[0]);
+            text.push_str(&format!("\n  {} ({}): {}", figure.name, points.join("-"), path.join(" → ")));
+        }
+        text
+    }
+
+    /// Display the inner cycle and triangle
+    pub fn display_figures(&self) {
+        println!("{}", self.figures_details());
+    }
+}
+
+impl Deref for Ennead {
+    type Target = SystemInstance;
+
+    fn deref(&self) -> &SystemInstance {
+        &self.system
+    }
+}
+
+impl DerefMut for Ennead {
+    fn deref_mut(&mut self) -> &mut SystemInstance {
+        &mut self.system
+    }
+}
+
+impl TryFrom<SystemInstance> for Ennead {
+    type Error = SystematicsError;
+
+    fn try_from(system: SystemInstance) -> Result<Self, Self::Error> {
+        if !std::ptr::eq(system.template(), &TEMPLATE) {
+            return Err(SystematicsError::Structure(format!("Expected Ennead template, got {}", system.template().kind)));
+        }
+        Ok(Ennead { system })
+    }
+}
+
+impl System for Ennead {
+    fn kind(&self) -> &'static str {
+        self.system.template().kind
+    }
+
+    fn order(&self) -> usize {
+        self.system.order()
+    }
+
+    fn name(&self) -> &str {
+        &self.system.name
+    }
+
+    fn canonical_terms(&self) -> Vec<&'static str> {
+        Self::get_canonical_terms()
+    }
+
+    fn instances(&self) -> Vec<String> {
+        self.get_instances()
+    }
+
+    fn connectives(&self) -> &[Connective] {
+        self.system.connectives()
+    }
+
+    fn attribute_description(&self) -> &'static str {
+        Self::TERM_ATTRIBUTE_DESCRIPTION
+    }
+
+    fn display(&self) {
+        self.system.display();
+        self.display_figures();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::prompter::ScriptedPrompter;
+
+    fn sample() -> Ennead {
+        Ennead::new("Test", "One", "Two", "Three", "Four", "Five", "Six", "Seven", "Eight", "Nine")
+    }
+
+    #[test]
+    fn test_ennead_creation() {
+        let ennead = sample();
+
+        assert_eq!(ennead.name, "Test");
+        assert_eq!(ennead.initiation(), "One");
+        assert_eq!(ennead.engagement(), "Two");
+        assert_eq!(ennead.first_shock(), "Three");
+        assert_eq!(ennead.development(), "Four");
+        assert_eq!(ennead.transformation(), "Five");
+        assert_eq!(ennead.second_shock(), "Six");
+        assert_eq!(ennead.realisation(), "Seven");
+        assert_eq!(ennead.fulfilment(), "Eight");
+        assert_eq!(ennead.completion(), "Nine");
+
+        // Should have default connectives
+        assert!(ennead.has_connectives());
+        assert_eq!(ennead.connective_label('A', 'B').unwrap(), "Entry");
+    }
+
+    #[test]
+    fn test_canonical_terms() {
+        let terms = Ennead::get_canonical_terms();
+        assert_eq!(terms, vec![
+            "Initiation",
+            "Engagement",
+            "First Shock",
+            "Development",
+            "Transformation",
+            "Second Shock",
+            "Realisation",
+            "Fulfilment",
+            "Completion",
+        ]);
+    }
+
+    #[test]
+    fn test_get_instances() {
+        let instances = sample().get_instances();
+        assert_eq!(instances, vec!["One", "Two", "Three", "Four", "Five", "Six", "Seven", "Eight", "Nine"]);
+    }
+
+    #[test]
+    fn test_all_connectives_count() {
+        let ennead = sample();
+
+        // Should have exactly 36 connectives (9 choose 2 = 36)
+        assert_eq!(ennead.connectives().len(), 36);
+        assert!(ennead.connectives().iter().all(|connective| !connective.is_relabelled()));
+    }
+
+    #[test]
+    fn test_reset_connective_label() {
+        let mut ennead = sample();
+        ennead.set_connective_label('A', 'B', Some("custom connection".to_string())).unwrap();
+        ennead.set_connective_label('A', 'B', None).unwrap();
+
+        // Clearing the user label restores the canonical name
+        assert!(ennead.has_connectives());
+        assert_eq!(ennead.connective_label('A', 'B'), Some("Entry"));
+        assert!(!ennead.connective('A', 'B').unwrap().is_relabelled());
+    }
+
+    #[test]
+    fn test_custom_connectives() {
+        let mut ennead = sample();
+
+        ennead.set_connective_label('A', 'D', Some("custom connection".to_string())).unwrap();
+        assert_eq!(ennead.connective_label('A', 'D').unwrap(), "custom connection");
+
+        // Other connectives should still have defaults
+        assert_eq!(ennead.connective_label('C', 'F').unwrap(), "Intervention");
+        assert_eq!(ennead.connective_label('A', 'I').unwrap(), "Recurrence");
+    }
+
+    #[test]
+    fn test_position_for_point() {
+        assert_eq!(Ennead::position_for_point(1), Some('A'));
+        assert_eq!(Ennead::position_for_point(9), Some('I'));
+        assert_eq!(Ennead::position_for_point(0), None);
+        assert_eq!(Ennead::position_for_point(10), None);
+    }
+
+    #[test]
+    fn test_point_out_of_range() {
+        let ennead = sample();
+        assert_eq!(ennead.point(1), Some(ennead.initiation()));
+        assert_eq!(ennead.point(0), None);
+        assert_eq!(ennead.point(10), None);
+    }
+
+    #[test]
+    fn test_inner_cycle() {
+        let ennead = sample();
+
+        assert_eq!(INNER_CYCLE.positions(), vec!['A', 'D', 'B', 'H', 'E', 'G']);
+        assert_eq!(ennead.inner_cycle(), vec!["One", "Four", "Two", "Eight", "Five", "Seven"]);
+
+        // The cycle closes from 7 back to 1
+        let edges = INNER_CYCLE.edges();
+        assert_eq!(edges.len(), 6);
+        assert_eq!(edges[0], ('A', 'D'));
+        assert_eq!(edges[5], ('G', 'A'));
+    }
+
+    #[test]
+    fn test_triangle() {
+        let ennead = sample();
+
+        assert_eq!(TRIANGLE.positions(), vec!['C', 'F', 'I']);
+        assert_eq!(ennead.triangle(), vec!["Three", "Six", "Nine"]);
+        assert_eq!(TRIANGLE.edges(), vec![('C', 'F'), ('F', 'I'), ('I', 'C')]);
+    }
+
+    #[test]
+    fn test_figures_are_distinct() {
+        // The inner cycle never touches the shock points, and no figure shares an edge
+        for point in TRIANGLE.points {
+            assert!(!INNER_CYCLE.points.contains(point));
+        }
+        for (from, to) in INNER_CYCLE.edges() {
+            assert!(!CIRCLE.contains_edge(from, to));
+            assert!(!TRIANGLE.contains_edge(from, to));
+        }
+        for (from, to) in TRIANGLE.edges() {
+            assert!(!CIRCLE.contains_edge(from, to));
+        }
+    }
+
+    #[test]
+    fn test_connective_descriptions_follow_figures() {
+        let description = |(from, to): (char, char)| {
+            TEMPLATE
+                .connectives
+                .iter()
+                .find(|connective| (connective.from, connective.to) == (from, to) || (connective.from, connective.to) == (to, from))
+                .unwrap()
+                .description
+        };
+
+        for edge in INNER_CYCLE.edges() {
+            assert!(description(edge).starts_with("Inner-cycle line"));
+        }
+        for edge in TRIANGLE.edges() {
+            assert!(description(edge).starts_with("Triangle line"));
+        }
+        for edge in CIRCLE.edges() {
+            assert!(description(edge).starts_with("Step around the circle"));
+        }
+        assert_eq!(sample().connective_label('A', 'C').unwrap(), "Impetus");
+    }
+
+    #[test]
+    fn test_try_from_instance() {
+        let ennead = sample();
+        let system = ennead.clone().into_instance();
+
+        assert_eq!(system.order(), 9);
+        assert_eq!(Ennead::try_from(system).unwrap(), ennead);
+    }
+
+    #[test]
+    fn test_create_with_shows_figures() {
+        let mut prompter = ScriptedPrompter::new(&["Year", "", "", "", "", "", "", "", "", "", "n"]);
+        let ennead = Ennead::create_with(&mut prompter).unwrap();
+
+        assert_eq!(ennead.name, "Year");
+        assert!(prompter.transcript().contains("Triangle (3-6-9): First Shock → Second Shock → Completion → First Shock"));
+    }
+}
@@ -0,0 +1,131 @@
+use std::fmt;
+use std::io;
+
+use crate::modules::validation::ValidationError;
+
+/// Everything that can go wrong while creating, reading or storing a system.
+///
+/// Each variant is a distinct kind of failure, so callers can react to a
+/// cancelled prompt differently from a corrupt file or a rejected value.
+#[derive(Debug)]
+pub enum SystematicsError {
+    /// Reading from or writing to a terminal or file failed
+    Io(io::Error),
+    /// A value was rejected by the validation policy
+    Validation { field: String, reason: ValidationError },
+    /// No system has this number of terms
+    UnknownOrder(usize),
+    /// No system kind has this name
+    UnknownKind(String),
+    /// Text could not be read as a system document (syntax, schema version)
+    Parse(String),
+    /// A hand-written system file is wrong at a known place (unknown or missing key, bad value)
+    Syntax { line: usize, column: usize, message: String },
+    /// A system's terms or connectives don't fit its kind
+    Structure(String),
+    /// The store holds no system with this id
+    NotFound(u64),
+    /// The store's files could not be used (corrupt record or counter)
+    Storage(String),
+    /// Nesting systems would make one contain itself; the ids go round the loop
+    Cycle(Vec<u64>),
+    /// Input ended before a required value was given
+    Cancelled { field: String },
+}
+
+impl SystematicsError {
+    pub fn validation(field: &str, reason: ValidationError) -> Self {
+        SystematicsError::Validation {
+            field: field.to_string(),
+            reason,
+        }
+    }
+}
+
+impl fmt::Display for SystematicsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SystematicsError::Io(error) => write!(f, "{}", error),
+            SystematicsError::Validation { field, reason } => write!(f, "{} {}", field, reason),
+            SystematicsError::UnknownOrder(order) => write!(f, "No system has {} terms (1 to 12)", order),
+            SystematicsError::UnknownKind(kind) => write!(f, "Unknown system kind '{}'", kind),
+            SystematicsError::Parse(message) | SystematicsError::Structure(message) | SystematicsError::Storage(message) => {
+                write!(f, "{}", message)
+            }
+            SystematicsError::Syntax { line, column, message } => {
+                write!(f, "Line {}, column {}: {}", line, column, message)
+            }
+            SystematicsError::NotFound(id) => write!(f, "No system with id {}", id),
+            SystematicsError::Cycle(ids) => {
+                let ids: Vec<String> = ids.iter().map(u64::to_string).collect();
+                write!(f, "Nesting would form a cycle: {}", ids.join(" → "))
+            }
+            SystematicsError::Cancelled { field } => write!(f, "Input ended before {} was given", field),
+        }
+    }
+}
+
+impl std::error::Error for SystematicsError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            SystematicsError::Io(error) => Some(error),
+            SystematicsError::Validation { reason, .. } => Some(reason),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for SystematicsError {
+    fn from(error: io::Error) -> Self {
+        SystematicsError::Io(error)
+    }
+}
+
+/// JSON errors while reading are parse errors; while writing they can only come from I/O
+impl From<serde_json::Error> for SystematicsError {
+    fn from(error: serde_json::Error) -> Self {
+        if error.is_io() {
+            SystematicsError::Io(error.into())
+        } else {
+            SystematicsError::Parse(error.to_string())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::error::Error;
+
+    #[test]
+    fn test_messages() {
+        let too_long = ValidationError::TooLong { max: 100, length: 101 };
+        assert_eq!(
+            SystematicsError::validation("Name", too_long).to_string(),
+            "Name is too long (max 100 characters)"
+        );
+        assert_eq!(SystematicsError::UnknownOrder(13).to_string(), "No system has 13 terms (1 to 12)");
+        assert_eq!(SystematicsError::NotFound(4).to_string(), "No system with id 4");
+        assert_eq!(SystematicsError::Cycle(vec![1, 2, 1]).to_string(), "Nesting would form a cycle: 1 → 2 → 1");
+        let syntax = SystematicsError::Syntax {
+            line: 3,
+            column: 1,
+            message: "unknown term 'grond'".to_string(),
+        };
+        assert_eq!(syntax.to_string(), "Line 3, column 1: unknown term 'grond'");
+        assert_eq!(
+            SystematicsError::Cancelled { field: "Essence".to_string() }.to_string(),
+            "Input ended before Essence was given"
+        );
+    }
+
+    #[test]
+    fn test_conversions_keep_their_kind() {
+        let error = SystematicsError::from(io::Error::new(io::ErrorKind::NotFound, "gone"));
+        assert!(matches!(error, SystematicsError::Io(_)));
+        assert!(error.source().is_some());
+
+        let error = SystematicsError::from(serde_json::from_str::<u32>("nope").unwrap_err());
+        assert!(matches!(error, SystematicsError::Parse(_)));
+    }
+}
@@ -0,0 +1,213 @@
+use crate::modules::connective::{Connective, Direction};
+use crate::modules::document::{SystemDocument, TermDocument};
+use crate::modules::hierarchy::SystemTree;
+
+/// Choices for a Graphviz DOT export
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DotOptions {
+    /// Lays terms out on a circle (Graphviz `circo`) instead of top to bottom
+    pub circle: bool,
+    /// Leaves connectives unlabelled while they still carry their canonical name
+    pub hide_default_labels: bool,
+    /// Draws nested systems as clusters joined to the term they elaborate (trees only)
+    pub nested: bool,
+}
+
+impl SystemDocument {
+    /// Graphviz DOT source for this system.
+    ///
+    /// Each term is a node labelled with its position, canonical term and instance;
+    /// each connective is an edge labelled with its user label or canonical name.
+    pub fn to_dot(&self, options: &DotOptions) -> String {
+        let mut dot = header(&self.name, &format!("{}: {}", self.kind, self.name), options);
+        write_system(&mut dot, self, "", "  ", options);
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+impl SystemTree {
+    /// Graphviz DOT source for the root system; with `options.nested`, every system
+    /// below it is drawn as a cluster linked to the term it elaborates.
+    pub fn to_dot(&self, options: &DotOptions) -> String {
+        if !options.nested {
+            return self.system.to_dot(options);
+        }
+
+        let mut dot = header(&self.system.name, &format!("{} {}: {}", self.system.kind, self.id, self.system.name), options);
+        dot.push_str("  compound=true;\n");
+        write_system(&mut dot, &self.system, "s0_", "  ", options);
+        let mut next = 1;
+        write_clusters(&mut dot, self, "s0_", &mut next, options);
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+fn header(name: &str, label: &str, options: &DotOptions) -> String {
+    let mut dot = format!("graph {} {{\n  label={};\n  labelloc=t;\n", quote(name), quote(label));
+    if options.circle {
+        dot.push_str("  layout=circo;\n");
+    }
+    dot.push_str("  node [shape=box, style=rounded];\n");
+    dot
+}
+
+/// Writes every nested system as a cluster, numbering them in walk order so a system
+/// shared by two parents gets its own nodes each time it appears
+fn write_clusters(dot: &mut String, tree: &SystemTree, prefix: &str, next: &mut usize, options: &DotOptions) {
+    for nested in &tree.children {
+        let child = &nested.tree;
+        let child_prefix = format!("s{}_", next);
+        let cluster = format!("cluster_{}", next);
+        *next += 1;
+
+        dot.push_str(&format!("  subgraph {} {{\n", cluster));
+        let label = format!("{} {}: {}", child.system.kind, child.id, child.system.name);
+        dot.push_str(&format!("    label={};\n", quote(&label)));
+        write_system(dot, &child.system, &child_prefix, "    ", options);
+        dot.push_str("  }\n");
+
+        // A dashed edge from the parent term to the cluster that elaborates it
+        if let Some(first) = child.system.terms.first() {
+            dot.push_str(&format!(
+                "  {}{} -- {}{} [style=dashed, lhead={}];\n",
+                prefix, nested.position, child_prefix, first.position, cluster
+            ));
+        }
+        write_clusters(dot, child, &child_prefix, next, options);
+    }
+}
+
+fn write_system(dot: &mut String, system: &SystemDocument, prefix: &str, indent: &str, options: &DotOptions) {
+    for term in &system.terms {
+        dot.push_str(&format!("{}{}{} [label={}];\n", indent, prefix, term.position, quote(&term_label(term))));
+    }
+    for connective in &system.connectives {
+        dot.push_str(&format!(
+            "{}{}{} -- {}{}{};\n",
+            indent,
+            prefix,
+            connective.from,
+            prefix,
+            connective.to,
+            edge_attributes(connective, options)
+        ));
+    }
+}
+
+/// "A. Ground\nOffice", or just "A. Ground" while the instance is the canonical term
+fn term_label(term: &TermDocument) -> String {
+    match term.canonical.as_deref() {
+        Some(canonical) if canonical == term.instance => format!("{}. {}", term.position, canonical),
+        Some(canonical) => format!("{}. {}\n{}", term.position, canonical, term.instance),
+        None => format!("{}. {}", term.position, term.instance),
+    }
+}
+
+fn edge_attributes(connective: &Connective, options: &DotOptions) -> String {
+    let mut attributes = Vec::new();
+    if connective.is_relabelled() || !options.hide_default_labels {
+        attributes.push(format!("label={}", quote(connective.label())));
+    }
+    match connective.direction {
+        Direction::Bidirectional => {}
+        Direction::Forward => attributes.push("dir=forward".to_string()),
+        Direction::Backward => attributes.push("dir=back".to_string()),
+    }
+    if attributes.is_empty() {
+        String::new()
+    } else {
+        format!(" [{}]", attributes.join(", "))
+    }
+}
+
+/// A DOT string literal; line breaks become Graphviz's centred `\n`
+fn quote(text: &str) -> String {
+    let escaped = text.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n");
+    format!("\"{}\"", escaped)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::connective::Position;
+    use crate::modules::hierarchy::NestedSystem;
+    use crate::modules::octad::Octad;
+    use crate::modules::system::System;
+    use crate::modules::tetrad::Tetrad;
+    use crate::modules::triad::Triad;
+
+    #[test]
+    fn test_tetrad_to_dot() {
+        let mut document = SystemDocument::from_system(&Tetrad::new("Work \"Q3\"", "Office", "Ideal", "Tools", "Plan"));
+        document.connectives[0].user_label = Some("Calling".to_string());
+        document.connectives[5].direction = Direction::Backward;
+
+        let dot = document.to_dot(&DotOptions::default());
+        assert!(dot.starts_with("graph \"Work \\\"Q3\\\"\" {\n  label=\"Tetrad: Work \\\"Q3\\\"\";\n"), "{}", dot);
+        assert!(dot.contains("  A [label=\"A. Ground\\nOffice\"];\n"), "{}", dot);
+        assert!(dot.contains("  B [label=\"B. Ideal\"];\n"), "{}", dot);
+        assert!(dot.contains("  A -- B [label=\"Calling\"];\n"), "{}", dot);
+        assert!(dot.contains("  C -- D [label=\"Demonstrable activity\", dir=back];\n"), "{}", dot);
+        assert_eq!(dot.matches(" -- ").count(), 6);
+        assert!(!dot.contains("circo"));
+        assert!(dot.ends_with("}\n"));
+    }
+
+    #[test]
+    fn test_circle_and_hidden_default_labels() {
+        let mut document = SystemDocument::from_system(&Tetrad::new("Work", "Office", "Ideal", "Tools", "Plan"));
+        document.connectives[0].user_label = Some("Calling".to_string());
+        let options = DotOptions {
+            circle: true,
+            hide_default_labels: true,
+            ..DotOptions::default()
+        };
+
+        let dot = document.to_dot(&options);
+        assert!(dot.contains("  layout=circo;\n"));
+        assert!(dot.contains("  A -- B [label=\"Calling\"];\n"));
+        assert!(dot.contains("  A -- C;\n"));
+        assert!(!dot.contains("Motivational imperative"));
+    }
+
+    #[test]
+    fn test_every_system_exports() {
+        let triad = Triad::new("Will", "Intention", "Resistance", "Resolution");
+        let dot = triad.to_dot(&DotOptions::default());
+        assert!(dot.contains("  A [label=\"A. Active\\nIntention\"];\n"), "{}", dot);
+
+        let octad: Box<dyn System> = Box::new(Octad::new("Firm", "A", "B", "C", "D", "E", "F", "G", "H"));
+        assert_eq!(octad.to_dot(&DotOptions::default()).matches(" -- ").count(), 28);
+    }
+
+    #[test]
+    fn test_nested_clusters() {
+        let leaf = SystemTree {
+            id: 3,
+            system: SystemDocument::from_system(&Triad::new("Sales drive", "Calls", "Objections", "Deals")),
+            children: Vec::new(),
+        };
+        let middle = SystemTree {
+            id: 2,
+            system: SystemDocument::from_system(&Tetrad::new("Functions", "Sales", "Service", "Delivery", "Billing")),
+            children: vec![NestedSystem { position: Position::new('A'), tree: leaf }],
+        };
+        let root = SystemTree {
+            id: 1,
+            system: SystemDocument::from_system(&Tetrad::new("Firm", "A", "B", "Critical Functions", "D")),
+            children: vec![NestedSystem { position: Position::new('C'), tree: middle }],
+        };
+
+        // Without the option only the root is drawn
+        assert_eq!(root.to_dot(&DotOptions::default()), root.system.to_dot(&DotOptions::default()));
+
+        let dot = root.to_dot(&DotOptions { nested: true, ..DotOptions::default() });
+        assert!(dot.contains("  compound=true;\n"));
+        assert!(dot.contains("  subgraph cluster_1 {\n    label=\"Tetrad 2: Functions\";\n"), "{}", dot);
+        assert!(dot.contains("    s1_A [label=\"A. Ground\\nSales\"];\n"), "{}", dot);
+        assert!(dot.contains("  s0_C -- s1_A [style=dashed, lhead=cluster_1];\n"), "{}", dot);
+        assert!(dot.contains("  s1_A -- s2_A [style=dashed, lhead=cluster_2];\n"), "{}", dot);
+    }
+}
@@ -1,32 +1,48 @@
-use std::io::{self, Write};
+use std::ops::{Deref, DerefMut};
 
-#[derive(Debug)]
-#[allow(non_snake_case)] // Connective fields use intentional positional-semantic naming
+use crate::modules::connective::Connective;
+use crate::modules::error::SystematicsError;
+use crate::modules::instance::SystemInstance;
+use crate::modules::prompter::{IoPrompter, Prompter};
+use crate::modules::system::System;
+use crate::modules::template::{ConnectiveTemplate, SystemTemplate, TermTemplate};
+
+/// Canonical Hexad positions and connectives
+pub static TEMPLATE: SystemTemplate = SystemTemplate {
+    kind: "Hexad",
+    order: 6,
+    attribute_description: Hexad::TERM_ATTRIBUTE_DESCRIPTION,
+    terms: &[
+        TermTemplate { position: 'A', label: "Resources" },
+        TermTemplate { position: 'B', label: "Values" },
+        TermTemplate { position: 'C', label: "Options" },
+        TermTemplate { position: 'D', label: "Criteria" },
+        TermTemplate { position: 'E', label: "Facts" },
+        TermTemplate { position: 'F', label: "Priorities" },
+    ],
+    connectives: &[
+        ConnectiveTemplate { from: 'A', to: 'B', name: "Worth", description: "What the resources are worth in the light of values" },
+        ConnectiveTemplate { from: 'A', to: 'C', name: "Feasibility", description: "Which options the resources make possible" },
+        ConnectiveTemplate { from: 'A', to: 'D', name: "Sufficiency", description: "Whether the resources meet the criteria" },
+        ConnectiveTemplate { from: 'A', to: 'E', name: "Inventory", description: "The resources as they actually stand" },
+        ConnectiveTemplate { from: 'A', to: 'F', name: "Allocation", description: "Resources committed according to priorities" },
+        ConnectiveTemplate { from: 'B', to: 'C', name: "Desirability", description: "Options weighed against what is valued" },
+        ConnectiveTemplate { from: 'B', to: 'D', name: "Standards", description: "Values made explicit as criteria" },
+        ConnectiveTemplate { from: 'B', to: 'E', name: "Appraisal", description: "Facts judged in the light of values" },
+        ConnectiveTemplate { from: 'B', to: 'F', name: "Commitment", description: "Values that settle what comes first" },
+        ConnectiveTemplate { from: 'C', to: 'D', name: "Evaluation", description: "Options tested against the criteria" },
+        ConnectiveTemplate { from: 'C', to: 'E', name: "Possibility", description: "Options grounded in the facts" },
+        ConnectiveTemplate { from: 'C', to: 'F', name: "Choice", description: "Options ranked by priority" },
+        ConnectiveTemplate { from: 'D', to: 'E', name: "Measurement", description: "Facts checked against the criteria" },
+        ConnectiveTemplate { from: 'D', to: 'F', name: "Weighting", description: "Criteria ordered by priority" },
+        ConnectiveTemplate { from: 'E', to: 'F', name: "Urgency", description: "Facts that press on the priorities" },
+    ],
+};
+
+/// Typed view over a 6-term `SystemInstance` built from the Hexad template
+#[derive(Debug, Clone, PartialEq)]
 pub struct Hexad {
-    pub name: String,
-    // User instances for canonical positions
-    pub resources: String,     // Position A
-    pub values: String,        // Position B
-    pub options: String,       // Position C
-    pub criteria: String,      // Position D
-    pub facts: String,         // Position E
-    pub priorities: String,    // Position F
-    // Positional-semantic connectives (bidirectional relationships)
-    pub AB_resources_values: Option<String>,      // A<>B
-    pub AC_resources_options: Option<String>,     // A<>C
-    pub AD_resources_criteria: Option<String>,    // A<>D
-    pub AE_resources_facts: Option<String>,       // A<>E
-    pub AF_resources_priorities: Option<String>,  // A<>F
-    pub BC_values_options: Option<String>,        // B<>C
-    pub BD_values_criteria: Option<String>,       // B<>D
-    pub BE_values_facts: Option<String>,          // B<>E
-    pub BF_values_priorities: Option<String>,     // B<>F
-    pub CD_options_criteria: Option<String>,      // C<>D
-    pub CE_options_facts: Option<String>,         // C<>E
-    pub CF_options_priorities: Option<String>,    // C<>F
-    pub DE_criteria_facts: Option<String>,        // D<>E
-    pub DF_criteria_priorities: Option<String>,   // D<>F
-    pub EF_facts_priorities: Option<String>,      // E<>F
+    system: SystemInstance,
 }
 
 impl Hexad {
@@ -42,275 +58,124 @@ impl Hexad {
         facts: &str,
         priorities: &str,
     ) -> Self {
-        Hexad {
-            name: name.to_string(),
-            resources: resources.to_string(),
-            values: values.to_string(),
-            options: options.to_string(),
-            criteria: criteria.to_string(),
-            facts: facts.to_string(),
-            priorities: priorities.to_string(),
-            // Initialize connectives with positional-semantic defaults
-            AB_resources_values: Some("AB_resources_values".to_string()),
-            AC_resources_options: Some("AC_resources_options".to_string()),
-            AD_resources_criteria: Some("AD_resources_criteria".to_string()),
-            AE_resources_facts: Some("AE_resources_facts".to_string()),
-            AF_resources_priorities: Some("AF_resources_priorities".to_string()),
-            BC_values_options: Some("BC_values_options".to_string()),
-            BD_values_criteria: Some("BD_values_criteria".to_string()),
-            BE_values_facts: Some("BE_values_facts".to_string()),
-            BF_values_priorities: Some("BF_values_priorities".to_string()),
-            CD_options_criteria: Some("CD_options_criteria".to_string()),
-            CE_options_facts: Some("CE_options_facts".to_string()),
-            CF_options_priorities: Some("CF_options_priorities".to_string()),
-            DE_criteria_facts: Some("DE_criteria_facts".to_string()),
-            DF_criteria_priorities: Some("DF_criteria_priorities".to_string()),
-            EF_facts_priorities: Some("EF_facts_priorities".to_string()),
-        }
+        let instances = [resources, values, options, criteria, facts, priorities];
+        let system = SystemInstance::with_instances(&TEMPLATE, name, &instances)
+            .expect("Hexad template has six positions");
+        Hexad { system }
     }
-    
-    /// Interactive creation method - handles all input/output internally
-    pub fn create_interactive() -> Result<Self, Box<dyn std::error::Error>> {
-        println!("\n--- Creating a Hexad ---");
-        
-        // Helper for required input - loops until valid input is provided
-        let _get_required_input = |prompt: &str, field_name: &str| -> Result<String, Box<dyn std::error::Error>> {
-            loop {
-                let mut input = String::new();
-                print!("{}", prompt);
-                
-                if let Err(e) = io::stdout().flush() {
-                    return Err(format!("Could not flush output: {}", e).into());
-                }
-                
-                match io::stdin().read_line(&mut input) {
-                    Ok(_) => {
-                        let trimmed = input.trim();
-                        
-                        // Check for empty input - prompt again
-                        if trimmed.is_empty() {
-                            println!("{} is required. Please enter a value.", field_name);
-                            continue;
-                        }
-                        
-                        // Check for reasonable length (1-100 characters)
-                        if trimmed.len() > 100 {
-                            println!("{} is too long (max 100 characters). Please try again.", field_name);
-                            continue;
-                        }
-                        
-                        // Check for valid characters (letters, numbers, spaces, basic punctuation)
-                        if !trimmed.chars().all(|c| c.is_alphanumeric() || c.is_whitespace() || ".,!?'-()".contains(c)) {
-                            println!("{} contains invalid characters. Please use only letters, numbers, spaces, and basic punctuation.", field_name);
-                            continue;
-                        }
-                        
-                        return Ok(trimmed.to_string());
-                    }
-                    Err(e) => return Err(format!("Error reading {}: {}", field_name, e).into())
-                }
-            }
-        };
-        
-        // Helper for optional input with default
-        let get_optional_input = |prompt: &str, default: &str| -> Result<String, Box<dyn std::error::Error>> {
-            let mut input = String::new();
-            print!("{}", prompt);
-            
-            if let Err(e) = io::stdout().flush() {
-                eprintln!("Warning: Could not flush output: {}", e);
-            }
-            
-            match io::stdin().read_line(&mut input) {
-                Ok(_) => {
-                    let trimmed = input.trim();
-                    
-                    if trimmed.is_empty() {
-                        Ok(default.to_string())
-                    } else {
-                        // Validate optional input if provided
-                        if trimmed.len() > 100 {
-                            return Err("Input is too long (max 100 characters)".into());
-                        }
-                        
-                        if !trimmed.chars().all(|c| c.is_alphanumeric() || c.is_whitespace() || ".,!?'-()".contains(c)) {
-                            return Err("Input contains invalid characters".into());
-                        }
-                        
-                        Ok(trimmed.to_string())
-                    }
-                }
-                Err(e) => {
-                    eprintln!("Error reading input: {}. Using default: {}", e, default);
-                    Ok(default.to_string())
-                }
-            }
-        };
-        
-        // Helper for yes/no input
-        let get_yes_no_input = |prompt: &str, default: &str| -> Result<String, Box<dyn std::error::Error>> {
-            loop {
-                let mut input = String::new();
-                print!("{}", prompt);
-                
-                if let Err(e) = io::stdout().flush() {
-                    eprintln!("Warning: Could not flush output: {}", e);
-                }
-                
-                match io::stdin().read_line(&mut input) {
-                    Ok(_) => {
-                        let trimmed = input.trim().to_lowercase();
-                        
-                        if trimmed.is_empty() {
-                            return Ok(default.to_string());
-                        }
-                        
-                        match trimmed.as_str() {
-                            "y" | "yes" | "n" | "no" => return Ok(trimmed),
-                            _ => {
-                                println!("Please enter 'y' or 'n' (or press Enter for default: {})", default);
-                                continue;
-                            }
-                        }
-                    }
-                    Err(e) => return Err(format!("Error reading input: {}", e).into())
-                }
-            }
-        };
-        
-        // Get inputs using canonical terms
-        let name = get_optional_input("Enter a name for your Hexad (or press Enter for 'Unnamed Hexad'): ", "Unnamed Hexad")?;
-        let resources = get_optional_input("Enter the Resources instance (or press Enter for 'Resources'): ", "Resources")?;
-        let values = get_optional_input("Enter the Values instance (or press Enter for 'Values'): ", "Values")?;
-        let options = get_optional_input("Enter the Options instance (or press Enter for 'Options'): ", "Options")?;
-        let criteria = get_optional_input("Enter the Criteria instance (or press Enter for 'Criteria'): ", "Criteria")?;
-        let facts = get_optional_input("Enter the Facts instance (or press Enter for 'Facts'): ", "Facts")?;
-        let priorities = get_optional_input("Enter the Priorities instance (or press Enter for 'Priorities'): ", "Priorities")?;
-
-        let mut hexad = Hexad::new(&name, &resources, &values, &options, &criteria, &facts, &priorities);
-        
-        // Ask if user wants to modify the default connectives
-        let modify_connectives = get_yes_no_input("\nWould you like to modify the default connectives? (y/n): ", "y")?;
-        
-        if modify_connectives.starts_with('y') {
-            println!("\nModifying connectives (press Enter to keep default, or input new value):");
-            println!("Note: Hexad has 15 connectives - this may take a moment to review.");
-            
-            // Helper to handle connective modification
-            let modify_connective = |prompt: &str, current: &str| -> Result<Option<String>, Box<dyn std::error::Error>> {
-                let input = get_optional_input(prompt, current)?;
-                Ok(Some(input))
-            };
-            
-            hexad.AB_resources_values = modify_connective("AB_resources_values: ", "AB_resources_values")?;
-            hexad.AC_resources_options = modify_connective("AC_resources_options: ", "AC_resources_options")?;
-            hexad.AD_resources_criteria = modify_connective("AD_resources_criteria: ", "AD_resources_criteria")?;
-            hexad.AE_resources_facts = modify_connective("AE_resources_facts: ", "AE_resources_facts")?;
-            hexad.AF_resources_priorities = modify_connective("AF_resources_priorities: ", "AF_resources_priorities")?;
-            hexad.BC_values_options = modify_connective("BC_values_options: ", "BC_values_options")?;
-            hexad.BD_values_criteria = modify_connective("BD_values_criteria: ", "BD_values_criteria")?;
-            hexad.BE_values_facts = modify_connective("BE_values_facts: ", "BE_values_facts")?;
-            hexad.BF_values_priorities = modify_connective("BF_values_priorities: ", "BF_values_priorities")?;
-            hexad.CD_options_criteria = modify_connective("CD_options_criteria: ", "CD_options_criteria")?;
-            hexad.CE_options_facts = modify_connective("CE_options_facts: ", "CE_options_facts")?;
-            hexad.CF_options_priorities = modify_connective("CF_options_priorities: ", "CF_options_priorities")?;
-            hexad.DE_criteria_facts = modify_connective("DE_criteria_facts: ", "DE_criteria_facts")?;
-            hexad.DF_criteria_priorities = modify_connective("DF_criteria_priorities: ", "DF_criteria_priorities")?;
-            hexad.EF_facts_priorities = modify_connective("EF_facts_priorities: ", "EF_facts_priorities")?;
-        } else {
-            // Keep the defaults that were initialized (no further questions needed)
-        }
-        
-        // Display the created hexad
-        hexad.display();
-        
-        // Show connectives if any were defined
-        if hexad.has_connectives() {
-            hexad.display_connectives();
-        }
-        
-        Ok(hexad)
-    }
-    
-    /// Check if any connectives are defined
-    pub fn has_connectives(&self) -> bool {
-        self.AB_resources_values.is_some() ||
-        self.AC_resources_options.is_some() ||
-        self.AD_resources_criteria.is_some() ||
-        self.AE_resources_facts.is_some() ||
-        self.AF_resources_priorities.is_some() ||
-        self.BC_values_options.is_some() ||
-        self.BD_values_criteria.is_some() ||
-        self.BE_values_facts.is_some() ||
-        self.BF_values_priorities.is_some() ||
-        self.CD_options_criteria.is_some() ||
-        self.CE_options_facts.is_some() ||
-        self.CF_options_priorities.is_some() ||
-        self.DE_criteria_facts.is_some() ||
-        self.DF_criteria_priorities.is_some() ||
-        self.EF_facts_priorities.is_some()
-    }
-    
-    /// Get canonical term names (hardcoded)
-    #[allow(dead_code)]
+
+    /// Interactive creation method on the terminal
+    pub fn create_interactive() -> Result<Self, SystematicsError> {
+        Self::create_with(&mut IoPrompter::stdio())
+    }
+
+    /// Interactive creation through any prompter
+    pub fn create_with(prompter: &mut dyn Prompter) -> Result<Self, SystematicsError> {
+        SystemInstance::create_with(&TEMPLATE, prompter).map(|system| Hexad { system })
+    }
+
+    /// Resources instance (position A)
+    pub fn resources(&self) -> &str {
+        self.system.instance_at(0)
+    }
+
+    /// Values instance (position B)
+    pub fn values(&self) -> &str {
+        self.system.instance_at(1)
+    }
+
+    /// Options instance (position C)
+    pub fn options(&self) -> &str {
+        self.system.instance_at(2)
+    }
+
+    /// Criteria instance (position D)
+    pub fn criteria(&self) -> &str {
+        self.system.instance_at(3)
+    }
+
+    /// Facts instance (position E)
+    pub fn facts(&self) -> &str {
+        self.system.instance_at(4)
+    }
+
+    /// Priorities instance (position F)
+    pub fn priorities(&self) -> &str {
+        self.system.instance_at(5)
+    }
+
+    /// Get canonical term names
     pub fn get_canonical_terms() -> Vec<&'static str> {
-        vec!["Resources", "Values", "Options", "Criteria", "Facts", "Priorities"]
+        TEMPLATE.canonical_terms()
     }
-    
+
     /// Get all user instances
-    #[allow(dead_code)]
     pub fn get_instances(&self) -> Vec<String> {
-        vec![
-            self.resources.clone(),
-            self.values.clone(),
-            self.options.clone(),
-            self.criteria.clone(),
-            self.facts.clone(),
-            self.priorities.clone(),
-        ]
-    }
-    
-    /// Display hexad details
-    pub fn display(&self) {
-        println!("\n--- Hexad Details ---");
-        println!("Hexad Name: {}", self.name);
-        println!("Core Attribute: {}", Self::TERM_ATTRIBUTE_DESCRIPTION);
-        println!("A (Resources): {}", self.resources);
-        println!("B (Values): {}", self.values);
-        println!("C (Options): {}", self.options);
-        println!("D (Criteria): {}", self.criteria);
-        println!("E (Facts): {}", self.facts);
-        println!("F (Priorities): {}", self.priorities);
-        println!("---------------------");
-    }
-    
-    /// Display all connectives
-    pub fn display_connectives(&self) {
-        println!("\nConnectives:");
-        let connectives = [
-            (&self.resources, &self.values, &self.AB_resources_values, "A<>B"),
-            (&self.resources, &self.options, &self.AC_resources_options, "A<>C"),
-            (&self.resources, &self.criteria, &self.AD_resources_criteria, "A<>D"),
-            (&self.resources, &self.facts, &self.AE_resources_facts, "A<>E"),
-            (&self.resources, &self.priorities, &self.AF_resources_priorities, "A<>F"),
-            (&self.values, &self.options, &self.BC_values_options, "B<>C"),
-            (&self.values, &self.criteria, &self.BD_values_criteria, "B<>D"),
-            (&self.values, &self.facts, &self.BE_values_facts, "B<>E"),
-            (&self.values, &self.priorities, &self.BF_values_priorities, "B<>F"),
-            (&self.options, &self.criteria, &self.CD_options_criteria, "C<>D"),
-            (&self.options, &self.facts, &self.CE_options_facts, "C<>E"),
-            (&self.options, &self.priorities, &self.CF_options_priorities, "C<>F"),
-            (&self.criteria, &self.facts, &self.DE_criteria_facts, "D<>E"),
-            (&self.criteria, &self.priorities, &self.DF_criteria_priorities, "D<>F"),
-            (&self.facts, &self.priorities, &self.EF_facts_priorities, "E<>F"),
-        ];
-        
-        for (from, to, connective, code) in connectives {
-            match connective {
-                Some(conn) => println!("  {} <--[{}]--> {} ({})", from, conn, to, code),
-                None => println!("  {} <--> {} (no connective defined) ({})", from, to, code),
-            }
+        self.system.instances().to_vec()
+    }
+
+    /// Underlying data-driven system
+    pub fn into_instance(self) -> SystemInstance {
+        self.system
+    }
+}
+
+impl Deref for Hexad {
+    type Target = SystemInstance;
+
+    fn deref(&self) -> &SystemInstance {
+        &self.system
+    }
+}
+
+impl DerefMut for Hexad {
+    fn deref_mut(&mut self) -> &mut SystemInstance {
+        &mut self.system
+    }
+}
+
+impl TryFrom<SystemInstance> for Hexad {
+    type Error = SystematicsError;
+
+    fn try_from(system: SystemInstance) -> Result<Self, Self::Error> {
+        if !std::ptr::eq(system.template(), &TEMPLATE) {
+            return Err(SystematicsError::Structure(format!("Expected Hexad template, got {}", system.template().kind)));
         }
+        Ok(Hexad { system })
+    }
+}
+
+impl System for Hexad {
+    fn kind(&self) -> &'static str {
+        self.system.template().kind
+    }
+
+    fn order(&self) -> usize {
+        self.system.order()
+    }
+
+    fn name(&self) -> &str {
+        &self.system.name
+    }
+
+    fn canonical_terms(&self) -> Vec<&'static str> {
+        Self::get_canonical_terms()
+    }
+
+    fn instances(&self) -> Vec<String> {
+        self.get_instances()
+    }
+
+    fn connectives(&self) -> &[Connective] {
+        self.system.connectives()
+    }
+
+    fn attribute_description(&self) -> &'static str {
+        Self::TERM_ATTRIBUTE_DESCRIPTION
+    }
+
+    fn display(&self) {
+        self.system.display()
     }
 }
 
@@ -331,16 +196,16 @@ mod tests {
         );
         
         assert_eq!(hexad.name, "Test Hexad");
-        assert_eq!(hexad.resources, "Money");
-        assert_eq!(hexad.values, "Quality");
-        assert_eq!(hexad.options, "Choices");
-        assert_eq!(hexad.criteria, "Standards");
-        assert_eq!(hexad.facts, "Data");
-        assert_eq!(hexad.priorities, "Goals");
+        assert_eq!(hexad.resources(), "Money");
+        assert_eq!(hexad.values(), "Quality");
+        assert_eq!(hexad.options(), "Choices");
+        assert_eq!(hexad.criteria(), "Standards");
+        assert_eq!(hexad.facts(), "Data");
+        assert_eq!(hexad.priorities(), "Goals");
         
         // Should have default connectives
         assert!(hexad.has_connectives());
-        assert_eq!(hexad.AB_resources_values.unwrap(), "AB_resources_values");
+        assert_eq!(hexad.connective_label('A', 'B').unwrap(), "Worth");
     }
 
     #[test]
@@ -368,32 +233,21 @@ mod tests {
     #[test]
     fn test_has_connectives_with_some() {
         let mut hexad = Hexad::new("Test", "R", "V", "O", "C", "F", "P");
-        hexad.AB_resources_values = Some("test connection".to_string());
+        hexad.set_connective_label('A', 'B', Some("test connection".to_string())).unwrap();
         
         assert!(hexad.has_connectives());
     }
 
     #[test]
-    fn test_has_connectives_with_none() {
+    fn test_reset_connective_label() {
         let mut hexad = Hexad::new("Test", "R", "V", "O", "C", "F", "P");
-        // Remove all connectives to test none state
-        hexad.AB_resources_values = None;
-        hexad.AC_resources_options = None;
-        hexad.AD_resources_criteria = None;
-        hexad.AE_resources_facts = None;
-        hexad.AF_resources_priorities = None;
-        hexad.BC_values_options = None;
-        hexad.BD_values_criteria = None;
-        hexad.BE_values_facts = None;
-        hexad.BF_values_priorities = None;
-        hexad.CD_options_criteria = None;
-        hexad.CE_options_facts = None;
-        hexad.CF_options_priorities = None;
-        hexad.DE_criteria_facts = None;
-        hexad.DF_criteria_priorities = None;
-        hexad.EF_facts_priorities = None;
-        
-        assert!(!hexad.has_connectives());
+        hexad.set_connective_label('A', 'B', Some("custom connection".to_string())).unwrap();
+        hexad.set_connective_label('A', 'B', None).unwrap();
+
+        // Clearing the user label restores the canonical name
+        assert!(hexad.has_connectives());
+        assert_eq!(hexad.connective_label('A', 'B'), Some("Worth"));
+        assert!(!hexad.connective('A', 'B').unwrap().is_relabelled());
     }
 
     #[test]
@@ -402,10 +256,10 @@ mod tests {
         
         // Should start with positional-semantic connectives
         assert!(hexad.has_connectives());
-        assert_eq!(hexad.AB_resources_values.unwrap(), "AB_resources_values");
-        assert_eq!(hexad.BC_values_options.unwrap(), "BC_values_options");
-        assert_eq!(hexad.DE_criteria_facts.unwrap(), "DE_criteria_facts");
-        assert_eq!(hexad.EF_facts_priorities.unwrap(), "EF_facts_priorities");
+        assert_eq!(hexad.connective_label('A', 'B').unwrap(), "Worth");
+        assert_eq!(hexad.connective_label('B', 'C').unwrap(), "Desirability");
+        assert_eq!(hexad.connective_label('D', 'E').unwrap(), "Measurement");
+        assert_eq!(hexad.connective_label('E', 'F').unwrap(), "Urgency");
     }
 
     #[test]
@@ -413,14 +267,14 @@ mod tests {
         let mut hexad = Hexad::new("Test", "R", "V", "O", "C", "F", "P");
         
         // Modify one connective while others keep defaults
-        hexad.AB_resources_values = Some("custom connection".to_string());
+        hexad.set_connective_label('A', 'B', Some("custom connection".to_string())).unwrap();
         
         assert!(hexad.has_connectives());
-        assert_eq!(hexad.AB_resources_values.unwrap(), "custom connection");
+        assert_eq!(hexad.connective_label('A', 'B').unwrap(), "custom connection");
         
         // Other connectives should still have defaults
-        assert_eq!(hexad.BC_values_options.unwrap(), "BC_values_options");
-        assert_eq!(hexad.DE_criteria_facts.unwrap(), "DE_criteria_facts");
+        assert_eq!(hexad.connective_label('B', 'C').unwrap(), "Desirability");
+        assert_eq!(hexad.connective_label('D', 'E').unwrap(), "Measurement");
     }
 
     #[test]
@@ -429,23 +283,40 @@ mod tests {
         
         // Should have exactly 15 connectives (6 choose 2 = 15)
         let connectives_count = [
-            hexad.AB_resources_values.is_some(),
-            hexad.AC_resources_options.is_some(),
-            hexad.AD_resources_criteria.is_some(),
-            hexad.AE_resources_facts.is_some(),
-            hexad.AF_resources_priorities.is_some(),
-            hexad.BC_values_options.is_some(),
-            hexad.BD_values_criteria.is_some(),
-            hexad.BE_values_facts.is_some(),
-            hexad.BF_values_priorities.is_some(),
-            hexad.CD_options_criteria.is_some(),
-            hexad.CE_options_facts.is_some(),
-            hexad.CF_options_priorities.is_some(),
-            hexad.DE_criteria_facts.is_some(),
-            hexad.DF_criteria_priorities.is_some(),
-            hexad.EF_facts_priorities.is_some(),
+            hexad.connective_label('A', 'B').is_some(),
+            hexad.connective_label('A', 'C').is_some(),
+            hexad.connective_label('A', 'D').is_some(),
+            hexad.connective_label('A', 'E').is_some(),
+            hexad.connective_label('A', 'F').is_some(),
+            hexad.connective_label('B', 'C').is_some(),
+            hexad.connective_label('B', 'D').is_some(),
+            hexad.connective_label('B', 'E').is_some(),
+            hexad.connective_label('B', 'F').is_some(),
+            hexad.connective_label('C', 'D').is_some(),
+            hexad.connective_label('C', 'E').is_some(),
+            hexad.connective_label('C', 'F').is_some(),
+            hexad.connective_label('D', 'E').is_some(),
+            hexad.connective_label('D', 'F').is_some(),
+            hexad.connective_label('E', 'F').is_some(),
         ].iter().filter(|&&x| x).count();
         
         assert_eq!(connectives_count, 15);
     }
-} 
\ No newline at end of file
+
+    #[test]
+    fn test_view_over_system_instance() {
+        let hexad = Hexad::new("Test", "R", "V", "O", "C", "F", "P");
+        let system = hexad.clone().into_instance();
+
+        assert_eq!(system.instance('C'), Some("O"));
+        assert_eq!(Hexad::try_from(system).unwrap(), hexad);
+    }
+
+    #[test]
+    fn test_try_from_other_order_fails() {
+        let octad = crate::modules::octad::Octad::new("Test", "A", "B", "C", "D", "E", "F", "G", "H");
+        let result = Hexad::try_from(octad.into_instance());
+
+        assert_eq!(result.unwrap_err().to_string(), "Expected Hexad template, got Octad");
+    }
+}
@@ -0,0 +1,229 @@
+use serde::Serialize;
+
+use crate::modules::connective::Position;
+use crate::modules::document::SystemDocument;
+use crate::modules::error::SystematicsError;
+use crate::modules::store::{Store, StoredSystem};
+
+/// A stored system with the systems nested in its terms, expanded all the way down.
+///
+/// Nesting is by store id, so one system can elaborate terms of several parents;
+/// such shared systems appear once under each parent.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SystemTree {
+    pub id: u64,
+    pub system: SystemDocument,
+    pub children: Vec<NestedSystem>,
+}
+
+/// A child system and the position of the term it elaborates
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct NestedSystem {
+    pub position: Position,
+    pub tree: SystemTree,
+}
+
+impl SystemTree {
+    /// Every system in the tree, depth first, with its depth (0 for the root)
+    pub fn walk(&self) -> Vec<(usize, &SystemTree)> {
+        let mut nodes = vec![(0, self)];
+        for nested in &self.children {
+            nodes.extend(nested.tree.walk().into_iter().map(|(depth, tree)| (depth + 1, tree)));
+        }
+        nodes
+    }
+
+    /// Follows term positions down the tree, e.g. [C, B] is the system nested at B of the one at C
+    pub fn descend(&self, path: &[Position]) -> Option<&SystemTree> {
+        match path.split_first() {
+            None => Some(self),
+            Some((position, rest)) => self
+                .children
+                .iter()
+                .find(|nested| nested.position == *position)
+                .and_then(|nested| nested.tree.descend(rest)),
+        }
+    }
+}
+
+impl Store {
+    /// The system with the given id and everything nested below it
+    pub fn tree(&self, id: u64) -> Result<SystemTree, SystematicsError> {
+        self.tree_below(id, &mut Vec::new())
+    }
+
+    /// Makes a stored system elaborate one term of another (None removes the link),
+    /// refusing if the parent is saved by someone else in the meantime
+    pub fn nest(&self, parent: u64, position: Position, child: Option<u64>) -> Result<StoredSystem, SystematicsError> {
+        let original = self.get(parent)?.system;
+        let mut system = original.clone();
+        let kind = system.kind.clone();
+        let term = system
+            .terms
+            .iter_mut()
+            .find(|term| term.position == position)
+            .ok_or_else(|| SystematicsError::Structure(format!("{} has no position {}", kind, position)))?;
+        term.child = child;
+        self.update_if_unchanged(parent, &original, system)
+    }
+
+    /// Systems that nest the given one, with the position it elaborates in each
+    pub fn parents(&self, id: u64) -> Result<Vec<(u64, Position)>, SystematicsError> {
+        let mut parents = Vec::new();
+        for stored in self.list()? {
+            for (position, child) in stored.system.children() {
+                if child == id {
+                    parents.push((stored.id, position));
+                }
+            }
+        }
+        Ok(parents)
+    }
+
+    /// Checks a document's children exist and, when it is saved under `id`, don't lead back to it
+    pub(crate) fn check_nesting(&self, id: Option<u64>, system: &SystemDocument) -> Result<(), SystematicsError> {
+        for (_, child) in system.children() {
+            self.get(child)?;
+            let Some(id) = id else { continue };
+            if child == id {
+                return Err(SystematicsError::Cycle(vec![id, id]));
+            }
+            if let Some(mut path) = self.path_between(child, id, &mut Vec::new())? {
+                path.insert(0, id);
+                return Err(SystematicsError::Cycle(path));
+            }
+        }
+        Ok(())
+    }
+
+    fn tree_below(&self, id: u64, ancestors: &mut Vec<u64>) -> Result<SystemTree, SystematicsError> {
+        if let Some(start) = ancestors.iter().position(|&ancestor| ancestor == id) {
+            let mut cycle = ancestors[start..].to_vec();
+            cycle.push(id);
+            return Err(SystematicsError::Cycle(cycle));
+        }
+
+        let system = self.get(id)?.system;
+        ancestors.push(id);
+        let mut children = Vec::new();
+        for (position, child) in system.children() {
+            children.push(NestedSystem {
+                position,
+                tree: self.tree_below(child, ancestors)?,
+            });
+        }
+        ancestors.pop();
+        Ok(SystemTree { id, system, children })
+    }
+
+    /// Ids leading from one system down to another through nesting, if any
+    fn path_between(&self, from: u64, to: u64, seen: &mut Vec<u64>) -> Result<Option<Vec<u64>>, SystematicsError> {
+        if from == to {
+            return Ok(Some(vec![to]));
+        }
+        if seen.contains(&from) {
+            return Ok(None);
+        }
+        seen.push(from);
+        for (_, child) in self.get(from)?.system.children() {
+            if let Some(mut path) = self.path_between(child, to, seen)? {
+                path.insert(0, from);
+                return Ok(Some(path));
+            }
+        }
+        Ok(None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::octad::Octad;
+    use crate::modules::tetrad::Tetrad;
+    use crate::modules::triad::Triad;
+    use std::fs;
+
+    fn temp_store(test: &str) -> Store {
+        let root = std::env::temp_dir().join(format!("systematics-hierarchy-{}-{}", test, std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        Store::open(&root).unwrap()
+    }
+
+    #[test]
+    fn test_nest_and_traverse() {
+        let store = temp_store("tree");
+        let octad = store.create(&Octad::new("Firm", "A", "B", "Critical Functions", "D", "E", "F", "G", "H")).unwrap();
+        let tetrad = store.create(&Tetrad::new("Functions", "Sales", "Service", "Delivery", "Billing")).unwrap();
+        let triad = store.create(&Triad::new("Sales drive", "Calls", "Objections", "Deals")).unwrap();
+
+        store.nest(octad.id, Position::new('C'), Some(tetrad.id)).unwrap();
+        store.nest(tetrad.id, Position::new('A'), Some(triad.id)).unwrap();
+
+        let tree = store.tree(octad.id).unwrap();
+        let walked: Vec<(usize, u64)> = tree.walk().into_iter().map(|(depth, node)| (depth, node.id)).collect();
+        assert_eq!(walked, [(0, 1), (1, 2), (2, 3)]);
+        let path = [Position::new('C'), Position::new('A')];
+        assert_eq!(tree.descend(&path).unwrap().system.name, "Sales drive");
+        assert!(tree.descend(&[Position::new('B')]).is_none());
+        assert_eq!(store.parents(tetrad.id).unwrap(), [(octad.id, Position::new('C'))]);
+
+        assert_eq!(
+            store.delete(tetrad.id).unwrap_err().to_string(),
+            "System 2 is nested in system 1 at C; unnest it first"
+        );
+
+        // A save in progress on the parent keeps the link from changing under it
+        let lock = store.lock(octad.id).unwrap();
+        assert!(store.nest(octad.id, Position::new('C'), None).is_err());
+        drop(lock);
+        assert_eq!(store.parents(tetrad.id).unwrap(), [(octad.id, Position::new('C'))]);
+
+        // Unnesting removes the link
+        store.nest(octad.id, Position::new('C'), None).unwrap();
+        assert!(store.tree(octad.id).unwrap().children.is_empty());
+
+        fs::remove_dir_all(store.root()).unwrap();
+    }
+
+    #[test]
+    fn test_shared_child_forms_a_dag() {
+        let store = temp_store("dag");
+        let first = store.create(&Tetrad::new("First", "A", "B", "C", "D")).unwrap();
+        let second = store.create(&Tetrad::new("Second", "A", "B", "C", "D")).unwrap();
+        let shared = store.create(&Triad::new("Shared", "A", "P", "R")).unwrap();
+
+        store.nest(first.id, Position::new('A'), Some(shared.id)).unwrap();
+        store.nest(first.id, Position::new('B'), Some(second.id)).unwrap();
+        store.nest(second.id, Position::new('D'), Some(shared.id)).unwrap();
+
+        let ids: Vec<u64> = store.tree(first.id).unwrap().walk().into_iter().map(|(_, node)| node.id).collect();
+        assert_eq!(ids, [1, 3, 2, 3]);
+
+        fs::remove_dir_all(store.root()).unwrap();
+    }
+
+    #[test]
+    fn test_cycles_are_rejected() {
+        let store = temp_store("cycle");
+        let one = store.create(&Tetrad::new("One", "A", "B", "C", "D")).unwrap();
+        let two = store.create(&Tetrad::new("Two", "A", "B", "C", "D")).unwrap();
+        let three = store.create(&Tetrad::new("Three", "A", "B", "C", "D")).unwrap();
+        store.nest(one.id, Position::new('A'), Some(two.id)).unwrap();
+        store.nest(two.id, Position::new('B'), Some(three.id)).unwrap();
+
+        let error = store.nest(three.id, Position::new('C'), Some(one.id)).unwrap_err();
+        assert!(matches!(&error, SystematicsError::Cycle(ids) if ids == &[3, 1, 2, 3]));
+        assert_eq!(error.to_string(), "Nesting would form a cycle: 3 → 1 → 2 → 3");
+        assert!(matches!(store.nest(one.id, Position::new('B'), Some(one.id)), Err(SystematicsError::Cycle(_))));
+        assert!(matches!(store.nest(one.id, Position::new('B'), Some(9)), Err(SystematicsError::NotFound(9))));
+
+        // A cycle written behind the store's back is still caught when walking
+        let mut record: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(store.root().join("3.json")).unwrap()).unwrap();
+        record["system"]["terms"][2]["child"] = serde_json::json!(1);
+        fs::write(store.root().join("3.json"), record.to_string()).unwrap();
+        assert!(matches!(store.tree(two.id), Err(SystematicsError::Cycle(ids)) if ids == [2, 3, 1, 2]));
+
+        fs::remove_dir_all(store.root()).unwrap();
+    }
+}
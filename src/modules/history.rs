@@ -0,0 +1,553 @@
+use std::fmt;
+use std::fs;
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+use crate::modules::connective::Position;
+use crate::modules::document::SystemDocument;
+use crate::modules::error::SystematicsError;
+use crate::modules::store::{now, write_atomic, Store, StoredSystem};
+
+/// Directory inside the store holding one `<id>.json` history per system
+const HISTORY_DIR: &str = "history";
+
+/// One mutation of a stored system
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum Change {
+    Created,
+    /// The system already existed before its history was kept
+    Baseline,
+    Renamed { from: String, to: String },
+    TermChanged { position: Position, canonical: Option<String>, from: String, to: String },
+    /// A Monad term was added
+    TermAdded { position: Position, instance: String },
+    /// A Monad term was removed
+    TermRemoved { position: Position, instance: String },
+    /// A connective's label changed; an unlabelled connective counts as its canonical name
+    ConnectiveRelabelled { connective: String, from: String, to: String },
+    Nested { position: Position, from: Option<u64>, to: Option<u64> },
+    /// Anything else, e.g. "six laws" or "A<>B direction"
+    Other { what: String },
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let system = |id: &Option<u64>| id.map_or_else(|| "none".to_string(), |id| id.to_string());
+        match self {
+            Change::Created => write!(f, "created"),
+            Change::Baseline => write!(f, "as saved before history was kept"),
+            Change::Renamed { from, to } => write!(f, "name '{}' → '{}'", from, to),
+            Change::TermChanged { position, canonical: Some(canonical), from, to } => {
+                write!(f, "{} ({}) '{}' → '{}'", position, canonical, from, to)
+            }
+            Change::TermChanged { position, canonical: None, from, to } => write!(f, "{} '{}' → '{}'", position, from, to),
+            Change::TermAdded { position, instance } => write!(f, "{} added '{}'", position, instance),
+            Change::TermRemoved { position, instance } => write!(f, "{} removed '{}'", position, instance),
+            Change::ConnectiveRelabelled { connective, from, to } => write!(f, "{} '{}' → '{}'", connective, from, to),
+            Change::Nested { position, from, to } => write!(f, "{} nested system {} → {}", position, system(from), system(to)),
+            Change::Other { what } => write!(f, "{} changed", what),
+        }
+    }
+}
+
+/// The events that turn one version of a system into another
+pub fn changes(before: &SystemDocument, after: &SystemDocument) -> Vec<Change> {
+    let mut changes = Vec::new();
+    if before.name != after.name {
+        changes.push(Change::Renamed {
+            from: before.name.clone(),
+            to: after.name.clone(),
+        });
+    }
+
+    for index in 0..before.terms.len().max(after.terms.len()) {
+        match (before.terms.get(index), after.terms.get(index)) {
+            (Some(old), Some(new)) => {
+                if old.instance != new.instance {
+                    changes.push(Change::TermChanged {
+                        position: new.position,
+                        canonical: new.canonical.clone(),
+                        from: old.instance.clone(),
+                        to: new.instance.clone(),
+                    });
+                }
+                if old.child != new.child {
+                    changes.push(Change::Nested {
+                        position: new.position,
+                        from: old.child,
+                        to: new.child,
+                    });
+                }
+            }
+            (None, Some(new)) => changes.push(Change::TermAdded {
+                position: new.position,
+                instance: new.instance.clone(),
+            }),
+            (Some(old), None) => changes.push(Change::TermRemoved {
+                position: old.position,
+                instance: old.instance.clone(),
+            }),
+            (None, None) => {}
+        }
+    }
+
+    for new in &after.connectives {
+        let Some(old) = before.connectives.iter().find(|old| old.joins(new.from, new.to)) else {
+            continue;
+        };
+        let code = format!("{}<>{}", new.from, new.to);
+        if old.label() != new.label() {
+            changes.push(Change::ConnectiveRelabelled {
+                connective: code.clone(),
+                from: old.label().to_string(),
+                to: new.label().to_string(),
+            });
+        }
+        if old.direction != new.direction {
+            changes.push(Change::Other { what: format!("{} direction", code) });
+        }
+        if old.notes != new.notes {
+            changes.push(Change::Other { what: format!("{} notes", code) });
+        }
+    }
+    if before.permutations != after.permutations {
+        changes.push(Change::Other { what: "six laws".to_string() });
+    }
+    if before.metadata != after.metadata {
+        changes.push(Change::Other { what: "metadata".to_string() });
+    }
+    changes
+}
+
+/// A saved version of a system: who made it, when, what changed and the result
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Revision {
+    /// Counts from 1, the system as created
+    pub number: usize,
+    /// Seconds since the Unix epoch
+    pub timestamp: u64,
+    pub author: String,
+    pub changes: Vec<Change>,
+    pub system: SystemDocument,
+}
+
+/// An undo or redo: who moved a system from one revision to another, and when
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Step {
+    /// Seconds since the Unix epoch
+    pub timestamp: u64,
+    pub author: String,
+    pub from: usize,
+    pub to: usize,
+}
+
+impl fmt::Display for Step {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let action = if self.to < self.from { "undo" } else { "redo" };
+        write!(f, "{} from revision {} to {}", action, self.from, self.to)
+    }
+}
+
+/// Every revision of one stored system.
+///
+/// `undo` and `redo` move `current` without dropping revisions and are logged as
+/// steps; a new edit made after an undo replaces the undone revisions.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct History {
+    pub id: u64,
+    /// Revision the stored system is at
+    pub current: usize,
+    pub revisions: Vec<Revision>,
+    /// Undo and redo steps, oldest first
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub steps: Vec<Step>,
+}
+
+impl History {
+    /// The revision with the given number
+    pub fn revision(&self, number: usize) -> Option<&Revision> {
+        number.checked_sub(1).and_then(|index| self.revisions.get(index))
+    }
+
+    /// A history starting from a record saved before histories were kept
+    fn baseline(stored: &StoredSystem) -> Self {
+        let created = stored.created_at == stored.updated_at;
+        History {
+            id: stored.id,
+            current: 1,
+            revisions: vec![Revision {
+                number: 1,
+                timestamp: stored.updated_at,
+                author: "unknown".to_string(),
+                changes: vec![if created { Change::Created } else { Change::Baseline }],
+                system: stored.system.clone(),
+            }],
+            steps: Vec::new(),
+        }
+    }
+}
+
+impl Store {
+    /// Every revision of a stored system, oldest first
+    pub fn history(&self, id: u64) -> Result<History, SystematicsError> {
+        let stored = self.get(id)?;
+        self.read_history(&stored)
+    }
+
+    /// A stored system as it was at a revision
+    pub fn at_revision(&self, id: u64, number: usize) -> Result<Revision, SystematicsError> {
+        let history = self.history(id)?;
+        history.revision(number).cloned().ok_or_else(|| {
+            SystematicsError::Structure(format!(
+                "System {} has no revision {} (1 to {})",
+                id,
+                number,
+                history.revisions.len()
+            ))
+        })
+    }
+
+    /// Puts a system back to the revision before its current one
+    pub fn undo(&self, id: u64) -> Result<StoredSystem, SystematicsError> {
+        let _lock = self.lock(id)?;
+        let history = self.history(id)?;
+        if history.current <= 1 {
+            return Err(SystematicsError::Structure(format!("Nothing to undo for system {}", id)));
+        }
+        let number = history.current - 1;
+        self.move_to(history, number)
+    }
+
+    /// Reapplies the revision most recently undone
+    pub fn redo(&self, id: u64) -> Result<StoredSystem, SystematicsError> {
+        let _lock = self.lock(id)?;
+        let history = self.history(id)?;
+        if history.current >= history.revisions.len() {
+            return Err(SystematicsError::Structure(format!("Nothing to redo for system {}", id)));
+        }
+        let number = history.current + 1;
+        self.move_to(history, number)
+    }
+
+    /// Adds a revision for a system just written, after being created (no `previous`)
+    /// or updated
+    pub(crate) fn record(&self, previous: Option<&StoredSystem>, stored: &StoredSystem) -> Result<(), SystematicsError> {
+        let (mut history, changes) = match previous {
+            None => (
+                History {
+                    id: stored.id,
+                    current: 0,
+                    revisions: Vec::new(),
+                    steps: Vec::new(),
+                },
+                vec![Change::Created],
+            ),
+            Some(previous) => (self.read_history(previous)?, changes(&previous.system, &stored.system)),
+        };
+        if changes.is_empty() {
+            return Ok(());
+        }
+
+        history.revisions.truncate(history.current);
+        history.current += 1;
+        history.revisions.push(Revision {
+            number: history.current,
+            timestamp: stored.updated_at,
+            author: self.author().to_string(),
+            changes,
+            system: stored.system.clone(),
+        });
+        self.write_history(&history)
+    }
+
+    /// Drops the history of a deleted system
+    pub(crate) fn forget(&self, id: u64) -> Result<(), SystematicsError> {
+        match fs::remove_file(self.history_path(id)) {
+            Err(error) if error.kind() != std::io::ErrorKind::NotFound => Err(error.into()),
+            _ => Ok(()),
+        }
+    }
+
+    /// Puts the stored system at a revision and logs the step, writing the system first
+    /// so a failed history write never leaves the history ahead of the system. Callers
+    /// hold the system's lock from reading the history until this returns.
+    fn move_to(&self, mut history: History, number: usize) -> Result<StoredSystem, SystematicsError> {
+        let mut stored = self.get(history.id)?;
+        stored.system = history.revisions[number - 1].system.clone();
+        for (position, child) in stored.system.children() {
+            if let Err(SystematicsError::NotFound(_)) = self.get(child) {
+                return Err(SystematicsError::Structure(format!(
+                    "Revision {} of system {} nests system {} at {}, which has since been deleted",
+                    number, history.id, child, position
+                )));
+            }
+        }
+        self.check_nesting(Some(history.id), &stored.system)?;
+        stored.updated_at = now().max(stored.created_at);
+        self.write(&stored)?;
+
+        history.steps.push(Step {
+            timestamp: stored.updated_at,
+            author: self.author().to_string(),
+            from: history.current,
+            to: number,
+        });
+        history.current = number;
+        self.write_history(&history)?;
+        Ok(stored)
+    }
+
+    fn read_history(&self, stored: &StoredSystem) -> Result<History, SystematicsError> {
+        let path = self.history_path(stored.id);
+        match fs::read_to_string(&path) {
+            Ok(contents) => serde_json::from_str(&contents)
+                .map_err(|error| SystematicsError::Storage(format!("{}: {}", path.display(), error))),
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => Ok(History::baseline(stored)),
+            Err(error) => Err(error.into()),
+        }
+    }
+
+    fn write_history(&self, history: &History) -> Result<(), SystematicsError> {
+        fs::create_dir_all(self.root().join(HISTORY_DIR))?;
+        write_atomic(&self.history_path(history.id), &serde_json::to_string_pretty(history)?)
+    }
+
+    fn history_path(&self, id: u64) -> PathBuf {
+        self.root().join(HISTORY_DIR).join(format!("{}.json", id))
+    }
+}
+
+/// "2026-10-18 09:15 UTC" for seconds since the Unix epoch
+pub fn format_timestamp(seconds: u64) -> String {
+    let days = (seconds / 86_400) as i64;
+    let (hour, minute) = ((seconds % 86_400) / 3_600, (seconds % 3_600) / 60);
+
+    // Civil date from days since 1970-01-01, in 400-year eras starting in March
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era = (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 { month_index + 3 } else { month_index - 9 };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    format!("{:04}-{:02}-{:02} {:02}:{:02} UTC", year, month, day, hour, minute)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::monad::Monad;
+    use crate::modules::tetrad::Tetrad;
+    use crate::modules::triad::Triad;
+
+    fn temp_store(test: &str) -> Store {
+        let root = std::env::temp_dir().join(format!("systematics-history-{}-{}", test, std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        Store::open(&root).unwrap()
+    }
+
+    fn tetrad() -> SystemDocument {
+        SystemDocument::from_system(&Tetrad::new("Work", "Office", "Mission", "Tools", "Plan"))
+    }
+
+    #[test]
+    fn test_changes_between_versions() {
+        let before = tetrad();
+        let mut after = before.clone();
+        after.name = "Office work".to_string();
+        after.terms[0].instance = "Desk".to_string();
+        after.terms[2].child = Some(4);
+        after.connectives[0].user_label = Some("Calling".to_string());
+
+        let described: Vec<String> = changes(&before, &after).iter().map(Change::to_string).collect();
+        assert_eq!(
+            described,
+            vec![
+                "name 'Work' → 'Office work'",
+                "A (Ground) 'Office' → 'Desk'",
+                "C nested system none → 4",
+                "A<>B 'Motivational imperative' → 'Calling'",
+            ]
+        );
+        assert!(changes(&before, &before).is_empty());
+
+        // A directed connective keeps one code across its events
+        let mut directed = before.clone();
+        directed.connectives[0].direction = crate::modules::connective::Direction::Forward;
+        directed.connectives[0].user_label = Some("Calling".to_string());
+        let codes: Vec<String> = changes(&before, &directed)
+            .into_iter()
+            .map(|change| match change {
+                Change::ConnectiveRelabelled { connective, .. } => connective,
+                Change::Other { what } => what,
+                change => change.to_string(),
+            })
+            .collect();
+        assert_eq!(codes, vec!["A<>B", "A<>B direction"]);
+
+        let mut monad = SystemDocument::from_system(&Monad::new("Whole"));
+        let mut grown = monad.clone();
+        grown.terms.push(crate::modules::document::TermDocument {
+            position: Position::new('A'),
+            canonical: None,
+            instance: "One".to_string(),
+            child: None,
+        });
+        assert_eq!(changes(&monad, &grown)[0].to_string(), "A added 'One'");
+        monad.name = "Hole".to_string();
+        assert_eq!(changes(&grown, &monad).len(), 2);
+    }
+
+    #[test]
+    fn test_every_mutation_is_recorded() {
+        let store = temp_store("record").with_author("alice");
+        let id = store.create_document(tetrad()).unwrap().id;
+        let mut system = store.get(id).unwrap().system;
+        system.terms[0].instance = "Desk".to_string();
+        store.update(id, system.clone()).unwrap();
+        // Saving the same values again is not a revision
+        store.update(id, system.clone()).unwrap();
+        let store = store.with_author("bob");
+        system.connectives[5].user_label = Some("Delivery".to_string());
+        store.update(id, system).unwrap();
+
+        let history = store.history(id).unwrap();
+        assert_eq!(history.current, 3);
+        let summary: Vec<(usize, &str, String)> = history
+            .revisions
+            .iter()
+            .map(|revision| (revision.number, revision.author.as_str(), revision.changes[0].to_string()))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (1, "alice", "created".to_string()),
+                (2, "alice", "A (Ground) 'Office' → 'Desk'".to_string()),
+                (3, "bob", "C<>D 'Demonstrable activity' → 'Delivery'".to_string()),
+            ]
+        );
+        assert_eq!(store.at_revision(id, 1).unwrap().system.terms[0].instance, "Office");
+        assert!(store.at_revision(id, 4).unwrap_err().to_string().contains("no revision 4 (1 to 3)"));
+
+        store.delete(id).unwrap();
+        assert!(!store.history_path(id).exists());
+        fs::remove_dir_all(store.root()).unwrap();
+    }
+
+    #[test]
+    fn test_undo_and_redo() {
+        let store = temp_store("undo");
+        let id = store.create_document(tetrad()).unwrap().id;
+        let mut system = store.get(id).unwrap().system;
+        system.name = "Second".to_string();
+        store.update(id, system.clone()).unwrap();
+        system.name = "Third".to_string();
+        store.update(id, system.clone()).unwrap();
+
+        assert_eq!(store.undo(id).unwrap().system.name, "Second");
+        assert_eq!(store.undo(id).unwrap().system.name, "Work");
+        assert!(store.undo(id).unwrap_err().to_string().contains("Nothing to undo"));
+        assert_eq!(store.redo(id).unwrap().system.name, "Second");
+        assert_eq!(store.get(id).unwrap().system.name, "Second");
+        assert_eq!(store.history(id).unwrap().revisions.len(), 3);
+
+        // A new edit after an undo replaces the undone revision
+        system.name = "Other".to_string();
+        store.update(id, system).unwrap();
+        let history = store.history(id).unwrap();
+        assert_eq!((history.current, history.revisions.len()), (3, 3));
+        assert_eq!(history.revisions[2].changes, vec![Change::Renamed { from: "Second".to_string(), to: "Other".to_string() }]);
+        assert!(store.redo(id).unwrap_err().to_string().contains("Nothing to redo"));
+        fs::remove_dir_all(store.root()).unwrap();
+    }
+
+    #[test]
+    fn test_undo_and_redo_are_logged() {
+        let store = temp_store("steps").with_author("alice");
+        let id = store.create_document(tetrad()).unwrap().id;
+        let mut system = store.get(id).unwrap().system;
+        system.name = "Second".to_string();
+        store.update(id, system).unwrap();
+
+        store.undo(id).unwrap();
+        let store = store.with_author("bob");
+        store.redo(id).unwrap();
+        let history = store.history(id).unwrap();
+        let steps: Vec<(&str, String)> = history.steps.iter().map(|step| (step.author.as_str(), step.to_string())).collect();
+        assert_eq!(
+            steps,
+            vec![("alice", "undo from revision 2 to 1".to_string()), ("bob", "redo from revision 1 to 2".to_string())]
+        );
+        assert_eq!(history.revisions.len(), 2);
+        fs::remove_dir_all(store.root()).unwrap();
+    }
+
+    #[test]
+    fn test_undo_to_a_deleted_child() {
+        let store = temp_store("stale");
+        let child = store.create(&Triad::new("Will", "A", "P", "R")).unwrap().id;
+        let id = store.create_document(tetrad()).unwrap().id;
+        let mut system = store.get(id).unwrap().system;
+        system.terms[0].child = Some(child);
+        store.update(id, system.clone()).unwrap();
+        system.terms[0].child = None;
+        store.update(id, system).unwrap();
+        store.delete(child).unwrap();
+
+        let error = store.undo(id).unwrap_err();
+        assert_eq!(error.to_string(), "Revision 2 of system 2 nests system 1 at A, which has since been deleted");
+        assert_eq!(store.history(id).unwrap().current, 3);
+        fs::remove_dir_all(store.root()).unwrap();
+    }
+
+    #[test]
+    fn test_undo_and_update_share_the_lock() {
+        let store = temp_store("lock");
+        let id = store.create_document(tetrad()).unwrap().id;
+        let mut system = store.get(id).unwrap().system;
+        system.name = "Second".to_string();
+        store.update(id, system.clone()).unwrap();
+
+        // While an undo holds the lock, neither an update nor another undo gets in
+        let lock = store.lock(id).unwrap();
+        system.name = "Third".to_string();
+        let error = store.update(id, system.clone()).unwrap_err();
+        assert!(error.to_string().starts_with("System 1 is being saved by someone else"), "{}", error);
+        assert!(store.undo(id).is_err());
+        assert!(store.redo(id).is_err());
+        drop(lock);
+
+        assert_eq!(store.undo(id).unwrap().system.name, "Work");
+        store.update(id, system).unwrap();
+        assert_eq!(store.history(id).unwrap().current, 2);
+        assert!(!store.root().join("1.lock").exists());
+        fs::remove_dir_all(store.root()).unwrap();
+    }
+
+    #[test]
+    fn test_systems_saved_before_history() {
+        let store = temp_store("baseline");
+        let id = store.create(&Triad::new("Will", "A", "P", "R")).unwrap().id;
+        store.forget(id).unwrap();
+
+        let history = store.history(id).unwrap();
+        assert_eq!(history.revisions.len(), 1);
+        assert_eq!(history.revisions[0].author, "unknown");
+        let mut system = history.revisions[0].system.clone();
+        system.name = "Free will".to_string();
+        store.update(id, system).unwrap();
+        assert_eq!(store.undo(id).unwrap().system.name, "Will");
+        fs::remove_dir_all(store.root()).unwrap();
+    }
+
+    #[test]
+    fn test_format_timestamp() {
+        assert_eq!(format_timestamp(0), "1970-01-01 00:00 UTC");
+        assert_eq!(format_timestamp(1_700_000_000), "2023-11-14 22:13 UTC");
+        assert_eq!(format_timestamp(951_782_400), "2000-02-29 00:00 UTC");
+    }
+}
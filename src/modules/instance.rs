@@ -0,0 +1,439 @@
+use crate::modules::connective::{Connective, Direction, Position};
+use crate::modules::document::SystemDocument;
+use crate::modules::error::SystematicsError;
+use crate::modules::prompter::{IoPrompter, Prompter};
+use crate::modules::system::System;
+use crate::modules::template::SystemTemplate;
+
+/// A user's system of any order, built from a `SystemTemplate`.
+///
+/// Instances are stored by position, in the same order as the template's terms;
+/// connectives are stored as one `Connective` per pair of positions.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SystemInstance {
+    pub name: String,
+    template: &'static SystemTemplate,
+    instances: Vec<String>,
+    connectives: Vec<Connective>,
+}
+
+impl SystemInstance {
+    /// Creates a new system with canonical terms as instances and canonical connective names
+    pub fn new(template: &'static SystemTemplate, name: &str) -> Self {
+        SystemInstance {
+            name: name.to_string(),
+            template,
+            instances: template.terms.iter().map(|term| term.label.to_string()).collect(),
+            connectives: template
+                .connectives
+                .iter()
+                .map(|connective| Connective::new(connective.from, connective.to, connective.name))
+                .collect(),
+        }
+    }
+
+    /// Creates a new system with user instances for every canonical position
+    pub fn with_instances(
+        template: &'static SystemTemplate,
+        name: &str,
+        instances: &[&str],
+    ) -> Result<Self, SystematicsError> {
+        if instances.len() != template.order {
+            return Err(SystematicsError::Structure(format!(
+                "{} needs {} instances, got {}",
+                template.kind,
+                template.order,
+                instances.len()
+            )));
+        }
+
+        let mut system = SystemInstance::new(template, name);
+        system.instances = instances.iter().map(|instance| instance.to_string()).collect();
+        Ok(system)
+    }
+
+    /// Rebuilds a system from stored instances and connectives, checked against the template
+    pub fn from_parts(
+        template: &'static SystemTemplate,
+        name: &str,
+        instances: Vec<String>,
+        connectives: Vec<Connective>,
+    ) -> Result<Self, SystematicsError> {
+        let mut system = SystemInstance::new(template, name);
+        if instances.len() != template.order {
+            return Err(SystematicsError::Structure(format!(
+                "{} needs {} instances, got {}",
+                template.kind,
+                template.order,
+                instances.len()
+            )));
+        }
+        if connectives.len() != template.connectives.len() {
+            return Err(SystematicsError::Structure(format!(
+                "{} needs {} connectives, got {}",
+                template.kind,
+                template.connectives.len(),
+                connectives.len()
+            )));
+        }
+
+        // Keep the template's connective order whatever order they were stored in
+        for slot in system.connectives.iter_mut() {
+            let stored = connectives
+                .iter()
+                .find(|connective| connective.joins(slot.from, slot.to))
+                .ok_or_else(|| {
+                    SystematicsError::Structure(format!("{} is missing connective {}<>{}", template.kind, slot.from, slot.to))
+                })?;
+            *slot = stored.clone();
+        }
+        system.instances = instances;
+        Ok(system)
+    }
+
+    /// Interactive creation method for any template on the terminal
+    pub fn create_interactive(template: &'static SystemTemplate) -> Result<Self, SystematicsError> {
+        Self::create_with(template, &mut IoPrompter::stdio())
+    }
+
+    /// Interactive creation for any template through any prompter
+    pub fn create_with(
+        template: &'static SystemTemplate,
+        prompter: &mut dyn Prompter,
+    ) -> Result<Self, SystematicsError> {
+        let article = if template.kind.starts_with(['A', 'E', 'I', 'O', 'U']) { "an" } else { "a" };
+        prompter.say(&format!("\n--- Creating {} {} ---", article, template.kind))?;
+
+        // Get inputs using canonical terms
+        let unnamed = format!("Unnamed {}", template.kind);
+        let name = prompter.optional(
+            &format!("Enter a name for your {} (or press Enter for '{}'): ", template.kind, unnamed),
+            &unnamed,
+        )?;
+        let mut system = SystemInstance::new(template, &name);
+
+        for (index, term) in template.terms.iter().enumerate() {
+            system.instances[index] = prompter.optional(
+                &format!("Enter the {} instance (or press Enter for '{}'): ", term.label, term.label),
+                term.label,
+            )?;
+        }
+
+        // Ask if user wants to modify the default connectives
+        if !template.connectives.is_empty()
+            && prompter.yes_no("\nWould you like to modify the default connectives? (y/n): ", true)?
+        {
+            prompter.say("\nModifying connectives (press Enter to keep default, or input new value):")?;
+            if template.connectives.len() > 10 {
+                prompter.say(&format!(
+                    "Note: {} has {} connectives - this may take a moment to review.",
+                    template.kind,
+                    template.connectives.len()
+                ))?;
+            }
+
+            for connective in system.connectives.iter_mut() {
+                if let Some(entry) = template.connective(connective.from.letter(), connective.to.letter()) {
+                    prompter.say(&format!("  {}", entry.description))?;
+                }
+                let prompt = format!("{} ({}): ", connective.canonical_name, connective.code());
+                let label = prompter.optional(&prompt, connective.label())?;
+                if label != connective.canonical_name {
+                    connective.user_label = Some(label);
+                }
+            }
+        }
+
+        // Display the created system
+        prompter.say(&system.details())?;
+
+        // Show connectives if any were defined
+        if system.has_connectives() {
+            prompter.say(&system.connectives_details())?;
+        }
+
+        Ok(system)
+    }
+
+    /// Template this system was built from
+    pub fn template(&self) -> &'static SystemTemplate {
+        self.template
+    }
+
+    /// Number of canonical positions
+    pub fn order(&self) -> usize {
+        self.template.order
+    }
+
+    /// The system formed by some of this one's positions, carrying over their instances
+    /// and connectives; see `SystemDocument::subsystem`.
+    ///
+    /// Returned as a `System` because one to three positions form a Monad, Dyad or
+    /// Triad, which are not built from a template.
+    pub fn subsystem(&self, positions: &[Position]) -> Result<Box<dyn System>, SystematicsError> {
+        SystemDocument::from_system(self).subsystem(positions)?.to_system()
+    }
+
+    /// User instance at a zero-based position index
+    pub fn instance_at(&self, index: usize) -> &str {
+        &self.instances[index]
+    }
+
+    /// User instance at a position letter
+    pub fn instance(&self, position: char) -> Option<&str> {
+        self.template.position_index(position).map(|index| self.instance_at(index))
+    }
+
+    /// Replace the user instance at a position letter
+    pub fn set_instance(&mut self, position: char, value: &str) -> Result<(), SystematicsError> {
+        let index = self
+            .template
+            .position_index(position)
+            .ok_or_else(|| SystematicsError::Structure(format!("{} has no position {}", self.template.kind, position)))?;
+        self.instances[index] = value.to_string();
+        Ok(())
+    }
+
+    /// All user instances in positional order
+    pub fn instances(&self) -> &[String] {
+        &self.instances
+    }
+
+    /// All connectives, in template order
+    pub fn connectives(&self) -> &[Connective] {
+        &self.connectives
+    }
+
+    /// Mutable connective joining two positions, in either direction
+    pub fn connective_mut(&mut self, from: char, to: char) -> Option<&mut Connective> {
+        let (from, to) = (Position::from(from), Position::from(to));
+        self.connectives.iter_mut().find(|connective| connective.joins(from, to))
+    }
+
+    /// Label shown for the connective joining two positions, in either direction
+    pub fn connective_label(&self, from: char, to: char) -> Option<&str> {
+        self.connective(from, to).map(|connective| connective.label())
+    }
+
+    /// Catalogue description of the connective joining two positions
+    pub fn connective_description(&self, from: char, to: char) -> Option<&'static str> {
+        self.template.connective(from, to).map(|entry| entry.description)
+    }
+
+    /// Set the user label of the connective joining two positions (None restores the canonical name)
+    pub fn set_connective_label(
+        &mut self,
+        from: char,
+        to: char,
+        label: Option<String>,
+    ) -> Result<(), SystematicsError> {
+        let kind = self.template.kind;
+        let connective = self
+            .connective_mut(from, to)
+            .ok_or_else(|| SystematicsError::Structure(format!("{} has no connective {}<>{}", kind, from, to)))?;
+        connective.user_label = label;
+        Ok(())
+    }
+
+    /// Check if any connectives are defined
+    pub fn has_connectives(&self) -> bool {
+        !self.connectives.is_empty()
+    }
+
+    /// Formatted system details
+    pub fn details(&self) -> String {
+        let mut text = format!(
+            "\n--- {} Details ---\n{} Name: {}\nCore Attribute: {}\n",
+            self.template.kind, self.template.kind, self.name, self.template.attribute_description
+        );
+        for (term, instance) in self.template.terms.iter().zip(&self.instances) {
+            text.push_str(&format!("{} ({}): {}\n", term.position, term.label, instance));
+        }
+        text.push_str("---------------------");
+        text
+    }
+
+    /// Display system details
+    pub fn display(&self) {
+        println!("{}", self.details());
+    }
+
+    /// Formatted list of all connectives
+    pub fn connectives_details(&self) -> String {
+        let mut text = String::from("\nConnectives:");
+        for connective in &self.connectives {
+            let from = self.instance(connective.from.letter()).unwrap_or_default();
+            let to = self.instance(connective.to.letter()).unwrap_or_default();
+            let (left, right) = match connective.direction {
+                Direction::Bidirectional => ("<--", "-->"),
+                Direction::Forward => ("--", "-->"),
+                Direction::Backward => ("<--", "--"),
+            };
+            text.push_str(&format!(
+                "\n  {} {}[{}]{} {} ({})",
+                from,
+                left,
+                connective.label(),
+                right,
+                to,
+                connective.code()
+            ));
+            if connective.is_relabelled() {
+                text.push_str(&format!("\n      Canonical: {}", connective.canonical_name));
+            }
+            if let Some(description) = self.connective_description(connective.from.letter(), connective.to.letter()) {
+                text.push_str(&format!("\n      {}", description));
+            }
+            if let Some(notes) = &connective.notes {
+                text.push_str(&format!("\n      {}", notes));
+            }
+        }
+        text
+    }
+
+    /// Display all connectives
+    pub fn display_connectives(&self) {
+        println!("{}", self.connectives_details());
+    }
+}
+
+impl System for SystemInstance {
+    fn kind(&self) -> &'static str {
+        self.template.kind
+    }
+
+    fn order(&self) -> usize {
+        self.template.order
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn canonical_terms(&self) -> Vec<&'static str> {
+        self.template.canonical_terms()
+    }
+
+    fn instances(&self) -> Vec<String> {
+        self.instances.clone()
+    }
+
+    fn connectives(&self) -> &[Connective] {
+        &self.connectives
+    }
+
+    fn attribute_description(&self) -> &'static str {
+        self.template.attribute_description
+    }
+
+    fn display(&self) {
+        SystemInstance::display(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::template;
+
+    #[test]
+    fn test_new_uses_canonical_defaults() {
+        let system = SystemInstance::new(template::for_order(6).unwrap(), "Test");
+
+        assert_eq!(system.name, "Test");
+        assert_eq!(system.order(), 6);
+        assert_eq!(system.instances(), ["Resources", "Values", "Options", "Criteria", "Facts", "Priorities"]);
+        assert!(system.has_connectives());
+        assert_eq!(system.connectives().len(), 15);
+    }
+
+    #[test]
+    fn test_subsystem() {
+        let octad = SystemInstance::with_instances(template::for_order(8).unwrap(), "Firm", &["A", "B", "C", "D", "E", "F", "G", "H"])
+            .unwrap();
+        let positions: Vec<Position> = "BDFH".chars().map(Position::new).collect();
+        let tetrad = octad.subsystem(&positions).unwrap();
+
+        assert_eq!(tetrad.kind(), "Tetrad");
+        assert_eq!(tetrad.instances(), ["B", "D", "F", "H"]);
+        assert_eq!(tetrad.connectives().len(), 6);
+
+        let dyad = octad.subsystem(&positions[..2]).unwrap();
+        assert_eq!(dyad.kind(), "Dyad");
+        assert_eq!(dyad.instances(), ["B", "D"]);
+        assert_eq!(octad.subsystem(&positions[..1]).unwrap().kind(), "Monad");
+        assert_eq!(octad.subsystem(&positions[..3]).unwrap().kind(), "Triad");
+        assert!(octad.subsystem(&[]).is_err());
+    }
+
+    #[test]
+    fn test_with_instances() {
+        let template = template::for_order(7).unwrap();
+        let system = SystemInstance::with_instances(template, "Test", &["A", "B", "C", "D", "E", "F", "G"]).unwrap();
+
+        assert_eq!(system.instance('A'), Some("A"));
+        assert_eq!(system.instance('g'), Some("G"));
+        assert_eq!(system.instance('H'), None);
+        assert_eq!(system.instance_at(3), "D");
+    }
+
+    #[test]
+    fn test_with_instances_wrong_count() {
+        let template = template::for_order(8).unwrap();
+        let result = SystemInstance::with_instances(template, "Test", &["A", "B"]);
+
+        assert!(result.is_err());
+        assert_eq!(result.unwrap_err().to_string(), "Octad needs 8 instances, got 2");
+    }
+
+    #[test]
+    fn test_set_instance() {
+        let mut system = SystemInstance::new(template::for_order(6).unwrap(), "Test");
+
+        system.set_instance('C', "Choices").unwrap();
+        assert_eq!(system.instance('C'), Some("Choices"));
+        assert!(system.set_instance('Z', "Nowhere").is_err());
+    }
+
+    #[test]
+    fn test_connective_labels() {
+        let mut system = SystemInstance::new(template::for_order(8).unwrap(), "Test");
+
+        system.set_connective_label('C', 'A', Some("custom".to_string())).unwrap();
+        assert_eq!(system.connective_label('A', 'C'), Some("custom"));
+        assert_eq!(system.connective_label('C', 'A'), Some("custom"));
+
+        // Clearing the user label restores the canonical name
+        system.set_connective_label('A', 'C', None).unwrap();
+        assert_eq!(system.connective_label('A', 'C'), Some("Grounding"));
+        assert!(system.set_connective_label('A', 'A', None).is_err());
+    }
+
+    #[test]
+    fn test_connective_lookup() {
+        let mut system = SystemInstance::new(template::for_order(6).unwrap(), "Test");
+
+        let connective = system.connective('D', 'B').unwrap();
+        assert_eq!(connective.code(), "B<>D");
+        assert_eq!(connective.canonical_name, "Standards");
+        assert_eq!(system.connective_description('D', 'B'), Some("Values made explicit as criteria"));
+
+        let connective = system.connective_mut('B', 'D').unwrap();
+        connective.direction = Direction::Forward;
+        connective.notes = Some("Values shape the criteria".to_string());
+
+        let connective = system.connective('B', 'D').unwrap();
+        assert_eq!(connective.code(), "B->D");
+        assert_eq!(connective.notes.as_deref(), Some("Values shape the criteria"));
+    }
+
+    #[test]
+    fn test_every_template_builds() {
+        for template in template::all() {
+            let system = SystemInstance::new(template, "Test");
+            assert_eq!(system.instances().len(), template.order);
+            assert_eq!(system.canonical_terms(), template.canonical_terms());
+            assert_eq!(system.attribute_description(), template.attribute_description);
+        }
+    }
+}
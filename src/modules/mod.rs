@@ -1,3 +1,23 @@
+pub mod system;
+pub mod connective;
+pub mod error;
+pub mod validation;
+pub mod prompter;
+pub mod template;
+pub mod instance;
+pub mod document;
+pub mod authoring;
+pub mod bulk;
+pub mod subsystem;
+pub mod store;
+pub mod session;
+pub mod history;
+pub mod diff;
+pub mod hierarchy;
+pub mod graph;
+pub mod diagram;
+pub mod report;
+pub mod cli;
 pub mod monad;
 pub mod dyad;
 pub mod triad;
@@ -6,5 +26,8 @@ pub mod pentad;
 pub mod hexad;
 pub mod heptad;
 pub mod octad;
+pub mod ennead;
+pub mod decad;
+pub mod undecad;
 pub mod dodecad;
-pub mod permutations; 
\ No newline at end of file
+pub mod permutations; 
@@ -1,6 +1,9 @@
-use std::io::{self, Write};
+use crate::modules::connective::{Connective, Position};
+use crate::modules::error::SystematicsError;
+use crate::modules::prompter::{IoPrompter, Prompter};
+use crate::modules::system::System;
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Monad {
     pub name: String,
     pub terms: Vec<String>,
@@ -9,6 +12,9 @@ pub struct Monad {
 impl Monad {
     pub const TERM_ATTRIBUTE_DESCRIPTION: &'static str = "Unity in diversity and diversity in unity";
 
+    /// Most terms a Monad holds, one per position letter A to Z
+    pub const MAX_TERMS: usize = Position::COUNT;
+
     /// Creates a new monad
     pub fn new(name: &str) -> Self {
         Monad {
@@ -17,110 +23,47 @@ impl Monad {
         }
     }
 
-    /// Interactive creation method - handles all input/output internally
-    pub fn create_interactive() -> Result<Self, Box<dyn std::error::Error>> {
-        println!("\n--- Creating a Monad ---");
-        
-        // Helper for optional input with default
-        let get_optional_input = |prompt: &str, default: &str| -> Result<String, Box<dyn std::error::Error>> {
-            let mut input = String::new();
-            print!("{}", prompt);
-            
-            if let Err(e) = io::stdout().flush() {
-                eprintln!("Warning: Could not flush output: {}", e);
-            }
-            
-            match io::stdin().read_line(&mut input) {
-                Ok(_) => {
-                    let trimmed = input.trim();
-                    
-                    if trimmed.is_empty() {
-                        Ok(default.to_string())
-                    } else {
-                        // Validate optional input if provided
-                        if trimmed.len() > 100 {
-                            return Err("Input is too long (max 100 characters)".into());
-                        }
-                        
-                        if !trimmed.chars().all(|c| c.is_alphanumeric() || c.is_whitespace() || ".,!?'-()".contains(c)) {
-                            return Err("Input contains invalid characters".into());
-                        }
-                        
-                        Ok(trimmed.to_string())
-                    }
-                }
-                Err(e) => {
-                    eprintln!("Error reading input: {}. Using default: {}", e, default);
-                    Ok(default.to_string())
-                }
-            }
-        };
-
-        // Helper for term input with validation
-        let get_term_input = |prompt: &str| -> Result<Option<String>, Box<dyn std::error::Error>> {
-            let mut input = String::new();
-            print!("{}", prompt);
-            
-            if let Err(e) = io::stdout().flush() {
-                eprintln!("Warning: Could not flush output: {}", e);
-            }
-            
-            match io::stdin().read_line(&mut input) {
-                Ok(_) => {
-                    let trimmed = input.trim();
-                    
-                    if trimmed.is_empty() {
-                        Ok(None) // Empty input signals end of term entry
-                    } else {
-                        // Validate term input
-                        if trimmed.len() > 100 {
-                            return Err("Term is too long (max 100 characters)".into());
-                        }
-                        
-                        if !trimmed.chars().all(|c| c.is_alphanumeric() || c.is_whitespace() || ".,!?'-()".contains(c)) {
-                            return Err("Term contains invalid characters".into());
-                        }
-                        
-                        Ok(Some(trimmed.to_string()))
-                    }
-                }
-                Err(e) => Err(format!("Error reading term: {}", e).into())
-            }
-        };
+    /// Interactive creation method on the terminal
+    pub fn create_interactive() -> Result<Self, SystematicsError> {
+        Self::create_with(&mut IoPrompter::stdio())
+    }
+
+    /// Interactive creation through any prompter
+    pub fn create_with(prompter: &mut dyn Prompter) -> Result<Self, SystematicsError> {
+        prompter.say("\n--- Creating a Monad ---")?;
 
         // Get monad name
-        let name = get_optional_input("Enter a name for your Monad (or press Enter for 'Unnamed Monad'): ", "Unnamed Monad")?;
+        let name = prompter.optional("Enter a name for your Monad (or press Enter for 'Unnamed Monad'): ", "Unnamed Monad")?;
 
         let mut monad = Monad::new(&name);
 
-        // Get terms for the monad
-        println!("\nEnter terms for \"{}\". Press Enter on an empty line when done.", monad.name);
-        loop {
-            match get_term_input("Term: ") {
-                Ok(Some(term)) => {
-                    monad.add_term(&term);
-                }
-                Ok(None) => {
-                    break; // Empty input, user is done
-                }
-                Err(e) => {
-                    eprintln!("Error: {}. Please try again.", e);
-                    continue;
-                }
+        // Get terms for the monad until an empty line
+        prompter.say(&format!("\nEnter terms for \"{}\". Press Enter on an empty line when done.", monad.name))?;
+        while let Some(term) = prompter.next_entry("Term: ", "Term")? {
+            monad.add_term(&term)?;
+            if monad.terms.len() == Self::MAX_TERMS {
+                prompter.say(&format!("A Monad holds at most {} terms.", Self::MAX_TERMS))?;
+                break;
             }
         }
 
         // Display the created monad
-        monad.display();
-        
+        prompter.say(&monad.details())?;
+
         Ok(monad)
     }
 
-    /// Adds a term to the monad vector with validation
-    pub fn add_term(&mut self, term: &str) {
-        if !term.is_empty() {
-            self.terms.push(term.to_string());
+    /// Adds a term to the monad vector with validation; empty terms are skipped
+    /// and a full Monad is an error
+    pub fn add_term(&mut self, term: &str) -> Result<(), SystematicsError> {
+        if term.is_empty() {
+            return Ok(());
+        }
+        if self.terms.len() >= Self::MAX_TERMS {
+            return Err(SystematicsError::Structure(format!("A Monad holds at most {} terms", Self::MAX_TERMS)));
         }
+        self.terms.push(term.to_string());
+        Ok(())
     }
 
     /// Retrieves all terms associated with the Monad vector.
@@ -140,27 +83,71 @@ impl Monad {
         self.terms.len()
     }
 
-    /// Display the monad in a formatted way
-    pub fn display(&self) {
-        println!("\n--- Monad Details ---");
-        println!("Monad Name: {}", self.name);
-        println!("Core Attribute: {}", Self::TERM_ATTRIBUTE_DESCRIPTION);
+    /// Formatted details of the monad
+    pub fn details(&self) -> String {
+        let mut text = format!(
+            "\n--- Monad Details ---\nMonad Name: {}\nCore Attribute: {}\n",
+            self.name,
+            Self::TERM_ATTRIBUTE_DESCRIPTION
+        );
         let terms = self.get_all_terms();
         if !terms.is_empty() {
-            println!("User-defined Terms:");
+            text.push_str("User-defined Terms:\n");
             for term in terms {
-                println!("- {}", term);
+                text.push_str(&format!("- {}\n", term));
             }
         } else {
-            println!("No user-defined terms were added.");
+            text.push_str("No user-defined terms were added.\n");
         }
-        println!("---------------------");
+        text.push_str("---------------------");
+        text
+    }
+
+    /// Display the monad in a formatted way
+    pub fn display(&self) {
+        println!("{}", self.details());
+    }
+}
+
+impl System for Monad {
+    fn kind(&self) -> &'static str {
+        "Monad"
+    }
+
+    fn order(&self) -> usize {
+        1
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The Monad has no fixed positions; its instances are its user-defined terms
+    fn canonical_terms(&self) -> Vec<&'static str> {
+        Vec::new()
+    }
+
+    fn instances(&self) -> Vec<String> {
+        self.terms.clone()
+    }
+
+    fn connectives(&self) -> &[Connective] {
+        &[]
+    }
+
+    fn attribute_description(&self) -> &'static str {
+        Self::TERM_ATTRIBUTE_DESCRIPTION
+    }
+
+    fn display(&self) {
+        Monad::display(self)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::modules::prompter::ScriptedPrompter;
 
     #[test]
     fn test_monad_creation() {
@@ -183,8 +170,8 @@ mod tests {
     fn test_add_term() {
         let mut monad = Monad::new("Test");
         
-        monad.add_term("Unity");
-        monad.add_term("Consciousness");
+        monad.add_term("Unity").unwrap();
+        monad.add_term("Consciousness").unwrap();
         
         assert_eq!(monad.terms.len(), 2);
         assert_eq!(monad.terms[0], "Unity");
@@ -192,12 +179,24 @@ mod tests {
         assert!(monad.has_terms());
     }
 
+    #[test]
+    fn test_add_term_limit() {
+        let mut monad = Monad::new("Test");
+        for index in 0..Monad::MAX_TERMS {
+            monad.add_term(&format!("Term {}", index)).unwrap();
+        }
+
+        let error = monad.add_term("One too many").unwrap_err();
+        assert_eq!(error.to_string(), "A Monad holds at most 26 terms");
+        assert_eq!(monad.term_count(), 26);
+    }
+
     #[test]
     fn test_add_empty_term() {
         let mut monad = Monad::new("Test");
         
-        monad.add_term(""); // Should not add empty terms
-        monad.add_term("Valid Term");
+        monad.add_term("").unwrap(); // Should not add empty terms
+        monad.add_term("Valid Term").unwrap();
         
         assert_eq!(monad.terms.len(), 1);
         assert_eq!(monad.terms[0], "Valid Term");
@@ -206,8 +205,8 @@ mod tests {
     #[test]
     fn test_get_all_terms() {
         let mut monad = Monad::new("Test");
-        monad.add_term("First");
-        monad.add_term("Second");
+        monad.add_term("First").unwrap();
+        monad.add_term("Second").unwrap();
         
         let terms = monad.get_all_terms();
         assert_eq!(terms.len(), 2);
@@ -220,7 +219,7 @@ mod tests {
         let mut monad = Monad::new("Test");
         assert!(!monad.has_terms());
         
-        monad.add_term("Some term");
+        monad.add_term("Some term").unwrap();
         assert!(monad.has_terms());
     }
 
@@ -229,10 +228,10 @@ mod tests {
         let mut monad = Monad::new("Test");
         assert_eq!(monad.term_count(), 0);
         
-        monad.add_term("First");
+        monad.add_term("First").unwrap();
         assert_eq!(monad.term_count(), 1);
         
-        monad.add_term("Second");
+        monad.add_term("Second").unwrap();
         assert_eq!(monad.term_count(), 2);
     }
 
@@ -244,8 +243,8 @@ mod tests {
     #[test]
     fn test_monad_with_special_characters() {
         let mut monad = Monad::new("Test-Monad!");
-        monad.add_term("Unity (Primary)");
-        monad.add_term("Consciousness, Universal");
+        monad.add_term("Unity (Primary)").unwrap();
+        monad.add_term("Consciousness, Universal").unwrap();
         
         assert_eq!(monad.name, "Test-Monad!");
         assert_eq!(monad.terms[0], "Unity (Primary)");
@@ -255,8 +254,8 @@ mod tests {
     #[test]
     fn test_monad_with_unicode() {
         let mut monad = Monad::new("测试");
-        monad.add_term("统一体");
-        monad.add_term("意识");
+        monad.add_term("统一体").unwrap();
+        monad.add_term("意识").unwrap();
         
         assert_eq!(monad.name, "测试");
         assert_eq!(monad.terms[0], "统一体");
@@ -266,14 +265,14 @@ mod tests {
     #[test]
     fn test_monad_clone_behavior() {
         let mut original = Monad::new("Original");
-        original.add_term("Original Term");
+        original.add_term("Original Term").unwrap();
         
         let mut cloned = Monad::new(&original.name);
         for term in original.get_all_terms() {
-            cloned.add_term(term);
+            cloned.add_term(term).unwrap();
         }
         
-        cloned.add_term("New Term");
+        cloned.add_term("New Term").unwrap();
         
         // Original should be unchanged
         assert_eq!(original.terms.len(), 1);
@@ -288,7 +287,7 @@ mod tests {
     #[test]
     fn test_monad_debug_format() {
         let mut monad = Monad::new("Debug Test");
-        monad.add_term("Debug Term");
+        monad.add_term("Debug Term").unwrap();
         
         let debug_str = format!("{:?}", monad);
         
@@ -302,7 +301,7 @@ mod tests {
         
         let terms = vec!["Alpha", "Beta", "Gamma", "Delta"];
         for term in &terms {
-            monad.add_term(term);
+            monad.add_term(term).unwrap();
         }
         
         assert_eq!(monad.term_count(), 4);
@@ -314,9 +313,9 @@ mod tests {
     #[test]
     fn test_empty_string_handling() {
         let mut monad = Monad::new("");
-        monad.add_term("");
-        monad.add_term("Valid");
-        monad.add_term("");
+        monad.add_term("").unwrap();
+        monad.add_term("Valid").unwrap();
+        monad.add_term("").unwrap();
         
         assert_eq!(monad.name, "");
         assert_eq!(monad.term_count(), 1);
@@ -327,13 +326,25 @@ mod tests {
     fn test_term_order_preservation() {
         let mut monad = Monad::new("Order Test");
         
-        monad.add_term("First");
-        monad.add_term("Second");
-        monad.add_term("Third");
+        monad.add_term("First").unwrap();
+        monad.add_term("Second").unwrap();
+        monad.add_term("Third").unwrap();
         
         let terms = monad.get_all_terms();
         assert_eq!(terms[0], "First");
         assert_eq!(terms[1], "Second");
         assert_eq!(terms[2], "Third");
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_create_with_scripted_session() {
+        let mut prompter = ScriptedPrompter::new(&["Self", "Body", "Mind\tBody", "Mind", ""]);
+        let monad = Monad::create_with(&mut prompter).unwrap();
+
+        assert_eq!(monad.name, "Self");
+        assert_eq!(monad.terms, vec!["Body", "Mind"]);
+        let transcript = prompter.transcript();
+        assert!(transcript.contains("Error: Term contains invalid character \\u{9}. Please try again."));
+        assert!(transcript.contains("User-defined Terms:\n- Body\n- Mind\n"));
+    }
+}
@@ -1,109 +1,102 @@
-use std::io::{self, Write};
+use serde::{Deserialize, Serialize};
+
+use crate::modules::error::SystematicsError;
+use crate::modules::prompter::{IoPrompter, Prompter};
+
+/// The six laws: each name with the roles (1, 2, 3) placed in the initiating, colouring and outcome slots
+pub const SIX_LAWS: [(&str, [usize; 3]); 6] = [
+    ("Expansion", [1, 2, 3]),
+    ("Interaction", [1, 3, 2]),
+    ("Concentration", [2, 1, 3]),
+    ("Identity", [2, 3, 1]),
+    ("Order", [3, 1, 2]),
+    ("Freedom", [3, 2, 1]),
+];
 
 /// Represents a single permutation of three terms in sequence
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Permutation {
     pub name: String,
     pub initiating: String,
     pub colouring: String,
     pub outcome: String,
+    /// Role (1, 2 or 3) of the term in the initiating, colouring and outcome slots
+    pub roles: [usize; 3],
 }
 
 impl Permutation {
+    /// Permutation whose terms are given in role order (1, 2, 3)
     pub fn new(name: &str, initiating: &str, colouring: &str, outcome: &str) -> Self {
         Permutation {
             name: name.to_string(),
             initiating: initiating.to_string(),
             colouring: colouring.to_string(),
             outcome: outcome.to_string(),
+            roles: [1, 2, 3],
         }
     }
 
+    /// Sets the role of the term in each slot
+    pub fn with_roles(mut self, roles: [usize; 3]) -> Self {
+        self.roles = roles;
+        self
+    }
+
+    /// Roles as digits in slot order, e.g. "231" for Identity
+    pub fn code(&self) -> String {
+        self.roles.iter().map(|role| role.to_string()).collect()
+    }
+
     /// Display the permutation as "Name: Initiating → Colouring → Outcome"
     pub fn display(&self) -> String {
         format!("{}: {} → {} → {}", self.name, self.initiating, self.colouring, self.outcome)
     }
 }
 
-/// Generate all six permutations of three terms
+/// Generate all six permutations of three terms, given as roles 1, 2 and 3
 pub fn generate_six_permutations(term_a: &str, term_b: &str, term_c: &str) -> Vec<Permutation> {
-    vec![
-        Permutation::new("Expansion", term_a, term_b, term_c),     // term_1 > term_2 > term_3 (123)
-        Permutation::new("Interaction", term_a, term_c, term_b),   // term_1 > term_3 > term_2 (132)
-        Permutation::new("Concentration", term_b, term_a, term_c), // term_2 > term_1 > term_3 (213)
-        Permutation::new("Identity", term_b, term_c, term_a),      // term_2 > term_3 > term_1 (231)
-        Permutation::new("Order", term_c, term_a, term_b),         // term_3 > term_1 > term_2 (312)
-        Permutation::new("Freedom", term_c, term_b, term_a),       // term_3 > term_2 > term_1 (321)
-    ]
+    let terms = [term_a, term_b, term_c];
+    SIX_LAWS
+        .iter()
+        .map(|(name, roles)| {
+            let [initiating, colouring, outcome] = roles.map(|role| terms[role - 1]);
+            Permutation::new(name, initiating, colouring, outcome).with_roles(*roles)
+        })
+        .collect()
 }
 
-/// Interactive permutation generator - gets three terms from user and displays all permutations
-pub fn create_interactive() -> Result<Vec<Permutation>, Box<dyn std::error::Error>> {
-    println!("\n--- Six Permutations Generator ---");
-    
-    // Helper for input with validation
-    let get_term_input = |prompt: &str, field_name: &str| -> Result<String, Box<dyn std::error::Error>> {
-        loop {
-            let mut input = String::new();
-            print!("{}", prompt);
-            
-            if let Err(e) = io::stdout().flush() {
-                return Err(format!("Could not flush output: {}", e).into());
-            }
-            
-            match io::stdin().read_line(&mut input) {
-                Ok(_) => {
-                    let trimmed = input.trim();
-                    
-                    // Check for empty input - prompt again
-                    if trimmed.is_empty() {
-                        println!("{} is required. Please enter a value.", field_name);
-                        continue;
-                    }
-                    
-                    // Check for reasonable length (1-100 characters)
-                    if trimmed.len() > 100 {
-                        println!("{} is too long (max 100 characters). Please try again.", field_name);
-                        continue;
-                    }
-                    
-                    // Check for valid characters (letters, numbers, spaces, basic punctuation)
-                    if !trimmed.chars().all(|c| c.is_alphanumeric() || c.is_whitespace() || ".,!?'-()".contains(c)) {
-                        println!("{} contains invalid characters. Please use only letters, numbers, spaces, and basic punctuation.", field_name);
-                        continue;
-                    }
-                    
-                    return Ok(trimmed.to_string());
-                }
-                Err(e) => return Err(format!("Error reading {}: {}", field_name, e).into())
-            }
-        }
-    };
-    
+/// Interactive permutation generator on the terminal
+pub fn create_interactive() -> Result<Vec<Permutation>, SystematicsError> {
+    create_with(&mut IoPrompter::stdio())
+}
+
+/// Interactive permutation generator - gets three terms through any prompter and shows all permutations
+pub fn create_with(prompter: &mut dyn Prompter) -> Result<Vec<Permutation>, SystematicsError> {
+    prompter.say("\n--- Six Permutations Generator ---")?;
+
     // Get the three terms
-    let term_1 = get_term_input("Enter initiating term: ", "Initiating term")?;
-    let term_2 = get_term_input("Enter colouring term: ", "Colouring term")?;
-    let term_3 = get_term_input("Enter outcome term: ", "Outcome term")?;
-    
+    let term_1 = prompter.required("Enter initiating term: ", "Initiating term")?;
+    let term_2 = prompter.required("Enter colouring term: ", "Colouring term")?;
+    let term_3 = prompter.required("Enter outcome term: ", "Outcome term")?;
+
     // Generate permutations
     let permutations = generate_six_permutations(&term_1, &term_2, &term_3);
-    
+
     // Display results
-    println!("\n--- Six Permutations ---");
-    println!("For terms: '{}', '{}', '{}'", term_1, term_2, term_3);
-    println!();
-    
+    prompter.say("\n--- Six Permutations ---")?;
+    prompter.say(&format!("For terms: '{}', '{}', '{}'\n", term_1, term_2, term_3))?;
     for (i, perm) in permutations.iter().enumerate() {
-        println!("{}. {}", i + 1, perm.display());
+        prompter.say(&format!("{}. {}", i + 1, perm.display()))?;
     }
-    println!("------------------------");
-    
+    prompter.say("------------------------")?;
+
     Ok(permutations)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::modules::prompter::ScriptedPrompter;
 
     #[test]
     fn test_permutation_creation() {
@@ -138,6 +131,25 @@ mod tests {
         assert!(displays.contains(&"Freedom: C → B → A".to_string()));
     }
 
+    #[test]
+    fn test_roles_follow_the_six_laws() {
+        let perms = generate_six_permutations("A", "B", "C");
+        let codes: Vec<String> = perms.iter().map(|p| p.code()).collect();
+
+        assert_eq!(codes, ["123", "132", "213", "231", "312", "321"]);
+        assert_eq!(perms[3].roles, [2, 3, 1]);
+        assert_eq!(Permutation::new("Test", "A", "B", "C").code(), "123");
+    }
+
+    #[test]
+    fn test_permutation_json_round_trip() {
+        let perm = generate_six_permutations("Sun", "Moon", "Earth").remove(4);
+        let json = serde_json::to_string(&perm).unwrap();
+
+        assert!(json.contains(r#""roles":[3,1,2]"#), "{}", json);
+        assert_eq!(serde_json::from_str::<Permutation>(&json).unwrap(), perm);
+    }
+
     #[test]
     fn test_permutation_uniqueness() {
         let perms = generate_six_permutations("X", "Y", "Z");
@@ -195,4 +207,15 @@ mod tests {
         assert!(debug_str.contains("Test"));
         assert!(debug_str.contains("Format"));
     }
-} 
\ No newline at end of file
+
+    #[test]
+    fn test_create_with_scripted_session() {
+        let mut prompter = ScriptedPrompter::new(&["Sun", "", "Moon", "Earth"]);
+        let permutations = create_with(&mut prompter).unwrap();
+
+        assert_eq!(permutations.len(), 6);
+        let transcript = prompter.transcript();
+        assert!(transcript.contains("Colouring term is required. Please enter a value."));
+        assert!(transcript.contains("6. Freedom: Earth → Moon → Sun"));
+    }
+}
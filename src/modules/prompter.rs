@@ -0,0 +1,268 @@
+use std::io::{self, BufRead, Cursor, Write};
+use std::sync::LazyLock;
+
+use crate::modules::error::SystematicsError;
+use crate::modules::validation::{ValidationError, ValidationPolicy};
+
+/// Policy used by prompters that don't carry their own
+static DEFAULT_POLICY: LazyLock<ValidationPolicy> = LazyLock::new(ValidationPolicy::default);
+
+/// Question-and-answer interface shared by every interactive flow.
+///
+/// Creation sessions are written against this trait rather than stdin and
+/// stdout, so they can be driven from tests or embedded in other front ends.
+pub trait Prompter {
+    /// Shows a line of output
+    fn say(&mut self, text: &str) -> Result<(), SystematicsError>;
+
+    /// Asks a question and returns the raw answer, or None once input has ended
+    fn ask(&mut self, prompt: &str) -> Result<Option<String>, SystematicsError>;
+
+    /// Rules answers are cleaned and checked against
+    fn policy(&self) -> &ValidationPolicy {
+        &DEFAULT_POLICY
+    }
+
+    /// Asks for a value, returning the default when the answer is blank
+    fn optional(&mut self, prompt: &str, default: &str) -> Result<String, SystematicsError> {
+        match self.ask(prompt)? {
+            Some(answer) if !answer.trim().is_empty() => {
+                Ok(self.policy().validate(&answer).map_err(|reason| SystematicsError::validation("Input", reason))?)
+            }
+            _ => Ok(default.to_string()),
+        }
+    }
+
+    /// Asks for a value until a valid, non-blank one is given
+    fn required(&mut self, prompt: &str, field_name: &str) -> Result<String, SystematicsError> {
+        loop {
+            let answer = self
+                .ask(prompt)?
+                .ok_or_else(|| SystematicsError::Cancelled { field: field_name.to_string() })?;
+
+            match self.policy().validate(&answer) {
+                Ok(answer) => return Ok(answer),
+                Err(ValidationError::Empty) => {
+                    self.say(&format!("{} is required. Please enter a value.", field_name))?;
+                }
+                Err(reason) => self.say(&format!("{} {}. Please try again.", field_name, reason))?,
+            }
+        }
+    }
+
+    /// Asks for one entry of a list: None when the answer is blank or input has ended
+    fn next_entry(&mut self, prompt: &str, field_name: &str) -> Result<Option<String>, SystematicsError> {
+        loop {
+            let answer = match self.ask(prompt)? {
+                Some(answer) if !answer.trim().is_empty() => answer,
+                _ => return Ok(None),
+            };
+
+            match self.policy().validate(&answer) {
+                Ok(answer) => return Ok(Some(answer)),
+                Err(reason) => self.say(&format!("Error: {} {}. Please try again.", field_name, reason))?,
+            }
+        }
+    }
+
+    /// Asks a yes/no question until it gets y, yes, n or no (blank takes the default)
+    fn yes_no(&mut self, prompt: &str, default: bool) -> Result<bool, SystematicsError> {
+        loop {
+            let answer = match self.ask(prompt)? {
+                Some(answer) => answer.trim().to_lowercase(),
+                None => return Ok(default),
+            };
+
+            match answer.as_str() {
+                "" => return Ok(default),
+                "y" | "yes" => return Ok(true),
+                "n" | "no" => return Ok(false),
+                _ => {
+                    let default = if default { "y" } else { "n" };
+                    self.say(&format!("Please enter 'y' or 'n' (or press Enter for default: {})", default))?;
+                }
+            }
+        }
+    }
+}
+
+/// Prompter over any reader and writer
+pub struct IoPrompter<R: BufRead, W: Write> {
+    input: R,
+    output: W,
+    policy: ValidationPolicy,
+}
+
+impl<R: BufRead, W: Write> IoPrompter<R, W> {
+    pub fn new(input: R, output: W) -> Self {
+        IoPrompter {
+            input,
+            output,
+            policy: ValidationPolicy::default(),
+        }
+    }
+
+    /// Checks answers against the given policy instead of the default
+    pub fn with_policy(mut self, policy: ValidationPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Gives back the writer, e.g. to inspect what was shown
+    pub fn into_output(self) -> W {
+        self.output
+    }
+}
+
+impl IoPrompter<io::StdinLock<'static>, io::Stdout> {
+    /// Prompter on the terminal
+    pub fn stdio() -> Self {
+        IoPrompter::new(io::stdin().lock(), io::stdout())
+    }
+}
+
+impl<R: BufRead, W: Write> Prompter for IoPrompter<R, W> {
+    fn say(&mut self, text: &str) -> Result<(), SystematicsError> {
+        writeln!(self.output, "{}", text)?;
+        Ok(())
+    }
+
+    fn ask(&mut self, prompt: &str) -> Result<Option<String>, SystematicsError> {
+        write!(self.output, "{}", prompt)?;
+        self.output.flush()?;
+
+        let mut answer = String::new();
+        if self.input.read_line(&mut answer)? == 0 {
+            return Ok(None);
+        }
+        Ok(Some(answer.trim_end_matches(['\r', '\n']).to_string()))
+    }
+
+    fn policy(&self) -> &ValidationPolicy {
+        &self.policy
+    }
+}
+
+/// Prompter that answers from a fixed script and records everything shown
+pub struct ScriptedPrompter {
+    inner: IoPrompter<Cursor<Vec<u8>>, Vec<u8>>,
+}
+
+impl ScriptedPrompter {
+    /// One answer per line; once the script runs out, input has ended
+    pub fn new(answers: &[&str]) -> Self {
+        let mut script = answers.join("\n");
+        script.push('\n');
+        ScriptedPrompter {
+            inner: IoPrompter::new(Cursor::new(script.into_bytes()), Vec::new()),
+        }
+    }
+
+    /// Checks answers against the given policy instead of the default
+    pub fn with_policy(mut self, policy: ValidationPolicy) -> Self {
+        self.inner.policy = policy;
+        self
+    }
+
+    /// Everything shown so far: prompts and output
+    pub fn transcript(&self) -> String {
+        String::from_utf8_lossy(&self.inner.output).into_owned()
+    }
+}
+
+impl Prompter for ScriptedPrompter {
+    fn say(&mut self, text: &str) -> Result<(), SystematicsError> {
+        self.inner.say(text)
+    }
+
+    fn ask(&mut self, prompt: &str) -> Result<Option<String>, SystematicsError> {
+        self.inner.ask(prompt)
+    }
+
+    fn policy(&self) -> &ValidationPolicy {
+        self.inner.policy()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_optional_uses_default_when_blank() {
+        let mut prompter = ScriptedPrompter::new(&["", "  Given  "]);
+
+        assert_eq!(prompter.optional("First: ", "Default").unwrap(), "Default");
+        assert_eq!(prompter.optional("Second: ", "Default").unwrap(), "Given");
+        // Input has ended
+        assert_eq!(prompter.optional("Third: ", "Default").unwrap(), "Default");
+        assert_eq!(prompter.transcript(), "First: Second: Third: ");
+    }
+
+    #[test]
+    fn test_optional_rejects_invalid_input() {
+        let mut prompter = ScriptedPrompter::new(&["Hello\u{7}home", &"a".repeat(101)]);
+
+        let error = prompter.optional("Value: ", "x").unwrap_err();
+        assert_eq!(error.to_string(), "Input contains invalid character \\u{7}");
+        let error = prompter.optional("Value: ", "x").unwrap_err();
+        assert_eq!(error.to_string(), "Input is too long (max 100 characters)");
+    }
+
+    #[test]
+    fn test_required_loops_until_valid() {
+        let mut prompter = ScriptedPrompter::new(&["", "bad#", "Essence"]).with_policy(ValidationPolicy::strict());
+
+        assert_eq!(prompter.required("Essence: ", "Essence instance").unwrap(), "Essence");
+        let transcript = prompter.transcript();
+        assert!(transcript.contains("Essence instance is required. Please enter a value."));
+        assert!(transcript.contains("Essence instance contains invalid character '#'. Please try again."));
+    }
+
+    #[test]
+    fn test_required_fails_when_input_ends() {
+        let mut prompter = ScriptedPrompter::new(&[""]);
+
+        let error = prompter.required("Essence: ", "Essence instance").unwrap_err();
+        assert!(matches!(&error, SystematicsError::Cancelled { field } if field == "Essence instance"));
+        assert_eq!(error.to_string(), "Input ended before Essence instance was given");
+    }
+
+    #[test]
+    fn test_next_entry() {
+        let mut prompter = ScriptedPrompter::new(&["Body", "no$", "Mind", ""]).with_policy(ValidationPolicy::strict());
+
+        assert_eq!(prompter.next_entry("Term: ", "Term").unwrap(), Some("Body".to_string()));
+        assert_eq!(prompter.next_entry("Term: ", "Term").unwrap(), Some("Mind".to_string()));
+        assert_eq!(prompter.next_entry("Term: ", "Term").unwrap(), None);
+        assert!(prompter.transcript().contains("Error: Term contains invalid character '$'. Please try again."));
+    }
+
+    #[test]
+    fn test_default_policy_accepts_slashes_and_ampersands() {
+        let mut prompter = ScriptedPrompter::new(&["Mind/Body", "  A & B  "]);
+
+        assert_eq!(prompter.required("Term: ", "Term").unwrap(), "Mind/Body");
+        assert_eq!(prompter.optional("Term: ", "x").unwrap(), "A & B");
+    }
+
+    #[test]
+    fn test_yes_no() {
+        let mut prompter = ScriptedPrompter::new(&["maybe", "YES", "n", ""]);
+
+        assert!(prompter.yes_no("Modify? ", false).unwrap());
+        assert!(!prompter.yes_no("Modify? ", true).unwrap());
+        assert!(prompter.yes_no("Modify? ", true).unwrap());
+        assert!(prompter.transcript().contains("Please enter 'y' or 'n' (or press Enter for default: n)"));
+    }
+
+    #[test]
+    fn test_io_prompter_over_reader_and_writer() {
+        let mut prompter = IoPrompter::new("Answer\r\n".as_bytes(), Vec::new());
+
+        assert_eq!(prompter.ask("Question? ").unwrap(), Some("Answer".to_string()));
+        assert_eq!(prompter.ask("Again? ").unwrap(), None);
+        prompter.say("Done").unwrap();
+        assert_eq!(String::from_utf8(prompter.into_output()).unwrap(), "Question? Again? Done\n");
+    }
+}
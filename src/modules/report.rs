@@ -0,0 +1,250 @@
+use crate::modules::diagram::{escape, SvgOptions};
+use crate::modules::document::SystemDocument;
+use crate::modules::permutations::generate_six_permutations;
+
+/// Layout of a written report
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReportFormat {
+    Markdown,
+    Html,
+}
+
+impl ReportFormat {
+    /// Format for a name such as "markdown", "md" or "html" (case-insensitive)
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_lowercase().as_str() {
+            "markdown" | "md" => Some(ReportFormat::Markdown),
+            "html" | "htm" => Some(ReportFormat::Html),
+            _ => None,
+        }
+    }
+}
+
+/// A titled table of a report, written the same way in either format
+struct Table {
+    title: &'static str,
+    headers: Vec<&'static str>,
+    rows: Vec<Vec<String>>,
+}
+
+impl SystemDocument {
+    /// A report for facilitators: name, core attribute, canonical and user terms,
+    /// connectives, a Triad's six laws and the system's diagram
+    pub fn report(&self, format: ReportFormat) -> String {
+        let tables = self.report_tables();
+        let svg = self.to_svg(&SvgOptions {
+            connectives: true,
+            ..SvgOptions::default()
+        });
+        match format {
+            ReportFormat::Markdown => self.markdown(&tables, &svg),
+            ReportFormat::Html => self.html(&tables, &svg),
+        }
+    }
+
+    fn report_tables(&self) -> Vec<Table> {
+        let nested = self.terms.iter().any(|term| term.child.is_some());
+        let mut terms = Table {
+            title: "Terms",
+            headers: vec!["Position", "Canonical term", "Instance"],
+            rows: Vec::new(),
+        };
+        if nested {
+            terms.headers.push("Nested system");
+        }
+        for term in &self.terms {
+            let mut row = vec![term.position.to_string(), term.canonical.clone().unwrap_or_default(), term.instance.clone()];
+            if nested {
+                row.push(term.child.map(|child| child.to_string()).unwrap_or_default());
+            }
+            terms.rows.push(row);
+        }
+        let mut tables = vec![terms];
+
+        if !self.connectives.is_empty() {
+            tables.push(Table {
+                title: "Connectives",
+                headers: vec!["Connective", "Canonical name", "User label", "Notes"],
+                rows: self
+                    .connectives
+                    .iter()
+                    .map(|connective| {
+                        vec![
+                            connective.code(),
+                            connective.canonical_name.clone(),
+                            connective.user_label.clone().unwrap_or_default(),
+                            connective.notes.clone().unwrap_or_default(),
+                        ]
+                    })
+                    .collect(),
+            });
+        }
+
+        if self.order == 3 && self.terms.len() == 3 {
+            // Saved laws are reported as saved; otherwise they follow the current instances
+            let permutations = if self.permutations.is_empty() {
+                generate_six_permutations(&self.terms[0].instance, &self.terms[1].instance, &self.terms[2].instance)
+            } else {
+                self.permutations.clone()
+            };
+            tables.push(Table {
+                title: "Six laws",
+                headers: vec!["Law", "Roles", "Initiating", "Colouring", "Outcome"],
+                rows: permutations
+                    .iter()
+                    .map(|law| vec![law.name.clone(), law.code(), law.initiating.clone(), law.colouring.clone(), law.outcome.clone()])
+                    .collect(),
+            });
+        }
+        tables
+    }
+
+    fn markdown(&self, tables: &[Table], svg: &str) -> String {
+        let mut text = format!("# {}\n\n", markdown_text(&self.name));
+        text.push_str(&format!("**{}** · Core attribute: {}\n", self.kind, markdown_text(&self.attribute)));
+        for table in tables {
+            text.push_str(&format!("\n## {}\n\n", table.title));
+            text.push_str(&format!("| {} |\n", table.headers.join(" | ")));
+            text.push_str(&format!("|{}\n", " --- |".repeat(table.headers.len())));
+            for row in &table.rows {
+                let cells: Vec<String> = row.iter().map(|cell| markdown_cell(cell)).collect();
+                text.push_str(&format!("| {} |\n", cells.join(" | ")));
+            }
+        }
+        if !svg.is_empty() {
+            text.push_str("\n## Diagram\n\n");
+            text.push_str(svg);
+        }
+        text
+    }
+
+    fn html(&self, tables: &[Table], svg: &str) -> String {
+        let title = escape(&self.name);
+        let mut text = format!(
+            "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{}</title>\n<style>\n{}</style>\n</head>\n<body>\n",
+            title, STYLE
+        );
+        text.push_str(&format!("<h1>{}</h1>\n", title));
+        text.push_str(&format!(
+            "<p><strong>{}</strong> · Core attribute: {}</p>\n",
+            escape(&self.kind),
+            escape(&self.attribute)
+        ));
+        for table in tables {
+            text.push_str(&format!("<h2>{}</h2>\n<table>\n<tr>", table.title));
+            for header in &table.headers {
+                text.push_str(&format!("<th>{}</th>", header));
+            }
+            text.push_str("</tr>\n");
+            for row in &table.rows {
+                text.push_str("<tr>");
+                for cell in row {
+                    text.push_str(&format!("<td>{}</td>", escape(cell)));
+                }
+                text.push_str("</tr>\n");
+            }
+            text.push_str("</table>\n");
+        }
+        if !svg.is_empty() {
+            text.push_str("<h2>Diagram</h2>\n");
+            text.push_str(svg);
+        }
+        text.push_str("</body>\n</html>\n");
+        text
+    }
+}
+
+const STYLE: &str = "\
+body { font-family: sans-serif; max-width: 60em; margin: 2em auto; color: #333; }
+table { border-collapse: collapse; margin-bottom: 1em; }
+th, td { border: 1px solid #ccc; padding: 4px 8px; text-align: left; }
+th { background: #f0f0f0; }
+";
+
+/// Keeps user text from being read as inline HTML by Markdown renderers
+fn markdown_text(value: &str) -> String {
+    value.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;")
+}
+
+/// Keeps a value inside its Markdown table cell
+fn markdown_cell(value: &str) -> String {
+    markdown_text(value).replace('|', "\\|").replace('\n', " ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::tetrad::Tetrad;
+    use crate::modules::triad::Triad;
+
+    fn tetrad() -> SystemDocument {
+        let mut document = SystemDocument::from_system(&Tetrad::new("Work", "Office", "Ideal", "Tools | kit", "Plan"));
+        document.connectives[0].user_label = Some("Calling".to_string());
+        document.connectives[0].notes = Some("Agreed <today>".to_string());
+        document
+    }
+
+    #[test]
+    fn test_format_names() {
+        assert_eq!(ReportFormat::from_name("Markdown"), Some(ReportFormat::Markdown));
+        assert_eq!(ReportFormat::from_name("md"), Some(ReportFormat::Markdown));
+        assert_eq!(ReportFormat::from_name("HTML"), Some(ReportFormat::Html));
+        assert_eq!(ReportFormat::from_name("pdf"), None);
+    }
+
+    #[test]
+    fn test_markdown_report() {
+        let report = tetrad().report(ReportFormat::Markdown);
+
+        assert!(report.starts_with("# Work\n\n**Tetrad** · Core attribute: "), "{}", report);
+        assert!(report.contains("## Terms\n\n| Position | Canonical term | Instance |\n| --- | --- | --- |\n| A | Ground | Office |\n"));
+        assert!(report.contains("| C | Instrumental | Tools \\| kit |\n"), "{}", report);
+        assert!(report.contains("| A&lt;&gt;B | Motivational imperative | Calling | Agreed &lt;today&gt; |\n"), "{}", report);
+        assert!(report.contains("| C&lt;&gt;D | Demonstrable activity |  |  |\n"), "{}", report);
+        assert!(!report.contains("Six laws"));
+        assert!(report.contains("## Diagram\n\n<svg xmlns="));
+    }
+
+    #[test]
+    fn test_markdown_escapes_name_and_attribute() {
+        let mut document = tetrad();
+        document.name = "R&D <lab>".to_string();
+        document.attribute = "Work > play".to_string();
+        let report = document.report(ReportFormat::Markdown);
+
+        assert!(report.starts_with("# R&amp;D &lt;lab&gt;\n\n**Tetrad** · Core attribute: Work &gt; play\n"), "{}", report);
+    }
+
+    #[test]
+    fn test_html_report() {
+        let report = tetrad().report(ReportFormat::Html);
+
+        assert!(report.starts_with("<!DOCTYPE html>\n"));
+        assert!(report.contains("<title>Work</title>"));
+        assert!(report.contains("<tr><td>A</td><td>Ground</td><td>Office</td></tr>\n"), "{}", report);
+        assert!(report.contains("<td>Agreed &lt;today&gt;</td>"));
+        assert!(report.contains("<h2>Diagram</h2>\n<svg "));
+        assert!(report.ends_with("</body>\n</html>\n"));
+    }
+
+    #[test]
+    fn test_triad_report_has_six_laws() {
+        let document = SystemDocument::from_system(&Triad::new("Will", "Intention", "Resistance", "Resolution"));
+        let report = document.report(ReportFormat::Markdown);
+
+        assert!(report.contains("## Six laws\n\n| Law | Roles | Initiating | Colouring | Outcome |\n"), "{}", report);
+        assert!(report.contains("| Identity | 231 | Resistance | Resolution | Intention |\n"), "{}", report);
+        assert!(!report.contains("## Connectives"));
+    }
+
+    #[test]
+    fn test_nested_terms_are_reported() {
+        let mut document = tetrad();
+        document.terms[2].child = Some(7);
+        let report = document.report(ReportFormat::Markdown);
+
+        assert!(report.contains("| Position | Canonical term | Instance | Nested system |\n"));
+        assert!(report.contains("| C | Instrumental | Tools \\| kit | 7 |\n"), "{}", report);
+        assert!(report.contains("| A | Ground | Office |  |\n"));
+    }
+}
@@ -0,0 +1,267 @@
+use crate::modules::connective::Position;
+use crate::modules::document::SystemDocument;
+use crate::modules::error::SystematicsError;
+use crate::modules::prompter::Prompter;
+use crate::modules::store::{Store, StoredSystem};
+
+/// Answer that puts a connective back to its canonical name
+const RESTORE: &str = "-";
+
+/// Something an edit session can change, named by the user as "N", "C" or "B<>E"
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Target {
+    Name,
+    Term(Position),
+    Connective(Position, Position),
+}
+
+impl Target {
+    /// Reads "N" or "name", a position letter ("C") or a pair of them ("B<>E", "B-E", "BE")
+    pub fn parse(text: &str) -> Option<Self> {
+        let text = text.trim();
+        if text.eq_ignore_ascii_case("n") || text.eq_ignore_ascii_case("name") {
+            return Some(Target::Name);
+        }
+        let letters: Vec<char> = text.chars().filter(|character| !matches!(character, '<' | '>' | '-' | ' ')).collect();
+        if !letters.iter().all(char::is_ascii_alphabetic) {
+            return None;
+        }
+        let separated = letters.len() != text.chars().count();
+        match (letters.as_slice(), separated) {
+            ([letter], false) => Some(Target::Term(Position::new(*letter))),
+            ([from, to], _) => Some(Target::Connective(Position::new(*from), Position::new(*to))),
+            _ => None,
+        }
+    }
+}
+
+impl SystemDocument {
+    /// Edits the system through any prompter, one target at a time.
+    ///
+    /// Each question shows the current value as its default. `first` jumps straight to
+    /// a target; after it, the session asks which target to change next until the
+    /// answer is blank. Returns whether anything changed.
+    pub fn edit_with(&mut self, prompter: &mut dyn Prompter, first: Option<Target>) -> Result<bool, SystematicsError> {
+        let original = self.clone();
+        prompter.say(&format!("\n--- Editing {}: {} ---", self.kind, self.name))?;
+        prompter.say(&self.summary())?;
+
+        let mut next = first;
+        loop {
+            let target = match next.take() {
+                Some(target) => target,
+                None => {
+                    let answer = prompter.ask(&self.target_prompt())?.unwrap_or_default();
+                    if answer.trim().is_empty() {
+                        break;
+                    }
+                    match Target::parse(&answer) {
+                        Some(target) => target,
+                        None => {
+                            prompter.say(&format!("'{}' is not a position or connective, e.g. C or B<>E", answer.trim()))?;
+                            continue;
+                        }
+                    }
+                }
+            };
+            if let Err(error) = self.edit_target(target, prompter) {
+                match error {
+                    SystematicsError::Validation { .. } | SystematicsError::Structure(_) => prompter.say(&format!("Error: {}", error))?,
+                    error => return Err(error),
+                }
+            }
+        }
+
+        // Saved six laws follow the Triad's new instances
+        if !self.permutations.is_empty() {
+            *self = self.clone().with_six_laws()?;
+        }
+        self.to_system()?;
+        Ok(*self != original)
+    }
+
+    fn edit_target(&mut self, target: Target, prompter: &mut dyn Prompter) -> Result<(), SystematicsError> {
+        match target {
+            Target::Name => {
+                self.name = prompter.optional(&format!("Name (or press Enter to keep '{}'): ", self.name), &self.name)?;
+            }
+            Target::Term(position) => {
+                let kind = self.kind.clone();
+                let term = self
+                    .terms
+                    .iter_mut()
+                    .find(|term| term.position == position)
+                    .ok_or_else(|| SystematicsError::Structure(format!("{} has no position {}", kind, position)))?;
+                let label = match &term.canonical {
+                    Some(canonical) => format!("{} ({})", canonical, position),
+                    None => format!("Term {}", position),
+                };
+                term.instance = prompter.optional(&format!("{} (or press Enter to keep '{}'): ", label, term.instance), &term.instance)?;
+            }
+            Target::Connective(from, to) => {
+                let kind = self.kind.clone();
+                let connective = self
+                    .connectives
+                    .iter_mut()
+                    .find(|connective| connective.joins(from, to))
+                    .ok_or_else(|| SystematicsError::Structure(format!("{} has no connective {}<>{}", kind, from, to)))?;
+                let prompt = format!(
+                    "{} {} (or press Enter to keep '{}', '{}' for the canonical name): ",
+                    connective.code(),
+                    connective.canonical_name,
+                    connective.label(),
+                    RESTORE
+                );
+                let label = prompter.optional(&prompt, connective.label())?;
+                connective.user_label = match label.as_str() {
+                    RESTORE => None,
+                    label if label == connective.canonical_name => None,
+                    label => Some(label.to_string()),
+                };
+            }
+        }
+        Ok(())
+    }
+
+    fn target_prompt(&self) -> String {
+        let last = self.terms.last().map_or('A', |term| term.position.letter());
+        if self.connectives.is_empty() {
+            format!("\nEdit which? (N for the name, A-{} for a term, or press Enter to finish): ", last)
+        } else {
+            format!("\nEdit which? (N for the name, A-{} for a term, e.g. A<>B for a connective, or press Enter to finish): ", last)
+        }
+    }
+
+    /// Current values, one per line, as the session shows them
+    fn summary(&self) -> String {
+        let mut lines = vec![format!("Name: {}", self.name)];
+        for term in &self.terms {
+            match &term.canonical {
+                Some(canonical) => lines.push(format!("{} ({}): {}", term.position, canonical, term.instance)),
+                None => lines.push(format!("{}: {}", term.position, term.instance)),
+            }
+        }
+        for connective in &self.connectives {
+            lines.push(format!("{} {}", connective.code(), connective.label()));
+        }
+        lines.join("\n")
+    }
+}
+
+/// Runs an edit session on a saved system and saves the result in one write, unless
+/// nothing changed or the system was changed by someone else meanwhile.
+/// Returns the updated record, or None when there was nothing to save.
+pub fn edit_saved(
+    store: &Store,
+    id: u64,
+    prompter: &mut dyn Prompter,
+    first: Option<Target>,
+) -> Result<Option<StoredSystem>, SystematicsError> {
+    let original = store.get(id)?.system;
+    let mut system = original.clone();
+    if !system.edit_with(prompter, first)? {
+        prompter.say("No changes.")?;
+        return Ok(None);
+    }
+    let stored = store.update_if_unchanged(id, &original, system)?;
+    prompter.say(&format!("Saved {} {}: {}", stored.system.kind, stored.id, stored.system.name))?;
+    Ok(Some(stored))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::octad::Octad;
+    use crate::modules::prompter::ScriptedPrompter;
+    use crate::modules::tetrad::Tetrad;
+    use crate::modules::triad::Triad;
+
+    fn tetrad() -> SystemDocument {
+        SystemDocument::from_system(&Tetrad::new("Work", "Office", "Mission", "Tools", "Plan"))
+    }
+
+    #[test]
+    fn test_parse_targets() {
+        assert_eq!(Target::parse("n"), Some(Target::Name));
+        assert_eq!(Target::parse(" c "), Some(Target::Term(Position::new('C'))));
+        let pair = Some(Target::Connective(Position::new('B'), Position::new('E')));
+        assert_eq!(Target::parse("B<>E"), pair);
+        assert_eq!(Target::parse("b-e"), pair);
+        assert_eq!(Target::parse("BE"), pair);
+        assert_eq!(Target::parse("B<>"), None);
+        assert_eq!(Target::parse("1"), None);
+        assert_eq!(Target::parse("ABC"), None);
+    }
+
+    #[test]
+    fn test_jump_to_a_term_and_keep_defaults() {
+        let mut document = tetrad();
+        let mut prompter = ScriptedPrompter::new(&["Workshop", "A", "", ""]);
+
+        assert!(document.edit_with(&mut prompter, Some(Target::Term(Position::new('C')))).unwrap());
+        assert_eq!(document.instances(), vec!["Office", "Mission", "Workshop", "Plan"]);
+        let transcript = prompter.transcript();
+        assert!(transcript.contains("C (Instrumental): Tools\n"), "{}", transcript);
+        assert!(transcript.contains("Instrumental (C) (or press Enter to keep 'Tools'): "));
+        assert!(transcript.contains("Ground (A) (or press Enter to keep 'Office'): "));
+    }
+
+    #[test]
+    fn test_connectives_by_letter() {
+        let mut document = SystemDocument::from_system(&Octad::new("Firm", "A", "B", "C", "D", "E", "F", "G", "H"));
+        let mut prompter = ScriptedPrompter::new(&["E<>B", "Handover", "XY", "b-e", "-", "AH", "Reach", ""]);
+
+        assert!(document.edit_with(&mut prompter, None).unwrap());
+        let transcript = prompter.transcript();
+        assert!(transcript.contains("Error: Octad has no connective X<>Y"), "{}", transcript);
+        let connective = document.connectives.iter().find(|connective| connective.joins('B', 'E')).unwrap();
+        assert!(!connective.is_relabelled());
+        let connective = document.connectives.iter().find(|connective| connective.joins('A', 'H')).unwrap();
+        assert_eq!(connective.label(), "Reach");
+    }
+
+    #[test]
+    fn test_bad_answers_do_not_end_the_session() {
+        let mut document = tetrad();
+        let mut prompter = ScriptedPrompter::new(&["Q", "?", "B", "Bad\u{7}", "N", "Office work", ""]);
+
+        assert!(document.edit_with(&mut prompter, None).unwrap());
+        assert_eq!(document.name, "Office work");
+        assert_eq!(document.terms[1].instance, "Mission");
+        let transcript = prompter.transcript();
+        assert!(transcript.contains("Error: Tetrad has no position Q"), "{}", transcript);
+        assert!(transcript.contains("'?' is not a position or connective, e.g. C or B<>E"));
+        assert!(transcript.contains("Error: Input contains invalid character \\u{7}"));
+    }
+
+    #[test]
+    fn test_triad_six_laws_follow_edits() {
+        let mut document = SystemDocument::from_system(&Triad::new("Will", "Intention", "Resistance", "Resolution"))
+            .with_six_laws()
+            .unwrap();
+        let mut prompter = ScriptedPrompter::new(&["Choice"]);
+
+        assert!(document.edit_with(&mut prompter, Some(Target::Term(Position::new('A')))).unwrap());
+        assert!(document.permutations.iter().all(|law| !law.display().contains("Intention")));
+        assert!(document.permutations.iter().any(|law| law.display().contains("Choice")));
+    }
+
+    #[test]
+    fn test_edit_saved_writes_once() {
+        let root = std::env::temp_dir().join(format!("systematics-session-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&root);
+        let store = Store::open(&root).unwrap();
+        let id = store.create_document(tetrad()).unwrap().id;
+
+        let mut prompter = ScriptedPrompter::new(&[""]);
+        assert_eq!(edit_saved(&store, id, &mut prompter, None).unwrap(), None);
+        assert!(prompter.transcript().ends_with("No changes.\n"));
+
+        let mut prompter = ScriptedPrompter::new(&["Desk", ""]);
+        let stored = edit_saved(&store, id, &mut prompter, Some(Target::Term(Position::new('A')))).unwrap().unwrap();
+        assert_eq!(stored.system.terms[0].instance, "Desk");
+        assert_eq!(store.get(id).unwrap(), stored);
+        assert!(prompter.transcript().ends_with("Saved Tetrad 1: Work\n"));
+        let _ = std::fs::remove_dir_all(&root);
+    }
+}
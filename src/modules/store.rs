@@ -0,0 +1,482 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+use crate::modules::document::SystemDocument;
+use crate::modules::error::SystematicsError;
+use crate::modules::system::System;
+use crate::modules::validation::{ValidationPolicy, POLICY_FILE};
+
+/// File holding the next identifier to hand out
+const NEXT_ID_FILE: &str = "next-id";
+
+/// A system saved in the store, with its stable id and timestamps
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StoredSystem {
+    pub id: u64,
+    /// Seconds since the Unix epoch
+    pub created_at: u64,
+    /// Seconds since the Unix epoch
+    pub updated_at: u64,
+    pub system: SystemDocument,
+}
+
+/// Filter for `Store::query`; unset fields match everything
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Query {
+    pub order: Option<usize>,
+    /// Case-insensitive substring of the system name
+    pub name: Option<String>,
+}
+
+impl Query {
+    /// Check if a stored system matches every set field
+    pub fn matches(&self, stored: &StoredSystem) -> bool {
+        let order_matches = self.order.is_none_or(|order| stored.system.order == order);
+        let name_matches = self
+            .name
+            .as_ref()
+            .is_none_or(|name| stored.system.name.to_lowercase().contains(&name.to_lowercase()));
+        order_matches && name_matches
+    }
+}
+
+/// Embedded, file-backed store of named systems.
+///
+/// Each system lives in its own `<id>.json` file inside the store directory.
+/// Ids are never reused, and files are replaced atomically on every write.
+/// Every change is also recorded, with its author, in the system's history.
+#[derive(Debug, Clone)]
+pub struct Store {
+    root: PathBuf,
+    author: String,
+}
+
+impl Store {
+    /// Opens (creating if needed) a store in the given directory
+    pub fn open(root: &Path) -> Result<Self, SystematicsError> {
+        fs::create_dir_all(root)?;
+        Ok(Store {
+            root: root.to_path_buf(),
+            author: default_author(),
+        })
+    }
+
+    /// Records changes under the given author instead of the default
+    pub fn with_author(mut self, author: &str) -> Self {
+        self.author = author.to_string();
+        self
+    }
+
+    /// Who changes are recorded under: `$SYSTEMATICS_AUTHOR`, else `$USER`, unless set
+    pub fn author(&self) -> &str {
+        &self.author
+    }
+
+    /// Default store location: `$SYSTEMATICS_HOME`, else `~/.systematics`
+    pub fn default_root() -> PathBuf {
+        if let Some(home) = std::env::var_os("SYSTEMATICS_HOME") {
+            return PathBuf::from(home);
+        }
+        let home = std::env::var_os("HOME").map(PathBuf::from).unwrap_or_else(|| PathBuf::from("."));
+        home.join(".systematics")
+    }
+
+    /// Directory the store lives in
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Validation policy for this workspace: its `policy.json`, else the default
+    pub fn policy(&self) -> Result<ValidationPolicy, SystematicsError> {
+        ValidationPolicy::for_workspace(&self.root)
+    }
+
+    /// Saves the validation policy for this workspace
+    pub fn set_policy(&self, policy: &ValidationPolicy) -> Result<(), SystematicsError> {
+        write_atomic(&self.root.join(POLICY_FILE), &serde_json::to_string_pretty(policy)?)
+    }
+
+    /// Saves a new system and returns its record
+    pub fn create(&self, system: &dyn System) -> Result<StoredSystem, SystematicsError> {
+        self.create_document(SystemDocument::from_system(system))
+    }
+
+    /// Saves a new system document and returns its record
+    pub fn create_document(&self, system: SystemDocument) -> Result<StoredSystem, SystematicsError> {
+        self.check_nesting(None, &system)?;
+        let id = self.next_id()?;
+        let now = now();
+        let stored = StoredSystem {
+            id,
+            created_at: now,
+            updated_at: now,
+            system,
+        };
+        self.write(&stored)?;
+        self.record(None, &stored)?;
+        Ok(stored)
+    }
+
+    /// Loads the system with the given id
+    pub fn get(&self, id: u64) -> Result<StoredSystem, SystematicsError> {
+        let path = self.path_for(id);
+        if !path.exists() {
+            return Err(SystematicsError::NotFound(id));
+        }
+        read_record(&path)
+    }
+
+    /// All stored systems, by ascending id
+    pub fn list(&self) -> Result<Vec<StoredSystem>, SystematicsError> {
+        let mut systems = Vec::new();
+        for entry in fs::read_dir(&self.root)? {
+            let path = entry?.path();
+            let is_record = path.extension().is_some_and(|extension| extension == "json")
+                && path.file_stem().and_then(|stem| stem.to_str()).is_some_and(|stem| stem.parse::<u64>().is_ok());
+            if is_record {
+                systems.push(read_record(&path)?);
+            }
+        }
+        systems.sort_by_key(|stored| stored.id);
+        Ok(systems)
+    }
+
+    /// Stored systems matching a query, by ascending id
+    pub fn query(&self, query: &Query) -> Result<Vec<StoredSystem>, SystematicsError> {
+        Ok(self.list()?.into_iter().filter(|stored| query.matches(stored)).collect())
+    }
+
+    /// Replaces the system stored under an id, keeping its creation time
+    pub fn update(&self, id: u64, system: SystemDocument) -> Result<StoredSystem, SystematicsError> {
+        let _lock = self.lock(id)?;
+        self.replace(id, system)
+    }
+
+    fn replace(&self, id: u64, system: SystemDocument) -> Result<StoredSystem, SystematicsError> {
+        let previous = self.get(id)?;
+        self.check_nesting(Some(id), &system)?;
+        let mut stored = previous.clone();
+        stored.system = system;
+        stored.updated_at = now().max(stored.created_at);
+        self.write(&stored)?;
+        self.record(Some(&previous), &stored)?;
+        Ok(stored)
+    }
+
+    /// Replaces the system stored under an id only while it still holds `expected`, so a
+    /// long edit never overwrites changes saved by someone else in the meantime. A lock
+    /// file keeps another save from slipping in between the comparison and the write.
+    pub fn update_if_unchanged(&self, id: u64, expected: &SystemDocument, system: SystemDocument) -> Result<StoredSystem, SystematicsError> {
+        let _lock = self.lock(id)?;
+        if self.get(id)?.system != *expected {
+            return Err(SystematicsError::Storage(format!(
+                "System {} was changed while it was being edited; nothing was saved",
+                id
+            )));
+        }
+        self.replace(id, system)
+    }
+
+    /// Removes the system stored under an id, unless another system nests it
+    pub fn delete(&self, id: u64) -> Result<(), SystematicsError> {
+        let path = self.path_for(id);
+        if !path.exists() {
+            return Err(SystematicsError::NotFound(id));
+        }
+        if let Some((parent, position)) = self.parents(id)?.first() {
+            return Err(SystematicsError::Structure(format!(
+                "System {} is nested in system {} at {}; unnest it first",
+                id, parent, position
+            )));
+        }
+        fs::remove_file(path)?;
+        self.forget(id)
+    }
+
+    /// Held while a system is saved, so two saves never interleave
+    pub(crate) fn lock(&self, id: u64) -> Result<Lock, SystematicsError> {
+        Lock::take(&self.root.join(format!("{}.lock", id)), &format!("System {}", id))
+    }
+
+    fn path_for(&self, id: u64) -> PathBuf {
+        self.root.join(format!("{}.json", id))
+    }
+
+    /// Hands out the next id and advances the counter, locked so two new systems
+    /// never get the same id
+    fn next_id(&self) -> Result<u64, SystematicsError> {
+        let path = self.root.join(NEXT_ID_FILE);
+        let _lock = Lock::take(&path.with_extension("lock"), "The id counter")?;
+        let id = match fs::read_to_string(&path) {
+            Ok(contents) => contents
+                .trim()
+                .parse::<u64>()
+                .map_err(|e| SystematicsError::Storage(format!("Corrupt id counter in {}: {}", path.display(), e)))?,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => 1,
+            Err(e) => return Err(e.into()),
+        };
+        write_atomic(&path, &(id + 1).to_string())?;
+        Ok(id)
+    }
+
+    pub(crate) fn write(&self, stored: &StoredSystem) -> Result<(), SystematicsError> {
+        write_atomic(&self.path_for(stored.id), &serde_json::to_string_pretty(stored)?)
+    }
+}
+
+/// A lock file held while a system is compared and saved, removed when dropped
+pub(crate) struct Lock {
+    path: PathBuf,
+}
+
+impl Lock {
+    /// Creates the lock file, failing if `what` is already locked
+    fn take(path: &Path, what: &str) -> Result<Self, SystematicsError> {
+        match fs::OpenOptions::new().write(true).create_new(true).open(path) {
+            Ok(_) => Ok(Lock { path: path.to_path_buf() }),
+            Err(error) if error.kind() == std::io::ErrorKind::AlreadyExists => Err(SystematicsError::Storage(format!(
+                "{} is being saved by someone else; try again (or remove {} if no save is running)",
+                what,
+                path.display()
+            ))),
+            Err(error) => Err(error.into()),
+        }
+    }
+}
+
+impl Drop for Lock {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.path);
+    }
+}
+
+/// Author recorded when none is given: `$SYSTEMATICS_AUTHOR`, else `$USER`, else "unknown"
+fn default_author() -> String {
+    ["SYSTEMATICS_AUTHOR", "USER", "USERNAME"]
+        .iter()
+        .find_map(|name| std::env::var(name).ok().filter(|value| !value.trim().is_empty()))
+        .unwrap_or_else(|| "unknown".to_string())
+}
+
+/// Seconds since the Unix epoch
+pub(crate) fn now() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map(|elapsed| elapsed.as_secs()).unwrap_or(0)
+}
+
+/// Reads a record, checking its system document like any other JSON document
+fn read_record(path: &Path) -> Result<StoredSystem, SystematicsError> {
+    let corrupt = |problem: &dyn std::fmt::Display| SystematicsError::Storage(format!("{}: {}", path.display(), problem));
+    let contents = fs::read_to_string(path)?;
+    let mut value: serde_json::Value = serde_json::from_str(&contents).map_err(|e| corrupt(&e))?;
+    let system = value
+        .get_mut("system")
+        .map(serde_json::Value::take)
+        .ok_or_else(|| corrupt(&"missing system"))?;
+    let system = SystemDocument::from_json(&system.to_string()).map_err(|e| corrupt(&e))?;
+
+    let field = |name: &str| {
+        value
+            .get(name)
+            .and_then(serde_json::Value::as_u64)
+            .ok_or_else(|| corrupt(&format!("missing {}", name)))
+    };
+    Ok(StoredSystem {
+        id: field("id")?,
+        created_at: field("created_at")?,
+        updated_at: field("updated_at")?,
+        system,
+    })
+}
+
+/// Writes through a temporary file so a crash never leaves a half-written file
+pub(crate) fn write_atomic(path: &Path, contents: &str) -> Result<(), SystematicsError> {
+    let temporary = path.with_extension("tmp");
+    fs::write(&temporary, contents)?;
+    fs::rename(&temporary, path)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::hexad::Hexad;
+    use crate::modules::tetrad::Tetrad;
+    use crate::modules::triad::Triad;
+
+    /// A fresh store in its own temporary directory
+    fn temp_store(test: &str) -> Store {
+        let root = std::env::temp_dir().join(format!("systematics-store-{}-{}", test, std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        Store::open(&root).unwrap()
+    }
+
+    #[test]
+    fn test_create_and_get() {
+        let store = temp_store("create");
+        let stored = store.create(&Triad::new("Will", "Intention", "Resistance", "Resolution")).unwrap();
+
+        assert_eq!(stored.id, 1);
+        assert_eq!(stored.created_at, stored.updated_at);
+        assert_eq!(store.get(1).unwrap(), stored);
+        assert!(matches!(store.get(2), Err(SystematicsError::NotFound(2))));
+        assert_eq!(store.get(2).unwrap_err().to_string(), "No system with id 2");
+
+        fs::remove_dir_all(store.root()).unwrap();
+    }
+
+    #[test]
+    fn test_ids_are_stable_and_never_reused() {
+        let store = temp_store("ids");
+        let first = store.create(&Tetrad::new("One", "G", "I", "In", "D")).unwrap();
+        let second = store.create(&Tetrad::new("Two", "G", "I", "In", "D")).unwrap();
+        store.delete(second.id).unwrap();
+        let third = store.create(&Tetrad::new("Three", "G", "I", "In", "D")).unwrap();
+
+        assert_eq!((first.id, second.id, third.id), (1, 2, 3));
+        let ids: Vec<u64> = store.list().unwrap().iter().map(|stored| stored.id).collect();
+        assert_eq!(ids, vec![1, 3]);
+
+        // Reopening the same directory carries on from the saved counter
+        let reopened = Store::open(store.root()).unwrap();
+        assert_eq!(reopened.create(&Tetrad::new("Four", "G", "I", "In", "D")).unwrap().id, 4);
+
+        fs::remove_dir_all(store.root()).unwrap();
+    }
+
+    #[test]
+    fn test_concurrent_creates_get_distinct_ids() {
+        let store = temp_store("concurrent");
+        let creators: Vec<_> = (0..8)
+            .map(|n| {
+                let store = store.clone();
+                std::thread::spawn(move || loop {
+                    // A creator that finds the counter locked tries again
+                    match store.create(&Triad::new(&format!("Will {}", n), "A", "P", "R")) {
+                        Ok(stored) => return stored.id,
+                        Err(SystematicsError::Storage(_)) => std::thread::yield_now(),
+                        Err(error) => panic!("{}", error),
+                    }
+                })
+            })
+            .collect();
+        let mut ids: Vec<u64> = creators.into_iter().map(|creator| creator.join().unwrap()).collect();
+        ids.sort();
+        assert_eq!(ids, (1..=8).collect::<Vec<u64>>());
+        assert_eq!(store.list().unwrap().len(), 8);
+
+        // While the counter is locked nothing is created
+        let lock = store.root().join("next-id.lock");
+        fs::write(&lock, "").unwrap();
+        let error = store.create(&Triad::new("Will", "A", "P", "R")).unwrap_err();
+        assert!(error.to_string().starts_with("The id counter is being saved by someone else"), "{}", error);
+        fs::remove_file(&lock).unwrap();
+        assert_eq!(store.create(&Triad::new("Will", "A", "P", "R")).unwrap().id, 9);
+
+        fs::remove_dir_all(store.root()).unwrap();
+    }
+
+    #[test]
+    fn test_update_keeps_created_at() {
+        let store = temp_store("update");
+        let stored = store.create(&Hexad::new("Plan", "R", "V", "O", "C", "F", "P")).unwrap();
+
+        let mut document = stored.system.clone();
+        document.name = "Revised plan".to_string();
+        let updated = store.update(stored.id, document).unwrap();
+
+        assert_eq!(updated.created_at, stored.created_at);
+        assert!(updated.updated_at >= stored.updated_at);
+        assert_eq!(store.get(stored.id).unwrap().system.name, "Revised plan");
+        assert!(store.update(99, stored.system).is_err());
+
+        fs::remove_dir_all(store.root()).unwrap();
+    }
+
+    #[test]
+    fn test_update_if_unchanged() {
+        let store = temp_store("unchanged");
+        let stored = store.create(&Tetrad::new("Work", "Office", "Mission", "Tools", "Plan")).unwrap();
+        let mut edited = stored.system.clone();
+        edited.name = "Session".to_string();
+
+        // Someone else saves first, so the session's edit is refused
+        let mut other = stored.system.clone();
+        other.name = "Elsewhere".to_string();
+        store.update(stored.id, other).unwrap();
+        let error = store.update_if_unchanged(stored.id, &stored.system, edited.clone()).unwrap_err();
+        assert_eq!(error.to_string(), "System 1 was changed while it was being edited; nothing was saved");
+        assert_eq!(store.get(stored.id).unwrap().system.name, "Elsewhere");
+
+        // A save in progress elsewhere holds the lock
+        let current = store.get(stored.id).unwrap().system;
+        let lock = store.root().join("1.lock");
+        fs::write(&lock, "").unwrap();
+        let error = store.update_if_unchanged(stored.id, &current, edited.clone()).unwrap_err();
+        assert!(error.to_string().starts_with("System 1 is being saved by someone else"), "{}", error);
+        fs::remove_file(&lock).unwrap();
+
+        store.update_if_unchanged(stored.id, &current, edited).unwrap();
+        assert_eq!(store.get(stored.id).unwrap().system.name, "Session");
+        assert!(!lock.exists());
+
+        fs::remove_dir_all(store.root()).unwrap();
+    }
+
+    #[test]
+    fn test_delete() {
+        let store = temp_store("delete");
+        let stored = store.create(&Triad::new("Will", "A", "P", "R")).unwrap();
+
+        store.delete(stored.id).unwrap();
+        assert!(store.get(stored.id).is_err());
+        assert_eq!(store.delete(stored.id).unwrap_err().to_string(), "No system with id 1");
+
+        fs::remove_dir_all(store.root()).unwrap();
+    }
+
+    #[test]
+    fn test_query_by_order_and_name() {
+        let store = temp_store("query");
+        store.create(&Triad::new("Morning will", "A", "P", "R")).unwrap();
+        store.create(&Tetrad::new("Morning work", "G", "I", "In", "D")).unwrap();
+        store.create(&Triad::new("Evening will", "A", "P", "R")).unwrap();
+
+        let names = |query: Query| -> Vec<String> {
+            store.query(&query).unwrap().into_iter().map(|stored| stored.system.name).collect()
+        };
+        assert_eq!(names(Query { order: Some(3), name: None }), ["Morning will", "Evening will"]);
+        assert_eq!(names(Query { order: None, name: Some("morning".to_string()) }), ["Morning will", "Morning work"]);
+        assert_eq!(names(Query { order: Some(3), name: Some("EVENING".to_string()) }), ["Evening will"]);
+        assert_eq!(names(Query::default()).len(), 3);
+
+        fs::remove_dir_all(store.root()).unwrap();
+    }
+
+    #[test]
+    fn test_policy_is_per_workspace() {
+        let store = temp_store("policy");
+        assert_eq!(store.policy().unwrap(), ValidationPolicy::default());
+
+        store.set_policy(&ValidationPolicy::strict()).unwrap();
+        assert_eq!(Store::open(store.root()).unwrap().policy().unwrap(), ValidationPolicy::strict());
+        // The policy file is not mistaken for a record
+        assert!(store.list().unwrap().is_empty());
+
+        fs::remove_dir_all(store.root()).unwrap();
+    }
+
+    #[test]
+    fn test_corrupt_record_is_reported() {
+        let store = temp_store("corrupt");
+        fs::write(store.root().join("7.json"), "{\"id\": 7}").unwrap();
+
+        assert!(matches!(store.get(7), Err(SystematicsError::Storage(_))));
+        let error = store.get(7).unwrap_err().to_string();
+        assert!(error.ends_with("missing system"), "{}", error);
+
+        fs::remove_dir_all(store.root()).unwrap();
+    }
+}
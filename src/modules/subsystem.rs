@@ -0,0 +1,358 @@
+use std::fmt;
+
+use serde::Serialize;
+
+use crate::modules::connective::{Connective, Direction, Position};
+use crate::modules::document::{SystemDocument, TermDocument};
+use crate::modules::error::SystematicsError;
+
+/// A value in the larger system that embedding would overwrite
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum Conflict {
+    /// The position already holds a user instance other than the incoming one
+    Term {
+        position: Position,
+        existing: String,
+        incoming: String,
+    },
+    /// The position already nests a system other than the incoming one
+    Child {
+        position: Position,
+        existing: u64,
+        incoming: u64,
+    },
+    /// The connective already carries a user label other than the incoming one
+    Connective {
+        from: Position,
+        to: Position,
+        existing: String,
+        incoming: String,
+    },
+    /// The connective already has a direction other than the incoming one
+    Direction {
+        from: Position,
+        to: Position,
+        existing: Direction,
+        incoming: Direction,
+    },
+}
+
+impl fmt::Display for Conflict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Conflict::Term { position, existing, incoming } => {
+                write!(f, "{}: '{}' → '{}'", position, existing, incoming)
+            }
+            Conflict::Child { position, existing, incoming } => {
+                write!(f, "{}: nested system {} → {}", position, existing, incoming)
+            }
+            Conflict::Connective { from, to, existing, incoming } => {
+                write!(f, "{}<>{}: '{}' → '{}'", from, to, existing, incoming)
+            }
+            Conflict::Direction { from, to, existing, incoming } => {
+                write!(f, "{}<>{}: {}{}{} → {}{}{}", from, to, from, existing.symbol(), to, from, incoming.symbol(), to)
+            }
+        }
+    }
+}
+
+/// A larger system with a smaller one placed inside it
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Embedding {
+    /// The larger system after embedding; incoming values win every conflict
+    pub system: SystemDocument,
+    pub conflicts: Vec<Conflict>,
+}
+
+impl SystemDocument {
+    /// The system formed by some of this one's positions, e.g. the Dyad of A and D in a Tetrad.
+    ///
+    /// The n-th position given becomes position n of the new system, so `[D, A]` makes
+    /// D the first term. Terms keep their nested systems, and connectives between the
+    /// chosen positions keep their label, notes and direction.
+    pub fn subsystem(&self, positions: &[Position]) -> Result<SystemDocument, SystematicsError> {
+        self.check_positions(positions)?;
+        let code: String = positions.iter().map(|position| position.letter()).collect();
+        let mut subsystem = SystemDocument::canonical(positions.len(), &format!("{} {}", self.name, code))?;
+
+        let sources = positions.iter().map(|position| &self.terms[position.index()]);
+        if subsystem.order == 1 {
+            // The Monad starts with no terms
+            subsystem.terms = sources
+                .enumerate()
+                .map_while(|(index, source)| {
+                    Some(TermDocument {
+                        position: Position::from_index(index)?,
+                        canonical: None,
+                        instance: source.instance.clone(),
+                        child: source.child,
+                    })
+                })
+                .collect();
+        } else {
+            for (term, source) in subsystem.terms.iter_mut().zip(sources) {
+                term.instance = source.instance.clone();
+                term.child = source.child;
+            }
+        }
+
+        for connective in subsystem.connectives.iter_mut() {
+            let (from, to) = (positions[connective.from.index()], positions[connective.to.index()]);
+            if let Some(source) = self.connectives.iter().find(|source| source.joins(from, to)) {
+                let label = source.label();
+                connective.user_label = (label != connective.canonical_name).then(|| label.to_string());
+                connective.notes = source.notes.clone();
+                connective.direction = if source.from == from { source.direction } else { source.direction.reversed() };
+            }
+        }
+        Ok(subsystem)
+    }
+
+    /// Places a smaller system's instances, nested systems, relabelled connectives and
+    /// connective directions at the given positions of this one, reporting every user
+    /// value that gets overwritten.
+    ///
+    /// Position n of the smaller system goes to the n-th position given.
+    pub fn embed(&self, smaller: &SystemDocument, positions: &[Position]) -> Result<Embedding, SystematicsError> {
+        self.check_positions(positions)?;
+        smaller.to_system()?;
+        if smaller.terms.len() != positions.len() {
+            return Err(SystematicsError::Structure(format!(
+                "{} has {} terms but {} positions were given",
+                smaller.kind,
+                smaller.terms.len(),
+                positions.len()
+            )));
+        }
+
+        let mut system = self.clone();
+        let mut conflicts = Vec::new();
+        for (incoming, position) in smaller.terms.iter().zip(positions) {
+            let term = &mut system.terms[position.index()];
+            if is_user_value(term) && term.instance != incoming.instance {
+                conflicts.push(Conflict::Term {
+                    position: *position,
+                    existing: term.instance.clone(),
+                    incoming: incoming.instance.clone(),
+                });
+            }
+            term.instance = incoming.instance.clone();
+            if let Some(child) = incoming.child {
+                if let Some(existing) = term.child.filter(|&existing| existing != child) {
+                    conflicts.push(Conflict::Child {
+                        position: *position,
+                        existing,
+                        incoming: child,
+                    });
+                }
+                term.child = Some(child);
+            }
+        }
+
+        let carried = |connective: &&Connective| connective.is_relabelled() || connective.direction != Direction::Bidirectional;
+        for incoming in smaller.connectives.iter().filter(carried) {
+            let (from, to) = (positions[incoming.from.index()], positions[incoming.to.index()]);
+            let Some(connective) = system.connectives.iter_mut().find(|connective| connective.joins(from, to)) else {
+                continue;
+            };
+            if incoming.is_relabelled() {
+                if connective.is_relabelled() && connective.label() != incoming.label() {
+                    conflicts.push(Conflict::Connective {
+                        from: connective.from,
+                        to: connective.to,
+                        existing: connective.label().to_string(),
+                        incoming: incoming.label().to_string(),
+                    });
+                }
+                relabel(connective, incoming);
+            }
+            if incoming.direction != Direction::Bidirectional {
+                let direction = if connective.from == from { incoming.direction } else { incoming.direction.reversed() };
+                if connective.direction != Direction::Bidirectional && connective.direction != direction {
+                    conflicts.push(Conflict::Direction {
+                        from: connective.from,
+                        to: connective.to,
+                        existing: connective.direction,
+                        incoming: direction,
+                    });
+                }
+                connective.direction = direction;
+            }
+        }
+        Ok(Embedding { system, conflicts })
+    }
+
+    /// Positions must be distinct and exist in this system
+    fn check_positions(&self, positions: &[Position]) -> Result<(), SystematicsError> {
+        self.to_system()?;
+        for (index, position) in positions.iter().enumerate() {
+            if position.index() >= self.terms.len() {
+                return Err(SystematicsError::Structure(format!("{} has no position {}", self.kind, position)));
+            }
+            if positions[..index].contains(position) {
+                return Err(SystematicsError::Structure(format!("Position {} is given more than once", position)));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Check if a term holds something the user typed rather than its canonical default
+fn is_user_value(term: &TermDocument) -> bool {
+    term.canonical.as_deref().is_none_or(|canonical| canonical != term.instance)
+}
+
+/// Copies an incoming label onto a connective, keeping its own notes unless it has none
+fn relabel(connective: &mut Connective, incoming: &Connective) {
+    let label = incoming.label();
+    connective.user_label = (label != connective.canonical_name).then(|| label.to_string());
+    if connective.notes.is_none() {
+        connective.notes = incoming.notes.clone();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::dyad::Dyad;
+    use crate::modules::octad::Octad;
+    use crate::modules::tetrad::Tetrad;
+
+    fn positions(letters: &str) -> Vec<Position> {
+        letters.chars().map(Position::new).collect()
+    }
+
+    #[test]
+    fn test_dyad_inside_a_tetrad() {
+        let tetrad = SystemDocument::from_system(&Tetrad::new("Work", "Office", "Mission", "Tools", "Plan"));
+        let dyad = tetrad.subsystem(&positions("AD")).unwrap();
+
+        assert_eq!(dyad.kind, "Dyad");
+        assert_eq!(dyad.name, "Work AD");
+        assert_eq!(dyad.instances(), ["Office", "Plan"]);
+        assert!(dyad.connectives.is_empty());
+    }
+
+    #[test]
+    fn test_tetrad_inside_an_octad_keeps_connectives() {
+        let octad = Octad::new("Firm", "A1", "B1", "C1", "D1", "E1", "F1", "G1", "H1");
+        let mut document = SystemDocument::from_system(&octad);
+        let connective = document.connectives.iter_mut().find(|connective| connective.joins('C', 'G')).unwrap();
+        connective.user_label = Some("Reach".to_string());
+        connective.direction = Direction::Forward;
+        connective.notes = Some("Checked yearly".to_string());
+
+        let tetrad = document.subsystem(&positions("GACE")).unwrap();
+        assert_eq!(tetrad.kind, "Tetrad");
+        assert_eq!(tetrad.instances(), ["G1", "A1", "C1", "E1"]);
+
+        // G and C are now positions A and C, and the connective points from C to A
+        let carried = tetrad.connectives.iter().find(|connective| connective.joins('A', 'C')).unwrap();
+        assert_eq!(carried.label(), "Reach");
+        assert_eq!(carried.direction, Direction::Backward);
+        assert_eq!(carried.notes.as_deref(), Some("Checked yearly"));
+
+        // Unlabelled connectives bring the Octad's name for the pair
+        let grounding = tetrad.connectives.iter().find(|connective| connective.joins('B', 'C')).unwrap();
+        assert_eq!(grounding.label(), "Grounding");
+    }
+
+    #[test]
+    fn test_invalid_positions() {
+        let tetrad = SystemDocument::from_system(&Tetrad::new("Work", "G", "I", "In", "D"));
+
+        assert_eq!(tetrad.subsystem(&positions("AE")).unwrap_err().to_string(), "Tetrad has no position E");
+        assert_eq!(tetrad.subsystem(&positions("ABA")).unwrap_err().to_string(), "Position A is given more than once");
+        assert!(matches!(tetrad.subsystem(&[]), Err(SystematicsError::UnknownOrder(0))));
+    }
+
+    #[test]
+    fn test_embed_without_conflicts() {
+        let tetrad = SystemDocument::from_system(&Tetrad::new("Work", "Ground", "Mission", "Instrumental", "Plan"));
+        let dyad = SystemDocument::from_system(&Dyad::new("Pair", "Office", "Tools"));
+
+        let embedding = tetrad.embed(&dyad, &positions("AC")).unwrap();
+        assert!(embedding.conflicts.is_empty());
+        assert_eq!(embedding.system.instances(), ["Office", "Mission", "Tools", "Plan"]);
+        assert_eq!(embedding.system.subsystem(&positions("AC")).unwrap().instances(), dyad.instances());
+    }
+
+    #[test]
+    fn test_embed_reports_conflicts() {
+        let mut octad = SystemDocument::from_system(&Octad::new("Firm", "A1", "B1", "C1", "D1", "E1", "F1", "G1", "H1"));
+        octad.connectives.iter_mut().find(|connective| connective.joins('A', 'C')).unwrap().user_label =
+            Some("Roots".to_string());
+        let mut tetrad = SystemDocument::from_system(&Tetrad::new("Work", "A1", "Mission", "C2", "D1"));
+        tetrad.connectives[0].user_label = Some("Calling".to_string());
+
+        let embedding = octad.embed(&tetrad, &positions("ACEG")).unwrap();
+        assert_eq!(
+            embedding.conflicts,
+            [
+                Conflict::Term {
+                    position: Position::new('C'),
+                    existing: "C1".to_string(),
+                    incoming: "Mission".to_string()
+                },
+                Conflict::Term {
+                    position: Position::new('E'),
+                    existing: "E1".to_string(),
+                    incoming: "C2".to_string()
+                },
+                Conflict::Term {
+                    position: Position::new('G'),
+                    existing: "G1".to_string(),
+                    incoming: "D1".to_string()
+                },
+                Conflict::Connective {
+                    from: Position::new('A'),
+                    to: Position::new('C'),
+                    existing: "Roots".to_string(),
+                    incoming: "Calling".to_string()
+                },
+            ]
+        );
+        assert_eq!(embedding.conflicts[3].to_string(), "A<>C: 'Roots' → 'Calling'");
+        assert_eq!(embedding.system.connectives.iter().find(|c| c.joins('A', 'C')).unwrap().label(), "Calling");
+        assert!(octad.embed(&tetrad, &positions("ACE")).is_err());
+    }
+
+    #[test]
+    fn test_embed_carries_children_and_directions() {
+        let mut octad = SystemDocument::from_system(&Octad::new("Firm", "A1", "B1", "C1", "D1", "E1", "F1", "G1", "H1"));
+        octad.terms[2].child = Some(7);
+        octad.connectives.iter_mut().find(|connective| connective.joins('A', 'C')).unwrap().direction = Direction::Forward;
+        let mut tetrad = SystemDocument::from_system(&Tetrad::new("Work", "A1", "C1", "E1", "G1"));
+        tetrad.terms[1].child = Some(9);
+        tetrad.terms[2].child = Some(8);
+        // B->A in the Tetrad lands as C->A, i.e. A<-C, in the Octad
+        tetrad.connectives.iter_mut().find(|connective| connective.joins('A', 'B')).unwrap().direction = Direction::Backward;
+        tetrad.connectives.iter_mut().find(|connective| connective.joins('C', 'D')).unwrap().direction = Direction::Forward;
+
+        let embedding = octad.embed(&tetrad, &positions("ACEG")).unwrap();
+        assert_eq!(
+            embedding.conflicts,
+            [
+                Conflict::Child {
+                    position: Position::new('C'),
+                    existing: 7,
+                    incoming: 9
+                },
+                Conflict::Direction {
+                    from: Position::new('A'),
+                    to: Position::new('C'),
+                    existing: Direction::Forward,
+                    incoming: Direction::Backward
+                },
+            ]
+        );
+        assert_eq!(embedding.conflicts[0].to_string(), "C: nested system 7 → 9");
+        assert_eq!(embedding.conflicts[1].to_string(), "A<>C: A->C → A<-C");
+        let system = embedding.system;
+        assert_eq!((system.terms[2].child, system.terms[4].child), (Some(9), Some(8)));
+        let direction = |a, b| system.connectives.iter().find(|connective| connective.joins(a, b)).unwrap().direction;
+        assert_eq!(direction('A', 'C'), Direction::Backward);
+        assert_eq!(direction('E', 'G'), Direction::Forward);
+    }
+}
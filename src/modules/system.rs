@@ -0,0 +1,271 @@
+use serde::Serialize;
+
+use crate::modules::connective::{Connective, Position};
+use crate::modules::diagram::SvgOptions;
+use crate::modules::document::SystemDocument;
+use crate::modules::graph::DotOptions;
+use crate::modules::permutations::Permutation;
+use crate::modules::template;
+use crate::modules::triad::Triad;
+
+/// Common interface implemented by every systematic structure.
+///
+/// Each term struct keeps its own typed fields and inherent methods; this trait
+/// exposes the parts they share so callers can work with a `Box<dyn System>`
+/// or be generic over any order.
+pub trait System {
+    /// Name of the structure, e.g. "Triad" or "Hexad"
+    fn kind(&self) -> &'static str;
+
+    /// Number of canonical terms in the system
+    fn order(&self) -> usize;
+
+    /// User-given name of this system
+    fn name(&self) -> &str;
+
+    /// Canonical term names, in positional order (A, B, C, ...)
+    fn canonical_terms(&self) -> Vec<&'static str>;
+
+    /// User instances for each canonical position, in positional order
+    fn instances(&self) -> Vec<String>;
+
+    /// Connectives between canonical positions
+    fn connectives(&self) -> &[Connective];
+
+    /// Connective joining two positions, in either direction
+    fn connective(&self, from: char, to: char) -> Option<&Connective> {
+        let (from, to) = (Position::from(from), Position::from(to));
+        self.connectives().iter().find(|connective| connective.joins(from, to))
+    }
+
+    /// Every combination of three terms as a Triad, e.g. 4 in a Tetrad and 56 in an Octad.
+    ///
+    /// Combinations come in position order (ABC, ABD, ACD, BCD, ...), and within one
+    /// the earliest position is taken as active and the latest as reconciling. Terms
+    /// past position Z have no letter and are left out.
+    fn triads(&self) -> Vec<SubTriad> {
+        let instances = self.instances();
+        let count = instances.len().min(Position::COUNT);
+        let mut triads = Vec::new();
+        for a in 0..count {
+            for b in a + 1..count {
+                for c in b + 1..count {
+                    let positions = [a, b, c].map(|index| Position::from_index(index).expect("index is within A to Z"));
+                    let code: String = positions.iter().map(|position| position.letter()).collect();
+                    let name = format!("{} {}", self.name(), code);
+                    triads.push(SubTriad {
+                        positions,
+                        triad: Triad::new(&name, &instances[a], &instances[b], &instances[c]),
+                        permutations: Vec::new(),
+                    });
+                }
+            }
+        }
+        triads
+    }
+
+    /// Graphviz DOT source with a node per term and an edge per connective
+    fn to_dot(&self, options: &DotOptions) -> String {
+        SystemDocument::from_system(self).to_dot(options)
+    }
+
+    /// SVG drawing of the system's Bennett figure, labelled with its terms
+    fn to_svg(&self, options: &SvgOptions) -> String {
+        SystemDocument::from_system(self).to_svg(options)
+    }
+
+    /// Core attribute shared by all systems of this order
+    fn attribute_description(&self) -> &'static str;
+
+    /// Print system details to stdout
+    fn display(&self);
+}
+
+/// A Triad drawn from three positions of a larger system
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SubTriad {
+    /// Positions taking the active, passive and reconciling roles
+    pub positions: [Position; 3],
+    pub triad: Triad,
+    /// The six laws, once asked for with `with_six_laws`
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub permutations: Vec<Permutation>,
+}
+
+impl SubTriad {
+    /// Positions as letters, e.g. "ACD"
+    pub fn code(&self) -> String {
+        self.positions.iter().map(|position| position.letter()).collect()
+    }
+
+    /// Check if the triad draws on a position
+    pub fn contains(&self, position: impl Into<Position>) -> bool {
+        self.positions.contains(&position.into())
+    }
+
+    /// Adds the triad's six laws
+    pub fn with_six_laws(mut self) -> Self {
+        self.permutations = self.triad.six_laws();
+        self
+    }
+}
+
+/// Name of the structure with the given number of terms (1 to 12)
+pub fn kind_name(order: usize) -> Option<&'static str> {
+    match order {
+        1 => Some("Monad"),
+        2 => Some("Dyad"),
+        3 => Some("Triad"),
+        order => template::for_order(order).map(|template| template.kind),
+    }
+}
+
+/// Number of terms for a structure name such as "triad" (case-insensitive)
+pub fn order_for_kind(kind: &str) -> Option<usize> {
+    (1..=12).find(|&order| kind_name(order).is_some_and(|name| name.eq_ignore_ascii_case(kind)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::decad::Decad;
+    use crate::modules::dodecad::Dodecad;
+    use crate::modules::dyad::Dyad;
+    use crate::modules::ennead::Ennead;
+    use crate::modules::heptad::Heptad;
+    use crate::modules::hexad::Hexad;
+    use crate::modules::monad::Monad;
+    use crate::modules::octad::Octad;
+    use crate::modules::pentad::Pentad;
+    use crate::modules::tetrad::Tetrad;
+    use crate::modules::triad::Triad;
+    use crate::modules::undecad::Undecad;
+
+    fn all_systems() -> Vec<Box<dyn System>> {
+        vec![
+            Box::new(Monad::new("Monad")),
+            Box::new(Dyad::new("Dyad", "Essence", "Existence")),
+            Box::new(Triad::new("Triad", "Active", "Passive", "Reconciling")),
+            Box::new(Tetrad::new("Tetrad", "G", "I", "In", "D")),
+            Box::new(Pentad::new("Pentad", "A", "B", "C", "D", "E")),
+            Box::new(Hexad::new("Hexad", "A", "B", "C", "D", "E", "F")),
+            Box::new(Heptad::new("Heptad", "A", "B", "C", "D", "E", "F", "G")),
+            Box::new(Octad::new("Octad", "A", "B", "C", "D", "E", "F", "G", "H")),
+            Box::new(Ennead::new("Ennead", "A", "B", "C", "D", "E", "F", "G", "H", "I")),
+            Box::new(Decad::new("Decad", "A", "B", "C", "D", "E", "F", "G", "H", "I", "J")),
+            Box::new(Undecad::new("Undecad", "A", "B", "C", "D", "E", "F", "G", "H", "I", "J", "K")),
+            Box::new(Dodecad::new("Dodecad", "A", "B", "C", "D", "E", "F", "G", "H", "I", "J", "K", "L")),
+        ]
+    }
+
+    #[test]
+    fn test_kind_names() {
+        let kinds: Vec<&str> = all_systems().iter().map(|s| s.kind()).collect();
+        let names: Vec<&str> = (1..=12).filter_map(kind_name).collect();
+        assert_eq!(names, kinds);
+        assert_eq!(kind_name(13), None);
+        assert_eq!(order_for_kind("triad"), Some(3));
+        assert_eq!(order_for_kind("UNDECAD"), Some(11));
+        assert_eq!(order_for_kind("Enneagram"), None);
+    }
+
+    #[test]
+    fn test_orders() {
+        let orders: Vec<usize> = all_systems().iter().map(|s| s.order()).collect();
+        assert_eq!(orders, vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12]);
+    }
+
+    #[test]
+    fn test_instances_match_canonical_terms() {
+        // The Monad has no fixed positions, so it is skipped here
+        for system in all_systems().iter().skip(1) {
+            assert_eq!(system.canonical_terms().len(), system.order(), "{}", system.name());
+            assert_eq!(system.instances().len(), system.order(), "{}", system.name());
+        }
+    }
+
+    #[test]
+    fn test_connective_counts() {
+        let counts: Vec<usize> = all_systems().iter().map(|s| s.connectives().len()).collect();
+        assert_eq!(counts, vec![0, 0, 0, 6, 10, 15, 21, 28, 36, 45, 55, 66]);
+    }
+
+    #[test]
+    fn test_connective_positions_within_order() {
+        for system in all_systems() {
+            for connective in system.connectives() {
+                let (from, to) = (connective.from, connective.to);
+                assert!(from < to, "{}: {}<>{}", system.name(), from, to);
+                assert!(to.index() < system.order(), "{}: {}<>{}", system.name(), from, to);
+            }
+        }
+    }
+
+    #[test]
+    fn test_attribute_descriptions() {
+        let systems = all_systems();
+        assert_eq!(systems[0].attribute_description(), Monad::TERM_ATTRIBUTE_DESCRIPTION);
+        assert_eq!(systems[3].attribute_description(), Tetrad::TERM_ATTRIBUTE_DESCRIPTION);
+        assert_eq!(systems[11].attribute_description(), Dodecad::TERM_ATTRIBUTE_DESCRIPTION);
+    }
+
+    #[test]
+    fn test_generic_over_system() {
+        fn describe<S: System>(system: &S) -> String {
+            format!("{} ({}): {}", system.name(), system.order(), system.instances().join(", "))
+        }
+
+        let triad = Triad::new("Will", "Intention", "Resistance", "Resolution");
+        assert_eq!(describe(&triad), "Will (3): Intention, Resistance, Resolution");
+    }
+
+    #[test]
+    fn test_triad_counts() {
+        // n choose 3; the lone Monad term makes no triad
+        let counts: Vec<usize> = all_systems().iter().map(|s| s.triads().len()).collect();
+        assert_eq!(counts, vec![0, 0, 1, 4, 10, 20, 35, 56, 84, 120, 165, 220]);
+    }
+
+    #[test]
+    fn test_triads_of_a_tetrad() {
+        let tetrad = Tetrad::new("Work", "Office", "Mission", "Tools", "Plan");
+        let triads = tetrad.triads();
+
+        let codes: Vec<String> = triads.iter().map(SubTriad::code).collect();
+        assert_eq!(codes, ["ABC", "ABD", "ACD", "BCD"]);
+        assert_eq!(triads[2].triad, Triad::new("Work ACD", "Office", "Tools", "Plan"));
+        assert!(triads[2].contains('D'));
+        assert!(!triads[2].contains('B'));
+        assert!(triads[2].permutations.is_empty());
+
+        let with_laws = triads[3].clone().with_six_laws();
+        assert_eq!(with_laws.permutations[1].display(), "Interaction: Mission → Plan → Tools");
+    }
+
+    #[test]
+    fn test_tetrad_connective_labels() {
+        let tetrad = Tetrad::new("Test", "F", "V", "M", "P");
+        let connectives = tetrad.connectives();
+        assert_eq!(connectives[0].code(), "A<>B");
+        assert_eq!(connectives[0].label(), "Motivational imperative");
+        assert_eq!(connectives[5].code(), "C<>D");
+        assert_eq!(connectives[5].label(), "Demonstrable activity");
+    }
+
+    #[test]
+    fn test_connective_lookup() {
+        for system in all_systems() {
+            for connective in system.connectives() {
+                let (from, to) = (connective.from.letter(), connective.to.letter());
+                assert_eq!(system.connective(from, to), Some(connective));
+                assert_eq!(system.connective(to, from), Some(connective));
+            }
+        }
+
+        let pentad = Pentad::new("Test", "A", "B", "C", "D", "E");
+        assert_eq!(pentad.connective('A', 'C').unwrap().canonical_name, "Operation");
+        assert_eq!(pentad.connective('c', 'a').unwrap().canonical_name, "Operation");
+        assert!(pentad.connective('A', 'A').is_none());
+        assert!(pentad.connective('A', 'F').is_none());
+    }
+}
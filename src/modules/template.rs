@@ -0,0 +1,202 @@
+use crate::modules::decad;
+use crate::modules::dodecad;
+use crate::modules::ennead;
+use crate::modules::heptad;
+use crate::modules::hexad;
+use crate::modules::octad;
+use crate::modules::pentad;
+use crate::modules::tetrad;
+use crate::modules::undecad;
+
+/// A canonical position within a template: its letter and term label
+#[derive(Debug, PartialEq)]
+pub struct TermTemplate {
+    pub position: char,
+    pub label: &'static str,
+}
+
+/// A canonical connective between two positions of a template, as listed in
+/// the connective catalogue: its Bennett-style name and a short description
+#[derive(Debug, PartialEq)]
+pub struct ConnectiveTemplate {
+    pub from: char,
+    pub to: char,
+    pub name: &'static str,
+    pub description: &'static str,
+}
+
+/// Declarative description of a system of a given order.
+///
+/// A template holds everything that is fixed for an order (term labels,
+/// position letters and the catalogue of canonical connectives), so a `SystemInstance`
+/// can be built for any order from data alone.
+#[derive(Debug, PartialEq)]
+pub struct SystemTemplate {
+    pub kind: &'static str,
+    pub order: usize,
+    pub attribute_description: &'static str,
+    pub terms: &'static [TermTemplate],
+    pub connectives: &'static [ConnectiveTemplate],
+}
+
+impl SystemTemplate {
+    /// Canonical term labels in positional order
+    pub fn canonical_terms(&self) -> Vec<&'static str> {
+        self.terms.iter().map(|term| term.label).collect()
+    }
+
+    /// Index of the term at the given position letter
+    pub fn position_index(&self, position: char) -> Option<usize> {
+        let position = position.to_ascii_uppercase();
+        self.terms.iter().position(|term| term.position == position)
+    }
+
+    /// Index of the connective joining two positions, in either direction
+    pub fn connective_index(&self, from: char, to: char) -> Option<usize> {
+        let (from, to) = (from.to_ascii_uppercase(), to.to_ascii_uppercase());
+        self.connectives.iter().position(|connective| {
+            (connective.from == from && connective.to == to) || (connective.from == to && connective.to == from)
+        })
+    }
+
+    /// Catalogue entry for the connective joining two positions, in either direction
+    pub fn connective(&self, from: char, to: char) -> Option<&'static ConnectiveTemplate> {
+        let connectives: &'static [ConnectiveTemplate] = self.connectives;
+        self.connective_index(from, to).map(|index| &connectives[index])
+    }
+}
+
+/// All templates that drive a `SystemInstance`, in ascending order
+pub fn all() -> Vec<&'static SystemTemplate> {
+    vec![
+        &tetrad::TEMPLATE,
+        &pentad::TEMPLATE,
+        &hexad::TEMPLATE,
+        &heptad::TEMPLATE,
+        &octad::TEMPLATE,
+        &ennead::TEMPLATE,
+        &decad::TEMPLATE,
+        &undecad::TEMPLATE,
+        &dodecad::TEMPLATE,
+    ]
+}
+
+/// Look up the template for a given number of terms
+pub fn for_order(order: usize) -> Option<&'static SystemTemplate> {
+    all().into_iter().find(|template| template.order == order)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::connective::Position;
+
+    #[test]
+    fn test_for_order() {
+        assert_eq!(for_order(4).unwrap().kind, "Tetrad");
+        assert_eq!(for_order(5).unwrap().kind, "Pentad");
+        assert_eq!(for_order(6).unwrap().kind, "Hexad");
+        assert_eq!(for_order(7).unwrap().kind, "Heptad");
+        assert_eq!(for_order(8).unwrap().kind, "Octad");
+        assert_eq!(for_order(9).unwrap().kind, "Ennead");
+        assert_eq!(for_order(10).unwrap().kind, "Decad");
+        assert_eq!(for_order(11).unwrap().kind, "Undecad");
+        assert_eq!(for_order(12).unwrap().kind, "Dodecad");
+        assert!(for_order(0).is_none());
+    }
+
+    #[test]
+    fn test_templates_are_consistent() {
+        for template in all() {
+            assert_eq!(template.terms.len(), template.order, "{}", template.kind);
+
+            // Positions are lettered A, B, C, ... in order
+            for (index, term) in template.terms.iter().enumerate() {
+                assert_eq!(Position::from_index(index), Some(Position::new(term.position)), "{}", template.kind);
+            }
+
+            // Every pair of positions is joined by exactly one connective
+            let order = template.order;
+            assert_eq!(template.connectives.len(), order * (order - 1) / 2, "{}", template.kind);
+            for connective in template.connectives {
+                assert!(template.position_index(connective.from).is_some());
+                assert!(template.position_index(connective.to).is_some());
+                assert_ne!(connective.from, connective.to);
+            }
+            for (i, a) in template.connectives.iter().enumerate() {
+                for b in &template.connectives[i + 1..] {
+                    let same = (a.from == b.from && a.to == b.to) || (a.from == b.to && a.to == b.from);
+                    assert!(!same, "{}: duplicate {}<>{}", template.kind, a.from, a.to);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_position_index() {
+        let template = for_order(6).unwrap();
+        assert_eq!(template.position_index('A'), Some(0));
+        assert_eq!(template.position_index('f'), Some(5));
+        assert_eq!(template.position_index('G'), None);
+    }
+
+    #[test]
+    fn test_connective_index_either_direction() {
+        let template = for_order(8).unwrap();
+        let forward = template.connective_index('A', 'C');
+        assert!(forward.is_some());
+        assert_eq!(forward, template.connective_index('C', 'A'));
+        assert_eq!(template.connective_index('A', 'A'), None);
+        assert_eq!(template.connective_index('A', 'Z'), None);
+    }
+
+    #[test]
+    fn test_connective_catalogue() {
+        let tetrad = for_order(4).unwrap().connective('B', 'A').unwrap();
+        assert_eq!(tetrad.name, "Motivational imperative");
+        assert_eq!(tetrad.description, "The ideal moves the ground to act");
+        assert_eq!(for_order(6).unwrap().connective('C', 'F').unwrap().name, "Choice");
+        assert_eq!(for_order(7).unwrap().connective('F', 'G').unwrap().name, "Benefit");
+        assert_eq!(for_order(8).unwrap().connective('E', 'H').unwrap().name, "Governance");
+        assert!(for_order(8).unwrap().connective('A', 'A').is_none());
+    }
+
+    #[test]
+    fn test_decad_and_undecad_follow_the_dodecad() {
+        let dodecad = for_order(12).unwrap();
+        for order in [10, 11] {
+            let template = for_order(order).unwrap();
+            let labels: Vec<&str> = dodecad.terms[12 - order..].iter().map(|term| term.label).collect();
+            assert_eq!(template.canonical_terms(), labels, "{}", template.kind);
+
+            let shift = |position: char| dodecad.terms[template.position_index(position).unwrap() + 12 - order].position;
+            for connective in template.connectives {
+                let same = dodecad.connective(shift(connective.from), shift(connective.to)).unwrap();
+                assert_eq!(connective.name, same.name, "{}", template.kind);
+            }
+        }
+    }
+
+    #[test]
+    fn test_catalogue_names_are_readable() {
+        for template in all() {
+            for (i, connective) in template.connectives.iter().enumerate() {
+                // Names are words, not identifiers such as "AB_resources_values"
+                assert!(!connective.name.contains('_'), "{}: {}", template.kind, connective.name);
+                assert!(!connective.description.is_empty(), "{}: {}", template.kind, connective.name);
+                // Nor placeholders that just join the two term labels, such as "Origin–Direction"
+                let label = |position| template.terms.iter().find(|term| term.position == position).unwrap().label;
+                let (from, to) = (label(connective.from), label(connective.to));
+                for joined in [format!("{from}–{to}"), format!("{from}-{to}"), format!("{to}–{from}"), format!("{to}-{from}")] {
+                    assert_ne!(connective.name, joined, "{}: placeholder name", template.kind);
+                }
+                assert!(
+                    template.connectives[i + 1..].iter().all(|other| other.name != connective.name),
+                    "{}: duplicate name {}",
+                    template.kind,
+                    connective.name
+                );
+            }
+        }
+    }
+}
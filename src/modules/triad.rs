@@ -1,6 +1,12 @@
-use std::io::{self, Write};
+use serde::Serialize;
 
-#[derive(Debug)]
+use crate::modules::connective::Connective;
+use crate::modules::error::SystematicsError;
+use crate::modules::permutations::{generate_six_permutations, Permutation};
+use crate::modules::prompter::{IoPrompter, Prompter};
+use crate::modules::system::System;
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct Triad {
     pub name: String,
     pub active: String,
@@ -21,56 +27,26 @@ impl Triad {
         }
     }
     
-    /// Interactive creation method - handles all input/output internally
-    pub fn create_interactive() -> Result<Self, Box<dyn std::error::Error>> {
-        println!("\n--- Creating a Triad ---");
-        
-        // Helper for optional input with default
-        let get_optional_input = |prompt: &str, default: &str| -> Result<String, Box<dyn std::error::Error>> {
-            let mut input = String::new();
-            print!("{}", prompt);
-            
-            if let Err(e) = io::stdout().flush() {
-                eprintln!("Warning: Could not flush output: {}", e);
-            }
-            
-            match io::stdin().read_line(&mut input) {
-                Ok(_) => {
-                    let trimmed = input.trim();
-                    
-                    if trimmed.is_empty() {
-                        Ok(default.to_string())
-                    } else {
-                        // Validate optional input if provided
-                        if trimmed.len() > 100 {
-                            return Err("Input is too long (max 100 characters)".into());
-                        }
-                        
-                        if !trimmed.chars().all(|c| c.is_alphanumeric() || c.is_whitespace() || ".,!?'-()".contains(c)) {
-                            return Err("Input contains invalid characters".into());
-                        }
-                        
-                        Ok(trimmed.to_string())
-                    }
-                }
-                Err(e) => {
-                    eprintln!("Error reading input: {}. Using default: {}", e, default);
-                    Ok(default.to_string())
-                }
-            }
-        };
-        
+    /// Interactive creation method on the terminal
+    pub fn create_interactive() -> Result<Self, SystematicsError> {
+        Self::create_with(&mut IoPrompter::stdio())
+    }
+
+    /// Interactive creation through any prompter
+    pub fn create_with(prompter: &mut dyn Prompter) -> Result<Self, SystematicsError> {
+        prompter.say("\n--- Creating a Triad ---")?;
+
         // Get inputs with canonical defaults
-        let name = get_optional_input("Enter a name for your Triad (or press Enter for 'Unnamed Triad'): ", "Unnamed Triad")?;
-        let active = get_optional_input("Enter the Active instance (or press Enter for 'Active'): ", "Active")?;
-        let passive = get_optional_input("Enter the Passive instance (or press Enter for 'Passive'): ", "Passive")?;
-        let reconciling = get_optional_input("Enter the Reconciling instance (or press Enter for 'Reconciling'): ", "Reconciling")?;
+        let name = prompter.optional("Enter a name for your Triad (or press Enter for 'Unnamed Triad'): ", "Unnamed Triad")?;
+        let active = prompter.optional("Enter the Active instance (or press Enter for 'Active'): ", "Active")?;
+        let passive = prompter.optional("Enter the Passive instance (or press Enter for 'Passive'): ", "Passive")?;
+        let reconciling = prompter.optional("Enter the Reconciling instance (or press Enter for 'Reconciling'): ", "Reconciling")?;
 
         let triad = Triad::new(&name, &active, &passive, &reconciling);
-        
+
         // Display the created triad
-        triad.display();
-        
+        prompter.say(&triad.details())?;
+
         Ok(triad)
     }
     
@@ -92,21 +68,73 @@ impl Triad {
         vec![self.active.clone(), self.passive.clone(), self.reconciling.clone()]
     }
     
+    /// The six named laws (Expansion to Freedom) over the active, passive and reconciling
+    /// instances, which take roles 1, 2 and 3
+    pub fn six_laws(&self) -> Vec<Permutation> {
+        generate_six_permutations(&self.active, &self.passive, &self.reconciling)
+    }
+
+    /// Same as `six_laws`
+    pub fn permutations(&self) -> Vec<Permutation> {
+        self.six_laws()
+    }
+
+    /// Formatted details of the triad
+    pub fn details(&self) -> String {
+        format!(
+            "\n--- Triad Details ---\nTriad Name: {}\nCore Attribute: {}\nActive: {}\nPassive: {}\nReconciling: {}\n---------------------",
+            self.name,
+            Self::TERM_ATTRIBUTE_DESCRIPTION,
+            self.active,
+            self.passive,
+            self.reconciling
+        )
+    }
+
     /// Display triad details
     pub fn display(&self) {
-        println!("\n--- Triad Details ---");
-        println!("Triad Name: {}", self.name);
-        println!("Core Attribute: {}", Self::TERM_ATTRIBUTE_DESCRIPTION);
-        println!("Active: {}", self.active);
-        println!("Passive: {}", self.passive);
-        println!("Reconciling: {}", self.reconciling);
-        println!("---------------------");
+        println!("{}", self.details());
+    }
+}
+
+impl System for Triad {
+    fn kind(&self) -> &'static str {
+        "Triad"
+    }
+
+    fn order(&self) -> usize {
+        3
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn canonical_terms(&self) -> Vec<&'static str> {
+        Self::get_canonical_terms()
+    }
+
+    fn instances(&self) -> Vec<String> {
+        self.get_instances()
+    }
+
+    fn connectives(&self) -> &[Connective] {
+        &[]
+    }
+
+    fn attribute_description(&self) -> &'static str {
+        Self::TERM_ATTRIBUTE_DESCRIPTION
+    }
+
+    fn display(&self) {
+        Triad::display(self)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::modules::prompter::ScriptedPrompter;
 
     #[test]
     fn test_triad_creation() {
@@ -195,6 +223,20 @@ mod tests {
         assert_eq!(triad.reconciling, "Balance!");
     }
 
+    #[test]
+    fn test_six_laws() {
+        let triad = Triad::new("Will", "Intention", "Resistance", "Resolution");
+        let laws = triad.six_laws();
+
+        assert_eq!(laws.len(), 6);
+        assert_eq!(laws[0].display(), "Expansion: Intention → Resistance → Resolution");
+        // Identity puts the passive first, then the reconciling, then the active
+        assert_eq!(laws[3].name, "Identity");
+        assert_eq!(laws[3].roles, [2, 3, 1]);
+        assert_eq!(laws[3].outcome, "Intention");
+        assert_eq!(triad.permutations(), laws);
+    }
+
     #[test]
     fn test_triad_with_unicode() {
         let triad = Triad::new("测试", "主动", "被动", "调和");
@@ -333,4 +375,13 @@ mod tests {
         assert_eq!(triad.passive, canonical[1]);
         assert_eq!(triad.reconciling, canonical[2]);
     }
+
+    #[test]
+    fn test_create_with_scripted_session() {
+        let mut prompter = ScriptedPrompter::new(&["Will", "Intention", "", "Resolution"]);
+        let triad = Triad::create_with(&mut prompter).unwrap();
+
+        assert_eq!(triad, Triad::new("Will", "Intention", "Passive", "Resolution"));
+        assert!(prompter.transcript().contains("--- Triad Details ---"));
+    }
 }
@@ -0,0 +1,373 @@
+use std::ops::{Deref, DerefMut};
+
+use crate::modules::connective::Connective;
+use crate::modules::error::SystematicsError;
+use crate::modules::instance::SystemInstance;
+use crate::modules::prompter::{IoPrompter, Prompter};
+use crate::modules::system::System;
+use crate::modules::template::{ConnectiveTemplate, SystemTemplate, TermTemplate};
+
+/// Canonical Undecad positions and connectives.
+///
+/// Like the Dodecad's, the terms are the attributes of the systems from the Undecad
+/// down to the Monad, and each connective keeps the Dodecad's name for the same pair.
+pub static TEMPLATE: SystemTemplate = SystemTemplate {
+    kind: "Undecad",
+    order: 11,
+    attribute_description: Undecad::TERM_ATTRIBUTE_DESCRIPTION,
+    terms: &[
+        TermTemplate { position: 'A', label: "Domination" },
+        TermTemplate { position: 'B', label: "Creativity" },
+        TermTemplate { position: 'C', label: "Pattern" },
+        TermTemplate { position: 'D', label: "Individuality" },
+        TermTemplate { position: 'E', label: "Structure" },
+        TermTemplate { position: 'F', label: "Repetition" },
+        TermTemplate { position: 'G', label: "Potentiality" },
+        TermTemplate { position: 'H', label: "Subsistence" },
+        TermTemplate { position: 'I', label: "Relatedness" },
+        TermTemplate { position: 'J', label: "Polarity" },
+        TermTemplate { position: 'K', label: "Wholeness" },
+    ],
+    connectives: &[
+        ConnectiveTemplate { from: 'A', to: 'B', name: "Conquest", description: "Power opening up new ground" },
+        ConnectiveTemplate { from: 'A', to: 'C', name: "Order", description: "Power imposing its form on things" },
+        ConnectiveTemplate { from: 'A', to: 'D', name: "Mastery", description: "Power concentrated in a single agent" },
+        ConnectiveTemplate { from: 'A', to: 'E', name: "Hierarchy", description: "Power fixed into ranks and offices" },
+        ConnectiveTemplate { from: 'A', to: 'F', name: "Enforcement", description: "Power maintained by repeated sanction" },
+        ConnectiveTemplate { from: 'A', to: 'G', name: "Ambition", description: "Power reaching toward what could be won" },
+        ConnectiveTemplate { from: 'A', to: 'H', name: "Control", description: "Power over the means of subsistence" },
+        ConnectiveTemplate { from: 'A', to: 'I', name: "Influence", description: "Power exercised through relationships" },
+        ConnectiveTemplate { from: 'A', to: 'J', name: "Conflict", description: "Power meeting opposing power" },
+        ConnectiveTemplate { from: 'A', to: 'K', name: "Authority", description: "Power the whole accepts as legitimate" },
+        ConnectiveTemplate { from: 'B', to: 'C', name: "Invention", description: "Creativity finding a new form" },
+        ConnectiveTemplate { from: 'B', to: 'D', name: "Originality", description: "Creativity bearing the mark of its maker" },
+        ConnectiveTemplate { from: 'B', to: 'E', name: "Construction", description: "Creativity building something that stands" },
+        ConnectiveTemplate { from: 'B', to: 'F', name: "Craft", description: "Creativity refined through practice" },
+        ConnectiveTemplate { from: 'B', to: 'G', name: "Imagination", description: "Creativity playing among possibilities" },
+        ConnectiveTemplate { from: 'B', to: 'H', name: "Enterprise", description: "Creativity turned to making a living" },
+        ConnectiveTemplate { from: 'B', to: 'I', name: "Collaboration", description: "Creativity shared between people" },
+        ConnectiveTemplate { from: 'B', to: 'J', name: "Tension", description: "Creativity sparked by opposites" },
+        ConnectiveTemplate { from: 'B', to: 'K', name: "Vision", description: "Creativity that sees the whole" },
+        ConnectiveTemplate { from: 'C', to: 'D', name: "Character", description: "Pattern taken up as individual style" },
+        ConnectiveTemplate { from: 'C', to: 'E', name: "Architecture", description: "Pattern realised in enduring structure" },
+        ConnectiveTemplate { from: 'C', to: 'F', name: "Rhythm", description: "Pattern recurring in time" },
+        ConnectiveTemplate { from: 'C', to: 'G', name: "Blueprint", description: "Pattern held as a possibility not yet built" },
+        ConnectiveTemplate { from: 'C', to: 'H', name: "Habitat", description: "Pattern of the conditions that sustain life" },
+        ConnectiveTemplate { from: 'C', to: 'I', name: "Network", description: "Pattern of connections among parts" },
+        ConnectiveTemplate { from: 'C', to: 'J', name: "Symmetry", description: "Pattern balanced between opposites" },
+        ConnectiveTemplate { from: 'C', to: 'K', name: "Harmony", description: "Pattern in which the whole agrees with itself" },
+        ConnectiveTemplate { from: 'D', to: 'E', name: "Role", description: "An individual placed within a structure" },
+        ConnectiveTemplate { from: 'D', to: 'F', name: "Habit", description: "An individual shaped by repetition" },
+        ConnectiveTemplate { from: 'D', to: 'G', name: "Aspiration", description: "An individual reaching toward its potential" },
+        ConnectiveTemplate { from: 'D', to: 'H', name: "Livelihood", description: "An individual sustaining its own existence" },
+        ConnectiveTemplate { from: 'D', to: 'I', name: "Encounter", description: "An individual meeting another" },
+        ConnectiveTemplate { from: 'D', to: 'J', name: "Identity", description: "An individual defined by what it is not" },
+        ConnectiveTemplate { from: 'D', to: 'K', name: "Vocation", description: "An individual finding its place in the whole" },
+        ConnectiveTemplate { from: 'E', to: 'F', name: "Procedure", description: "Structure enacted again and again" },
+        ConnectiveTemplate { from: 'E', to: 'G', name: "Capacity", description: "Structure that makes new things possible" },
+        ConnectiveTemplate { from: 'E', to: 'H', name: "Infrastructure", description: "Structure that keeps life going" },
+        ConnectiveTemplate { from: 'E', to: 'I', name: "Organisation", description: "Structure ordering relationships" },
+        ConnectiveTemplate { from: 'E', to: 'J', name: "Balance", description: "Structure holding opposing forces in check" },
+        ConnectiveTemplate { from: 'E', to: 'K', name: "Framework", description: "Structure that holds the whole together" },
+        ConnectiveTemplate { from: 'F', to: 'G', name: "Practice", description: "Repetition that builds capability" },
+        ConnectiveTemplate { from: 'F', to: 'H', name: "Maintenance", description: "Repetition that keeps things alive" },
+        ConnectiveTemplate { from: 'F', to: 'I', name: "Custom", description: "Repetition shared among people" },
+        ConnectiveTemplate { from: 'F', to: 'J', name: "Cycle", description: "Repetition swinging between poles" },
+        ConnectiveTemplate { from: 'F', to: 'K', name: "Tradition", description: "Repetition that carries the whole forward" },
+        ConnectiveTemplate { from: 'G', to: 'H', name: "Growth", description: "Potential realised as means of living" },
+        ConnectiveTemplate { from: 'G', to: 'I', name: "Opportunity", description: "Potential opened up by relationships" },
+        ConnectiveTemplate { from: 'G', to: 'J', name: "Choice", description: "Potential divided between alternatives" },
+        ConnectiveTemplate { from: 'G', to: 'K', name: "Promise", description: "Potential of the whole not yet realised" },
+        ConnectiveTemplate { from: 'H', to: 'I', name: "Exchange", description: "Subsistence gained through relationships" },
+        ConnectiveTemplate { from: 'H', to: 'J', name: "Scarcity", description: "Subsistence caught between need and supply" },
+        ConnectiveTemplate { from: 'H', to: 'K', name: "Economy", description: "Subsistence organised across the whole" },
+        ConnectiveTemplate { from: 'I', to: 'J', name: "Dialogue", description: "Relatedness that holds difference" },
+        ConnectiveTemplate { from: 'I', to: 'K', name: "Community", description: "Relatedness forming a whole" },
+        ConnectiveTemplate { from: 'J', to: 'K', name: "Reconciliation", description: "Opposites united in the whole" },
+    ],
+};
+
+/// Typed view over a 11-term `SystemInstance` built from the Undecad template
+#[derive(Debug, Clone, PartialEq)]
+pub struct Undecad {
+    system: SystemInstance,
+}
+
+impl Undecad {
+    pub const TERM_ATTRIBUTE_DESCRIPTION: &'static str = "Domination";
+
+    /// Creates a new Undecad with user instances for canonical positions
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        name: &str,
+        domination: &str,
+        creativity: &str,
+        pattern: &str,
+        individuality: &str,
+        structure: &str,
+        repetition: &str,
+        potentiality: &str,
+        subsistence: &str,
+        relatedness: &str,
+        polarity: &str,
+        wholeness: &str,
+    ) -> Self {
+        let instances = [
+            domination,
+            creativity,
+            pattern,
+            individuality,
+            structure,
+            repetition,
+            potentiality,
+            subsistence,
+            relatedness,
+            polarity,
+            wholeness,
+        ];
+        let system = SystemInstance::with_instances(&TEMPLATE, name, &instances)
+            .expect("Undecad template has eleven positions");
+        Undecad { system }
+    }
+
+    /// Interactive creation method on the terminal
+    pub fn create_interactive() -> Result<Self, SystematicsError> {
+        Self::create_with(&mut IoPrompter::stdio())
+    }
+
+    /// Interactive creation through any prompter
+    pub fn create_with(prompter: &mut dyn Prompter) -> Result<Self, SystematicsError> {
+        SystemInstance::create_with(&TEMPLATE, prompter).map(|system| Undecad { system })
+    }
+
+    /// Domination instance (position A)
+    pub fn domination(&self) -> &str {
+        self.system.instance_at(0)
+    }
+
+    /// Creativity instance (position B)
+    pub fn creativity(&self) -> &str {
+        self.system.instance_at(1)
+    }
+
+    /// Pattern instance (position C)
+    pub fn pattern(&self) -> &str {
+        self.system.instance_at(2)
+    }
+
+    /// Individuality instance (position D)
+    pub fn individuality(&self) -> &str {
+        self.system.instance_at(3)
+    }
+
+    /// Structure instance (position E)
+    pub fn structure(&self) -> &str {
+        self.system.instance_at(4)
+    }
+
+    /// Repetition instance (position F)
+    pub fn repetition(&self) -> &str {
+        self.system.instance_at(5)
+    }
+
+    /// Potentiality instance (position G)
+    pub fn potentiality(&self) -> &str {
+        self.system.instance_at(6)
+    }
+
+    /// Subsistence instance (position H)
+    pub fn subsistence(&self) -> &str {
+        self.system.instance_at(7)
+    }
+
+    /// Relatedness instance (position I)
+    pub fn relatedness(&self) -> &str {
+        self.system.instance_at(8)
+    }
+
+    /// Polarity instance (position J)
+    pub fn polarity(&self) -> &str {
+        self.system.instance_at(9)
+    }
+
+    /// Wholeness instance (position K)
+    pub fn wholeness(&self) -> &str {
+        self.system.instance_at(10)
+    }
+
+    /// Get canonical term names
+    pub fn get_canonical_terms() -> Vec<&'static str> {
+        TEMPLATE.canonical_terms()
+    }
+
+    /// Get all user instances
+    pub fn get_instances(&self) -> Vec<String> {
+        self.system.instances().to_vec()
+    }
+
+    /// Underlying data-driven system
+    pub fn into_instance(self) -> SystemInstance {
+        self.system
+    }
+}
+
+impl Deref for Undecad {
+    type Target = SystemInstance;
+
+    fn deref(&self) -> &SystemInstance {
+        &self.system
+    }
+}
+
+impl DerefMut for Undecad {
+    fn deref_mut(&mut self) -> &mut SystemInstance {
+        &mut self.system
+    }
+}
+
+impl TryFrom<SystemInstance> for Undecad {
+    type Error = SystematicsError;
+
+    fn try_from(system: SystemInstance) -> Result<Self, Self::Error> {
+        if !std::ptr::eq(system.template(), &TEMPLATE) {
+            return Err(SystematicsError::Structure(format!("Expected Undecad template, got {}", system.template().kind)));
+        }
+        Ok(Undecad { system })
+    }
+}
+
+impl System for Undecad {
+    fn kind(&self) -> &'static str {
+        self.system.template().kind
+    }
+
+    fn order(&self) -> usize {
+        self.system.order()
+    }
+
+    fn name(&self) -> &str {
+        &self.system.name
+    }
+
+    fn canonical_terms(&self) -> Vec<&'static str> {
+        Self::get_canonical_terms()
+    }
+
+    fn instances(&self) -> Vec<String> {
+        self.get_instances()
+    }
+
+    fn connectives(&self) -> &[Connective] {
+        self.system.connectives()
+    }
+
+    fn attribute_description(&self) -> &'static str {
+        Self::TERM_ATTRIBUTE_DESCRIPTION
+    }
+
+    fn display(&self) {
+        self.system.display()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Undecad {
+        Undecad::new("Test", "One", "Two", "Three", "Four", "Five", "Six", "Seven", "Eight", "Nine", "Ten", "Eleven")
+    }
+
+    #[test]
+    fn test_undecad_creation() {
+        let undecad = sample();
+
+        assert_eq!(undecad.name, "Test");
+        assert_eq!(undecad.domination(), "One");
+        assert_eq!(undecad.creativity(), "Two");
+        assert_eq!(undecad.pattern(), "Three");
+        assert_eq!(undecad.individuality(), "Four");
+        assert_eq!(undecad.structure(), "Five");
+        assert_eq!(undecad.repetition(), "Six");
+        assert_eq!(undecad.potentiality(), "Seven");
+        assert_eq!(undecad.subsistence(), "Eight");
+        assert_eq!(undecad.relatedness(), "Nine");
+        assert_eq!(undecad.polarity(), "Ten");
+        assert_eq!(undecad.wholeness(), "Eleven");
+
+        // Should have default connectives
+        assert!(undecad.has_connectives());
+        assert_eq!(undecad.connective_label('A', 'B').unwrap(), "Conquest");
+    }
+
+    #[test]
+    fn test_canonical_terms() {
+        let terms = Undecad::get_canonical_terms();
+        assert_eq!(terms, vec![
+            "Domination",
+            "Creativity",
+            "Pattern",
+            "Individuality",
+            "Structure",
+            "Repetition",
+            "Potentiality",
+            "Subsistence",
+            "Relatedness",
+            "Polarity",
+            "Wholeness",
+        ]);
+    }
+
+    #[test]
+    fn test_get_instances() {
+        let instances = sample().get_instances();
+        assert_eq!(instances, vec!["One", "Two", "Three", "Four", "Five", "Six", "Seven", "Eight", "Nine", "Ten", "Eleven"]);
+    }
+
+    #[test]
+    fn test_has_connectives_with_some() {
+        let mut undecad = Undecad::new("Test", "A", "B", "C", "D", "E", "F", "G", "H", "I", "J", "K");
+        undecad.set_connective_label('A', 'B', Some("test connection".to_string())).unwrap();
+
+        assert!(undecad.has_connectives());
+    }
+
+    #[test]
+    fn test_reset_connective_label() {
+        let mut undecad = sample();
+        undecad.set_connective_label('A', 'B', Some("custom connection".to_string())).unwrap();
+        undecad.set_connective_label('A', 'B', None).unwrap();
+
+        // Clearing the user label restores the canonical name
+        assert!(undecad.has_connectives());
+        assert_eq!(undecad.connective_label('A', 'B'), Some("Conquest"));
+        assert!(!undecad.connective('A', 'B').unwrap().is_relabelled());
+    }
+
+    #[test]
+    fn test_custom_connectives() {
+        let mut undecad = sample();
+
+        // Modify one connective while others keep defaults
+        undecad.set_connective_label('A', 'K', Some("custom connection".to_string())).unwrap();
+        assert_eq!(undecad.connective_label('K', 'A').unwrap(), "custom connection");
+        assert_eq!(undecad.connective_label('B', 'C').unwrap(), "Invention");
+    }
+
+    #[test]
+    fn test_all_connectives_count() {
+        let undecad = sample();
+
+        // Should have exactly 55 connectives (11 choose 2 = 55)
+        assert_eq!(undecad.connectives().len(), 55);
+    }
+
+    #[test]
+    fn test_try_from_instance() {
+        let undecad = sample();
+        let system = undecad.clone().into_instance();
+
+        assert_eq!(system.order(), 11);
+        assert_eq!(Undecad::try_from(system).unwrap(), undecad);
+    }
+}
@@ -0,0 +1,328 @@
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+use unicode_general_category::{get_general_category, GeneralCategory};
+use unicode_normalization::UnicodeNormalization;
+use crate::modules::error::SystematicsError;
+
+/// File in a workspace directory that overrides the default policy
+pub const POLICY_FILE: &str = "policy.json";
+
+/// Punctuation accepted by the original input rules
+const LEGACY_PUNCTUATION: &str = ".,!?'-()";
+
+/// Broad Unicode character classes a policy can allow
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CharacterClass {
+    /// Letters in any script, with their combining marks
+    Letters,
+    /// Decimal digits and other numerals
+    Digits,
+    /// Space separators (tabs and line breaks are never allowed)
+    Spaces,
+    /// Punctuation such as . , ! ? ' " - / ( ) &
+    Punctuation,
+    /// Symbols such as + = < > $ ~ and emoji
+    Symbols,
+}
+
+impl CharacterClass {
+    /// Check if a character belongs to this class
+    pub fn contains(self, character: char) -> bool {
+        use GeneralCategory::*;
+        let category = get_general_category(character);
+        match self {
+            CharacterClass::Letters => matches!(
+                category,
+                UppercaseLetter
+                    | LowercaseLetter
+                    | TitlecaseLetter
+                    | ModifierLetter
+                    | OtherLetter
+                    | NonspacingMark
+                    | SpacingMark
+                    | EnclosingMark
+            ),
+            CharacterClass::Digits => matches!(category, DecimalNumber | LetterNumber | OtherNumber),
+            CharacterClass::Spaces => category == SpaceSeparator,
+            CharacterClass::Punctuation => matches!(
+                category,
+                ConnectorPunctuation
+                    | DashPunctuation
+                    | OpenPunctuation
+                    | ClosePunctuation
+                    | InitialPunctuation
+                    | FinalPunctuation
+                    | OtherPunctuation
+            ),
+            CharacterClass::Symbols => matches!(category, MathSymbol | CurrencySymbol | ModifierSymbol | OtherSymbol),
+        }
+    }
+}
+
+/// Unicode normalization applied before any other check
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Normalization {
+    /// Keep input exactly as typed
+    None,
+    /// Canonical composition, so "é" typed either way compares equal
+    #[default]
+    Nfc,
+    /// Compatibility composition, which also folds ligatures and full-width forms
+    Nfkc,
+}
+
+/// How surrounding and repeated whitespace is cleaned up
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Trim {
+    /// Keep whitespace as typed
+    None,
+    /// Strip leading and trailing whitespace
+    #[default]
+    Ends,
+    /// Strip the ends and collapse inner runs of whitespace to a single space
+    Collapse,
+}
+
+/// Why a value was rejected
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum ValidationError {
+    /// Nothing left after trimming
+    Empty,
+    TooShort { min: usize, length: usize },
+    TooLong { max: usize, length: usize },
+    /// `index` counts characters, not bytes, in the cleaned value
+    InvalidCharacter { character: char, index: usize },
+}
+
+/// Reads as a predicate so it can follow the name of the field, e.g. "Name is too long (max 100 characters)"
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ValidationError::Empty => write!(f, "is empty"),
+            ValidationError::TooShort { min, .. } => write!(f, "is too short (min {} characters)", min),
+            ValidationError::TooLong { max, .. } => write!(f, "is too long (max {} characters)", max),
+            ValidationError::InvalidCharacter { character, .. } if character.is_control() => {
+                write!(f, "contains invalid character {}", character.escape_unicode())
+            }
+            ValidationError::InvalidCharacter { character, .. } => {
+                write!(f, "contains invalid character '{}'", character)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ValidationError {}
+
+/// Rules every user-supplied name, term and label goes through.
+///
+/// The policy cleans a value (normalization, then trimming) before checking its
+/// length and characters, and hands back the cleaned value. A workspace can
+/// override the default by placing a `policy.json` in its directory; missing
+/// fields keep their defaults.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ValidationPolicy {
+    /// Fewest characters accepted after cleaning (values are never empty)
+    pub min_length: usize,
+    /// Most characters accepted after cleaning
+    pub max_length: usize,
+    pub allowed: Vec<CharacterClass>,
+    /// Characters accepted on top of the allowed classes
+    pub extra_characters: String,
+    pub normalization: Normalization,
+    pub trim: Trim,
+}
+
+impl Default for ValidationPolicy {
+    fn default() -> Self {
+        ValidationPolicy {
+            min_length: 1,
+            max_length: 100,
+            allowed: vec![
+                CharacterClass::Letters,
+                CharacterClass::Digits,
+                CharacterClass::Spaces,
+                CharacterClass::Punctuation,
+                CharacterClass::Symbols,
+            ],
+            extra_characters: String::new(),
+            normalization: Normalization::Nfc,
+            trim: Trim::Ends,
+        }
+    }
+}
+
+impl ValidationPolicy {
+    /// The original rules: letters, digits, spaces and `.,!?'-()` only
+    pub fn strict() -> Self {
+        ValidationPolicy {
+            allowed: vec![CharacterClass::Letters, CharacterClass::Digits, CharacterClass::Spaces],
+            extra_characters: LEGACY_PUNCTUATION.to_string(),
+            ..ValidationPolicy::default()
+        }
+    }
+
+    /// Policy for a workspace directory: its `policy.json` if present, else the default
+    pub fn for_workspace(root: &Path) -> Result<Self, SystematicsError> {
+        let path = root.join(POLICY_FILE);
+        match fs::read_to_string(&path) {
+            Ok(contents) => serde_json::from_str(&contents)
+                .map_err(|e| SystematicsError::Parse(format!("{}: {}", path.display(), e))),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(ValidationPolicy::default()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Normalizes and trims a value without checking it
+    pub fn clean(&self, value: &str) -> String {
+        let normalized: String = match self.normalization {
+            Normalization::None => value.to_string(),
+            Normalization::Nfc => value.nfc().collect(),
+            Normalization::Nfkc => value.nfkc().collect(),
+        };
+        match self.trim {
+            Trim::None => normalized,
+            Trim::Ends => normalized.trim().to_string(),
+            Trim::Collapse => normalized.split_whitespace().collect::<Vec<_>>().join(" "),
+        }
+    }
+
+    /// Check if a character is accepted
+    pub fn allows(&self, character: char) -> bool {
+        self.extra_characters.contains(character) || self.allowed.iter().any(|class| class.contains(character))
+    }
+
+    /// Cleans a value and checks it, returning the cleaned value
+    pub fn validate(&self, value: &str) -> Result<String, ValidationError> {
+        let cleaned = self.clean(value);
+        let length = cleaned.chars().count();
+
+        if length == 0 {
+            return Err(ValidationError::Empty);
+        }
+        if length < self.min_length {
+            return Err(ValidationError::TooShort { min: self.min_length, length });
+        }
+        if length > self.max_length {
+            return Err(ValidationError::TooLong { max: self.max_length, length });
+        }
+        if let Some((index, character)) = cleaned.chars().enumerate().find(|(_, character)| !self.allows(*character)) {
+            return Err(ValidationError::InvalidCharacter { character, index });
+        }
+        Ok(cleaned)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_accepts_everyday_punctuation() {
+        let policy = ValidationPolicy::default();
+
+        for value in ["Mind/Body", "A & B", "\"Quoted\"", "It's 100% done", "Café – 2024", "«Être»"] {
+            assert_eq!(policy.validate(value), Ok(value.to_string()), "{}", value);
+        }
+    }
+
+    #[test]
+    fn test_strict_keeps_original_rules() {
+        let policy = ValidationPolicy::strict();
+
+        assert!(policy.validate("Hello World! (Version 1.0) It's fine, thanks.").is_ok());
+        assert_eq!(
+            policy.validate("Mind/Body"),
+            Err(ValidationError::InvalidCharacter { character: '/', index: 4 })
+        );
+    }
+
+    #[test]
+    fn test_length_limits() {
+        let policy = ValidationPolicy {
+            min_length: 2,
+            ..ValidationPolicy::default()
+        };
+
+        assert_eq!(policy.validate("   "), Err(ValidationError::Empty));
+        assert_eq!(policy.validate(" a "), Err(ValidationError::TooShort { min: 2, length: 1 }));
+        assert!(policy.validate(&"é".repeat(100)).is_ok());
+        assert_eq!(
+            policy.validate(&"a".repeat(101)),
+            Err(ValidationError::TooLong { max: 100, length: 101 })
+        );
+    }
+
+    #[test]
+    fn test_control_characters_are_always_rejected() {
+        let error = ValidationPolicy::default().validate("Tab\there").unwrap_err();
+
+        assert_eq!(error, ValidationError::InvalidCharacter { character: '\t', index: 3 });
+        assert_eq!(error.to_string(), "contains invalid character \\u{9}");
+    }
+
+    #[test]
+    fn test_normalization() {
+        let decomposed = "Cafe\u{301}";
+        assert_eq!(ValidationPolicy::default().validate(decomposed).unwrap(), "Caf\u{e9}");
+
+        let none = ValidationPolicy {
+            normalization: Normalization::None,
+            ..ValidationPolicy::default()
+        };
+        assert_eq!(none.validate(decomposed).unwrap(), decomposed);
+
+        let compatibility = ValidationPolicy {
+            normalization: Normalization::Nfkc,
+            ..ValidationPolicy::default()
+        };
+        assert_eq!(compatibility.validate("\u{fb01}re").unwrap(), "fire");
+    }
+
+    #[test]
+    fn test_trimming() {
+        let value = "  Mind   and  Body ";
+        let with = |trim| ValidationPolicy { trim, ..ValidationPolicy::default() };
+
+        assert_eq!(with(Trim::Ends).validate(value).unwrap(), "Mind   and  Body");
+        assert_eq!(with(Trim::Collapse).validate(value).unwrap(), "Mind and Body");
+        assert_eq!(with(Trim::None).validate(value).unwrap(), value);
+    }
+
+    #[test]
+    fn test_error_messages() {
+        assert_eq!(ValidationError::TooLong { max: 100, length: 120 }.to_string(), "is too long (max 100 characters)");
+        assert_eq!(
+            ValidationError::InvalidCharacter { character: '@', index: 0 }.to_string(),
+            "contains invalid character '@'"
+        );
+    }
+
+    #[test]
+    fn test_workspace_policy_file() {
+        let root = std::env::temp_dir().join(format!("systematics-policy-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(&root).unwrap();
+
+        assert_eq!(ValidationPolicy::for_workspace(&root).unwrap(), ValidationPolicy::default());
+
+        fs::write(root.join(POLICY_FILE), r#"{"max_length": 20, "allowed": ["letters", "spaces"], "trim": "collapse"}"#)
+            .unwrap();
+        let policy = ValidationPolicy::for_workspace(&root).unwrap();
+        assert_eq!(policy.max_length, 20);
+        assert_eq!(policy.normalization, Normalization::Nfc);
+        assert!(policy.validate("A1").is_err());
+
+        fs::write(root.join(POLICY_FILE), r#"{"allowed": ["emoji"]}"#).unwrap();
+        assert!(ValidationPolicy::for_workspace(&root).is_err());
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}